@@ -0,0 +1,17 @@
+import { invoke } from '@tauri-apps/api/core'
+
+export interface FileIcon {
+  /** The path or extension that was looked up */
+  app: string
+  /** Base64-encoded PNG */
+  icon: string | null
+}
+
+/**
+ * Get a document icon. An extension (`'pdf'`, `'.pdf'`) or UTI returns the
+ * generic icon for that type; a path (containing `/`, `~/` allowed) returns
+ * that file's own icon. `size` is in points (default 16, max 512).
+ */
+export async function getFileIcon(pathOrExtension: string, size?: number): Promise<FileIcon> {
+  return invoke<FileIcon>('get_file_icon', { pathOrExtension, size })
+}
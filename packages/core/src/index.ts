@@ -1,6 +1,10 @@
 export * from './types'
 export * from './window-controller'
 export * from './window'
+export * from './icons'
+export * from './permissions'
 export * from './popover-controller'
 export * from './shared-store'
+export * from './scheduler'
 export * from './shell'
+export * from './sources'
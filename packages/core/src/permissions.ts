@@ -0,0 +1,28 @@
+import { invoke } from '@tauri-apps/api/core'
+
+export type PermissionKind = 'accessibility' | 'screenRecording' | 'automation' | 'inputMonitoring'
+
+export interface PermissionStatus {
+  accessibility: boolean
+  screenRecording: boolean
+  inputMonitoring: boolean
+  /** Bundle id -> granted (null: not asked yet, or the app isn't running) */
+  automation: Record<string, boolean | null>
+}
+
+/**
+ * Check which macOS privacy permissions are granted.
+ * Automation is checked for `automationTargets` (default: Spotify and Music).
+ */
+export async function checkPermissions(automationTargets?: string[]): Promise<PermissionStatus> {
+  return invoke<PermissionStatus>('check_permissions', { automationTargets })
+}
+
+/**
+ * Ask for a permission. Shows the system prompt where macOS allows one and
+ * opens the Privacy & Security pane if it's still not granted.
+ * Automation needs the target app's bundle id.
+ */
+export async function requestPermission(kind: PermissionKind, target?: string): Promise<boolean> {
+  return invoke<boolean>('request_permission', { kind, target })
+}
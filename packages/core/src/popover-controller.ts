@@ -45,6 +45,7 @@ export function getPopoverId(): string | null {
 /**
  * Open a popover window below the anchor element (toggle mode)
  * If popover already exists, it will be closed.
+ * In the hover modes an open popover stays open instead (see PopoverMode).
  */
 export async function openPopover(options: PopoverOpenOptions): Promise<PopoverInfo> {
   const params = {
@@ -54,6 +55,8 @@ export async function openPopover(options: PopoverOpenOptions): Promise<PopoverI
     height: options.height ?? 200,
     align: options.align ?? 'center',
     offsetY: options.offsetY ?? 8,
+    keyboard: options.keyboard ?? false,
+    mode: options.mode ?? 'toggle',
   }
   return await invoke<PopoverInfo>('open_popover', params)
 }
@@ -0,0 +1,64 @@
+import { invoke } from '@tauri-apps/api/core'
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow'
+
+/**
+ * Call `callback` every `intervalMs`, in step with every other widget using
+ * the same interval (ticks land on wall-clock multiples of the interval).
+ * `id` names the tick's events (letters, digits, '-', '_', '/', ':') and only
+ * needs to be unique within this window.
+ * Resolves with a function that cancels the tick.
+ */
+export async function scheduleTick(
+  id: string,
+  intervalMs: number,
+  callback: (timestamp: number) => void
+): Promise<() => Promise<void>> {
+  // Emitted only to the window that scheduled it
+  const unlisten = await getCurrentWebviewWindow().listen<{ timestamp: number }>(
+    `tick:${id}`,
+    (event) => callback(event.payload.timestamp)
+  )
+
+  try {
+    await invoke('schedule_tick', { id, intervalMs })
+  } catch (e) {
+    unlisten()
+    throw e
+  }
+
+  return async () => {
+    unlisten()
+    await invoke('cancel_tick', { id })
+  }
+}
+
+/**
+ * Call `callback` once at `unixMs` (immediately if it has passed).
+ * Resolves with a function that cancels the alarm.
+ */
+export async function scheduleAt(
+  id: string,
+  unixMs: number,
+  callback: (timestamp: number) => void
+): Promise<() => Promise<void>> {
+  // Emitted only to the window that scheduled it
+  const unlisten = await getCurrentWebviewWindow().listen<{ timestamp: number; scheduledAt: number }>(
+    `alarm:${id}`,
+    (event) => {
+      unlisten()
+      callback(event.payload.timestamp)
+    }
+  )
+
+  try {
+    await invoke('schedule_at', { id, unixMs: Math.round(unixMs) })
+  } catch (e) {
+    unlisten()
+    throw e
+  }
+
+  return async () => {
+    unlisten()
+    await invoke('cancel_tick', { id }).catch(() => {})  // Already fired
+  }
+}
@@ -1,5 +1,77 @@
 import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 
-export async function executeShell(command: string): Promise<string> {
-  return invoke<string>('execute_shell', { command })
+export interface ShellOutput {
+  code: number | null   // null if killed by a signal
+  stdout: string
+  stderr: string
+}
+
+export interface ShellOptions {
+  /** Kill the command after this many ms (default: shell.timeoutMs, 30000) */
+  timeoutMs?: number
+}
+
+/**
+ * Run a command and resolve with its stdout (rejects with stderr on failure).
+ * The program must be listed in shell.allow in fluopanel.json.
+ */
+export async function executeShell(command: string, options: ShellOptions = {}): Promise<string> {
+  return invoke<string>('execute_shell', { command, ...options })
+}
+
+/**
+ * Run a command and resolve with its exit code, stdout and stderr
+ */
+export async function runShell(command: string, options: ShellOptions = {}): Promise<ShellOutput> {
+  return invoke<ShellOutput>('run_shell', { command, ...options })
+}
+
+export interface ShellStreamHandlers {
+  onOutput?: (line: string, stream: 'stdout' | 'stderr') => void
+  onExit?: (code: number | null, killed: boolean) => void
+}
+
+export interface ShellStream {
+  /** Terminate the program */
+  kill(): Promise<void>
+}
+
+/**
+ * Start a program (no shell) and receive its output line by line.
+ * `id` names the stream's events (letters, digits, '-', '_', '/', ':').
+ * The program is killed when this window closes.
+ */
+export async function executeShellStream(
+  id: string,
+  program: string,
+  args: string[],
+  handlers: ShellStreamHandlers
+): Promise<ShellStream> {
+  const unlistenOutput = await listen<{ stream: 'stdout' | 'stderr'; line: string }>(
+    `shell-output:${id}`,
+    (event) => handlers.onOutput?.(event.payload.line, event.payload.stream)
+  )
+  const unlistenExit = await listen<{ code: number | null; killed: boolean }>(
+    `shell-exit:${id}`,
+    (event) => {
+      unlistenOutput()
+      unlistenExit()
+      handlers.onExit?.(event.payload.code, event.payload.killed)
+    }
+  )
+
+  try {
+    await invoke('execute_shell_stream', { id, program, args })
+  } catch (e) {
+    unlistenOutput()
+    unlistenExit()
+    throw e
+  }
+
+  return {
+    async kill() {
+      await invoke('kill_shell', { id })
+    },
+  }
 }
@@ -0,0 +1,14 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { WatcherSource } from './types'
+
+export async function activateSources(sources: WatcherSource[]): Promise<void> {
+  return invoke('activate_sources', { sources })
+}
+
+export async function deactivateSources(sources: WatcherSource[]): Promise<void> {
+  return invoke('deactivate_sources', { sources })
+}
+
+export async function requestFastUpdates(source: WatcherSource, durationMs: number): Promise<void> {
+  return invoke('request_fast_updates', { source, durationMs })
+}
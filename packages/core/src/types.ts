@@ -2,18 +2,26 @@
 // Window Position Types
 // ============================================
 
-// Window positioning (all values in pixels)
+// A length in pixels, or a string relative to the monitor dimension on
+// that axis: "120" (pixels), "50%", "100%-20", "25%+8"
+export type PositionLength = number | string
+
+// Window positioning
 // Position defines a bounding box on screen
 export interface WindowPosition {
   monitor?: string          // Monitor name or 'primary'
-  top?: number              // Pixels from top edge
-  bottom?: number           // Pixels from bottom edge
-  left?: number             // Pixels from left edge
-  right?: number            // Pixels from right edge
-  width?: number            // Explicit width (if left+right not specified)
-  height?: number           // Explicit height (if top+bottom not specified)
+  top?: PositionLength      // Distance from top edge
+  bottom?: PositionLength   // Distance from bottom edge
+  left?: PositionLength     // Distance from left edge
+  right?: PositionLength    // Distance from right edge
+  width?: PositionLength    // Explicit width (if left+right not specified)
+  height?: PositionLength   // Explicit height (if top+bottom not specified)
+  minVisible?: number       // Share (0-1) kept inside the visible frame (default: 0.5, 0 = no clamp)
 }
 
+// Z-order layer, from behind desktop icons ('desktop') to above the menu bar
+export type WindowLevel = 'desktop' | 'normal' | 'floating' | 'statusBar' | 'popUpMenu' | 'screenSaver'
+
 // Window configuration
 export interface WindowConfig {
   transparent?: boolean     // default: true
@@ -22,6 +30,51 @@ export interface WindowConfig {
   decorations?: boolean     // default: false
   skipTaskbar?: boolean     // default: true
   clickThrough?: boolean    // Ignore mouse events (for overlays)
+  perMonitor?: boolean      // One instance per monitor, synced on display connect/disconnect
+  allSpaces?: boolean       // Show on every Space, including over fullscreen apps
+  level?: WindowLevel       // Overrides alwaysOnTop
+  minSize?: { width?: number; height?: number }  // Smallest size the user can resize to
+  maxSize?: { width?: number; height?: number }  // Largest size the user can resize to
+}
+
+// Resize limits for set_window_size_constraints (logical pixels)
+export interface SizeConstraints {
+  minWidth?: number
+  minHeight?: number
+  maxWidth?: number
+  maxHeight?: number
+}
+
+// Error rejected by create_inline_window
+export type WindowCreateError =
+  | { kind: 'alreadyExists'; label: string }
+  | { kind: 'invalidPosition'; reason: string }
+  | { kind: 'invalidSizeConstraints'; reason: string }
+  | { kind: 'invalidUrl'; url: string; reason: string }
+  | { kind: 'monitorNotFound'; name: string }
+  | { kind: 'build'; reason: string }
+
+// Saved by save_layout_preset (~/.config/fluopanel/layouts/{name}.json)
+export interface LayoutPreset {
+  name: string
+  windows: LayoutPresetWindow[]
+}
+
+export interface LayoutPresetWindow {
+  windowId: string
+  url: string
+  transparent: boolean
+  alwaysOnTop: boolean
+  decorations: boolean
+  resizable: boolean
+  skipTaskbar: boolean
+  sizeConstraints: SizeConstraints
+  allSpaces: boolean
+  overFullscreen: boolean | null  // null follows allSpaces
+  level: WindowLevel | null
+  position: WindowPosition  // Current geometry (per-monitor windows keep their config)
+  perMonitor: boolean
+  visible: boolean
 }
 
 // ============================================
@@ -34,10 +87,18 @@ export interface ThemeConfig {
   accentColor?: string
 }
 
+// Theme resolved against the system appearance (theme-resolved event payload)
+export interface EffectiveTheme {
+  mode: 'light' | 'dark'
+  accentColor: string
+}
+
 // Global settings
 export interface GlobalSettings {
   hotReload: boolean
   devMode: boolean
+  hoverFocusDelayMs?: number  // Hover focus dwell delay (default: 120)
+  launchAtLogin?: boolean     // Start at login (unset: leave system setting alone)
 }
 
 // Secrets configuration
@@ -51,6 +112,73 @@ export interface UiConfig {
   distPath?: string
 }
 
+// Aerospace integration settings
+export interface AerospaceConfig {
+  /** Poll aerospace for focus changes (default: true) */
+  watch?: boolean
+  /** Focus poll interval in milliseconds (default: 500) */
+  pollIntervalMs?: number
+}
+
+// IPC server settings
+export interface IpcConfig {
+  /** Also listen on 127.0.0.1:{port} (loopback only; send `auth:{token}` from ~/.config/fluopanel/ipc-token first) */
+  tcpPort?: number
+}
+
+// Media integration settings
+export interface MediaConfig {
+  /** Media app to query and control (default: 'auto' = Spotify, then Music) */
+  preferredApp?: 'auto' | 'Spotify' | 'Music'
+}
+
+export interface BatteryConfig {
+  /** battery-low fires when the level drops below this percent (default: 20) */
+  lowThreshold?: number
+  /** battery-critical fires when the level drops below this percent (default: 10) */
+  criticalThreshold?: number
+}
+
+export interface ShellConfig {
+  /**
+   * Programs widgets may run, by name ("open") or path ("/usr/bin/open");
+   * "*" allows any command line. Unset: everything in dev builds, nothing in release.
+   */
+  allow?: string[]
+  /** Kill commands after this many ms (default: 30000) */
+  timeoutMs?: number
+}
+
+export interface HistoryConfig {
+  /** Samples kept per metric for get_history (default: 60) */
+  length?: number
+}
+
+export interface ClipboardConfig {
+  /** Pasteboard poll interval in milliseconds (default: 500) */
+  pollIntervalMs?: number
+}
+
+// Poll intervals of the polling watchers (min 1000; applied when the config is saved)
+export interface WatchersConfig {
+  /** Now playing check in milliseconds (default: 5000) */
+  mediaIntervalMs?: number
+  /** Network fallback poll in milliseconds (default: 30000) */
+  networkIntervalMs?: number
+  /** CPU, memory, GPU and system stats in milliseconds (default: 5000) */
+  systemIntervalMs?: number
+}
+
+// Dismissal tuning for hover-mode popovers
+export interface PopoverConfig {
+  /** How long the cursor may be outside a hover popover before it closes (default: 150, max: 5000) */
+  hoverCloseDelayMs?: number
+  /** Extra hit area in px around the trigger element (default: 15, max: 100) */
+  triggerPadding?: number
+  /** Cursor polling interval in milliseconds while a hover popover is open (default: 100) */
+  monitorIntervalMs?: number
+}
+
 // Global Fluopanel configuration (fluopanel.json schema)
 export interface FluopanelConfig {
   version: number           // Schema version (2)
@@ -58,6 +186,94 @@ export interface FluopanelConfig {
   settings: GlobalSettings
   secrets?: SecretsConfig
   ui?: UiConfig
+  aerospace?: AerospaceConfig
+  ipc?: IpcConfig
+  media?: MediaConfig
+  clipboard?: ClipboardConfig
+  battery?: BatteryConfig
+  history?: HistoryConfig
+  shell?: ShellConfig
+  popover?: PopoverConfig
+  watchers?: WatchersConfig
+}
+
+// ============================================
+// Event Types
+// ============================================
+
+// Data sources widgets can activate (watchers only poll while activated)
+export type WatcherSource =
+  | 'system'                // cpu-changed, memory-changed, system-stats-changed, gpu-changed
+  | 'network'
+  | 'media'
+  | 'battery'
+  | 'volume'
+  | 'activeApp'
+  | 'aerospace'
+  | 'notifications'         // notification-count-changed
+  | 'clipboard'             // clipboard-changed
+  | 'windowTitle'           // active-window-title-changed
+
+// Entry returned by list_events
+export interface EventInfo {
+  name: string              // Event name ('*' marks a wildcard suffix, e.g. 'store-changed:*')
+  description: string
+  payloadSchema: unknown    // JSON Schema of the payload
+}
+
+// ============================================
+// System Types
+// ============================================
+
+// Accessibility display preferences (accessibility-prefs-changed payload)
+export interface AccessibilityPrefs {
+  reduceMotion: boolean
+  reduceTransparency: boolean
+  increaseContrast: boolean
+  differentiateWithoutColor: boolean
+}
+
+// focus-mode-changed payload / get_focus_mode result
+// modeId and name are null when the Focus database isn't readable;
+// enabled is null when the state is unknown (e.g. no Full Disk Access)
+export interface FocusInfo {
+  enabled: boolean | null
+  modeId: string | null
+  name: string | null
+}
+
+// thermal-changed payload / get_thermal_state result
+export type ThermalState = 'nominal' | 'fair' | 'serious' | 'critical'
+
+// Power draw returned by get_power_metrics (null on Intel/unsupported)
+export interface PowerMetrics {
+  cpuWatts: number | null
+  gpuWatts: number | null
+  aneWatts: number | null
+}
+
+// Entry returned by get_widget_resource_usage (null when the process can't be read)
+export interface WidgetResourceUsage {
+  label: string
+  widgetId: string | null   // null for the coordinator window
+  pid: number | null        // WebContent process
+  cpu: number | null        // Percent of one core
+  memoryBytes: number | null
+}
+
+// Entry returned by list_displays
+export interface DisplayInfo {
+  id: number                // CGDirectDisplayID (matches MonitorInfo.id)
+  name?: string
+  isMain: boolean
+  supportsBrightness: boolean
+  brightness?: number       // 0-100, when supported
+}
+
+// Entry returned by list_login_items
+export interface LoginItem {
+  name: string
+  path: string
 }
 
 // ============================================
@@ -66,6 +282,7 @@ export interface FluopanelConfig {
 
 // Monitor information
 export interface MonitorInfo {
+  id: string                // Stable display id (CGDirectDisplayID on macOS)
   name: string
   width: number
   height: number
@@ -74,6 +291,28 @@ export interface MonitorInfo {
   scaleFactor: number
 }
 
+// Point for screen_to_macos / macos_to_screen (global logical pixels)
+export interface ScreenPoint {
+  x: number
+  y: number
+  height?: number           // Height of the rect whose origin this is (default 0)
+}
+
+// Result of get_cursor_position (logical pixels, top-left origin)
+export interface CursorPosition {
+  x: number
+  y: number
+  monitor: MonitorInfo | null  // Monitor under the cursor (null while unavailable)
+}
+
+// Payload of the monitor-changed event
+export interface MonitorChange {
+  added: MonitorInfo[]
+  removed: MonitorInfo[]
+  changed: MonitorInfo[]     // Resized, moved or rescaled
+  monitors: MonitorInfo[]    // Current monitor set
+}
+
 // ============================================
 // Inline Window Types (for <Window> component)
 // ============================================
@@ -115,6 +354,15 @@ export interface WindowRegistration {
 /** Popover alignment relative to anchor element */
 export type PopoverAlign = 'start' | 'center' | 'end'
 
+/**
+ * How a popover is dismissed
+ * - toggle: opening it again closes it; also closes on blur
+ * - hover: closes once the cursor leaves both the trigger and the popover
+ * - hoverSticky: stays open until a click outside the trigger and popover
+ * Hover dismissal is macOS-only; elsewhere the hover modes close on blur.
+ */
+export type PopoverMode = 'toggle' | 'hover' | 'hoverSticky'
+
 /** Popover anchor position (from trigger element's getBoundingClientRect) */
 export interface PopoverAnchor {
   x: number
@@ -137,6 +385,10 @@ export interface PopoverOpenOptions {
   align?: PopoverAlign
   /** Vertical offset from anchor (default: 8) */
   offsetY?: number
+  /** Focus the popover's page on open so arrow keys / Enter work (default: false) */
+  keyboard?: boolean
+  /** How the popover is dismissed (default: 'toggle') */
+  mode?: PopoverMode
 }
 
 /** Popover info returned after open/toggle */
@@ -149,6 +401,18 @@ export interface PopoverInfo {
   maxHeight: number
 }
 
+/** Open overlay captured by snapshot_overlays (pass back to restore_overlays) */
+export interface OverlaySnapshot {
+  type: 'popover'
+  id: string
+  anchor: PopoverAnchor
+  size: { width: number; height: number }
+  align?: PopoverAlign
+  offsetY?: number | null
+  keyboard?: boolean
+  mode?: PopoverMode
+}
+
 /** Popover context for determining current window type */
 export interface PopoverContext {
   /** Popover ID (from URL parameter) */
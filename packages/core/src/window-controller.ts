@@ -1,16 +1,19 @@
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { WindowPosition, MonitorInfo } from './types'
+import type { WindowPosition, MonitorInfo, MonitorChange, CursorPosition } from './types'
 
 export interface WindowController {
   /** Get all available monitors */
   getMonitors(): Promise<MonitorInfo[]>
 
+  /** Get the cursor position and the monitor under it (e.g. to open a popover at the pointer) */
+  getCursorPosition(): Promise<CursorPosition>
+
   /** Update position of a specific window */
   updateWindowPosition(label: string, position: WindowPosition): Promise<void>
 
   /** Subscribe to monitor changes */
-  onMonitorChange(callback: () => void): Promise<UnlistenFn>
+  onMonitorChange(callback: (change: MonitorChange) => void): Promise<UnlistenFn>
 }
 
 /**
@@ -23,6 +26,10 @@ export function createWindowController(): WindowController {
       return invoke<MonitorInfo[]>('get_monitors')
     },
 
+    async getCursorPosition(): Promise<CursorPosition> {
+      return invoke<CursorPosition>('get_cursor_position')
+    },
+
     async updateWindowPosition(label: string, position: WindowPosition): Promise<void> {
       await invoke('update_window_position', {
         label,
@@ -34,12 +41,13 @@ export function createWindowController(): WindowController {
           right: position.right,
           width: position.width,
           height: position.height,
+          minVisible: position.minVisible,
         },
       })
     },
 
-    onMonitorChange(callback: () => void): Promise<UnlistenFn> {
-      return listen('monitor-changed', callback)
+    onMonitorChange(callback: (change: MonitorChange) => void): Promise<UnlistenFn> {
+      return listen<MonitorChange>('monitor-changed', (event) => callback(event.payload))
     },
   }
 }
@@ -1,7 +1,9 @@
 import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 import type {
   InlineWindowOptions,
   WindowContext,
+  WindowLevel,
   WindowPosition,
 } from './types'
 import { createWindowController } from './window-controller'
@@ -16,11 +18,13 @@ export function getWindowContext(): WindowContext {
   const params = new URLSearchParams(window.location.search)
   const windowId = params.get('window')
   const floatId = params.get('float')
+  const monitorId = params.get('monitor')
 
   if (windowId) {
     return {
       id: windowId,
-      label: `inline-window-${windowId}`,
+      // Per-monitor instances are labeled inline-window-{id}:{monitorId}
+      label: monitorId ? `inline-window-${windowId}:${monitorId}` : `inline-window-${windowId}`,
       mode: 'window',
     }
   }
@@ -86,6 +90,15 @@ export async function createInlineWindow(
     decorations: windowConfig?.decorations ?? false,
     resizable: windowConfig?.resizable ?? false,
     skipTaskbar: windowConfig?.skipTaskbar ?? true,
+    perMonitor: windowConfig?.perMonitor ?? false,
+    allSpaces: windowConfig?.allSpaces ?? false,
+    level: windowConfig?.level,
+    sizeConstraints: {
+      minWidth: windowConfig?.minSize?.width,
+      minHeight: windowConfig?.minSize?.height,
+      maxWidth: windowConfig?.maxSize?.width,
+      maxHeight: windowConfig?.maxSize?.height,
+    },
     position: {
       monitor: position.monitor,
       top: position.top,
@@ -94,6 +107,7 @@ export async function createInlineWindow(
       right: position.right,
       width: position.width,
       height: position.height,
+      minVisible: position.minVisible,
     },
   })
 
@@ -121,12 +135,96 @@ export async function updateWindowPosition(
   await windowController.updateWindowPosition(label, position)
 }
 
+/**
+ * Re-apply every inline window's position config against the current
+ * monitors (also done automatically on monitor-changed).
+ * Returns the labels that were repositioned.
+ */
+export async function reapplyAllWindowGeometry(): Promise<string[]> {
+  return invoke<string[]>('reapply_all_window_geometry')
+}
+
+/**
+ * Control whether an inline window joins every Space and stays visible
+ * over fullscreen apps.
+ */
+export async function setWindowCollectionBehavior(
+  id: string,
+  behavior: { allSpaces: boolean; overFullscreen: boolean }
+): Promise<void> {
+  const label = `inline-window-${id}`
+  await invoke('set_window_collection_behavior', { label, ...behavior })
+}
+
+/**
+ * Move an inline window to a z-order layer
+ */
+export async function setWindowLevel(id: string, level: WindowLevel): Promise<void> {
+  const label = `inline-window-${id}`
+  await invoke('set_window_level', { label, level })
+}
+
+/**
+ * Show or hide an inline window with a fade.
+ * `ignoreCursorEvents` optionally toggles click-through at the same time.
+ */
+export async function fadeInlineWindow(
+  id: string,
+  visible: boolean,
+  options: { durationMs?: number; ignoreCursorEvents?: boolean } = {}
+): Promise<void> {
+  const label = `inline-window-${id}`
+  await invoke('fade_window', { label, visible, ...options })
+}
+
+/**
+ * Save state before this window is reloaded by the backend (reload_widget).
+ * `save` should stash what it needs (e.g. in a shared store) to restore after
+ * loading; the reload proceeds once it resolves or the backend timeout passes.
+ */
+export function onWidgetWillReload(
+  save: () => void | Promise<void>
+): Promise<UnlistenFn> {
+  return listen<{ label: string; timeoutMs: number }>('widget-will-reload', async (event) => {
+    if (event.payload.label !== getWindowContext().label) return
+    try {
+      await save()
+    } finally {
+      await invoke('ack_widget_reload')
+    }
+  })
+}
+
+/**
+ * Reload a window, letting it save state first (see onWidgetWillReload)
+ */
+export async function reloadWidget(label: string): Promise<void> {
+  await invoke('reload_widget', { label })
+}
+
+/**
+ * Reload every inline window, each saving state first (see onWidgetWillReload).
+ * Resolves with the labels that reloaded; `widgets-reloaded` is emitted too.
+ */
+export async function reloadAllWidgets(): Promise<string[]> {
+  return invoke<string[]>('reload_all_widgets')
+}
+
+/**
+ * Called when a shared runtime library (`fluopanel://lib/{file}`) changes.
+ * Only emitted in debug builds, while iterating on vue/providers/tauri-api.
+ */
+export function onLibsChanged(callback: (file: string) => void): Promise<UnlistenFn> {
+  return listen<{ file: string }>('libs-changed', (event) => callback(event.payload.file))
+}
+
 /**
  * Hide the coordinator window (make it invisible).
  * Useful when all UI is rendered in windows.
  */
 export async function hideCoordinatorWindow(): Promise<void> {
-  await invoke('hide_window', { label: 'main' })
+  // Click-through too, so the hidden coordinator never swallows clicks
+  await invoke('hide_window', { label: 'main', ignoreCursorEvents: true })
 }
 
 /**
@@ -1,15 +1,24 @@
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { ActiveAppInfo, Provider } from './types'
+import type { ActiveAppInfo, ActiveWindowTitle, Provider, RunningAppInfo } from './types'
 
 export interface ActiveAppProvider extends Provider<ActiveAppInfo> {
   getActiveApp(): Promise<ActiveAppInfo>
+  /** Running apps; agent/accessory apps only with includeBackground */
+  getRunningApps(includeBackground?: boolean): Promise<RunningAppInfo[]>
+  /** Bring a running app to the front */
+  activateApp(bundleId: string): Promise<void>
+  /** Focused window title of the frontmost app (null without Accessibility permission) */
+  getActiveWindowTitle(): Promise<string | null>
+  onWindowTitleChange(callback: (info: ActiveWindowTitle) => void): () => void
   onActiveAppChange(callback: (info: ActiveAppInfo) => void): () => void
 }
 
 export function createActiveAppProvider(): ActiveAppProvider {
   let unlistenFn: UnlistenFn | null = null
   let subscribers: Set<(info: ActiveAppInfo) => void> = new Set()
+  let titleUnlistenFn: UnlistenFn | null = null
+  let titleSubscribers: Set<(info: ActiveWindowTitle) => void> = new Set()
 
   const setupListener = async () => {
     if (unlistenFn) return
@@ -19,6 +28,14 @@ export function createActiveAppProvider(): ActiveAppProvider {
     })
   }
 
+  const setupTitleListener = async () => {
+    if (titleUnlistenFn) return
+
+    titleUnlistenFn = await listen<ActiveWindowTitle>('active-window-title-changed', (event) => {
+      titleSubscribers.forEach((callback) => callback(event.payload))
+    })
+  }
+
   return {
     async get() {
       return this.getActiveApp()
@@ -28,6 +45,18 @@ export function createActiveAppProvider(): ActiveAppProvider {
       return invoke<ActiveAppInfo>('get_active_app_info')
     },
 
+    async getRunningApps(includeBackground = false) {
+      return invoke<RunningAppInfo[]>('get_running_apps', { includeBackground })
+    },
+
+    async activateApp(bundleId) {
+      return invoke<void>('activate_app', { bundleId })
+    },
+
+    async getActiveWindowTitle() {
+      return invoke<string | null>('get_active_window_title')
+    },
+
     subscribe(callback) {
       return this.onActiveAppChange(callback)
     },
@@ -43,6 +72,19 @@ export function createActiveAppProvider(): ActiveAppProvider {
           unlistenFn = null
         }
       }
+    },
+
+    onWindowTitleChange(callback) {
+      titleSubscribers.add(callback)
+      setupTitleListener()
+
+      return () => {
+        titleSubscribers.delete(callback)
+        if (titleSubscribers.size === 0 && titleUnlistenFn) {
+          titleUnlistenFn()
+          titleUnlistenFn = null
+        }
+      }
     }
   }
 }
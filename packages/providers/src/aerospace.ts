@@ -15,6 +15,7 @@ export interface AerospaceProvider extends Provider<Workspace[]> {
   onFocusChange(callback: (event: FocusChangeEvent) => void): () => void
   getAppIcon(appName: string): Promise<AppIcon>
   getAppIcons(appNames: string[]): Promise<AppIcon[]>
+  getAllAppIcons(): Promise<Record<string, string | null>>
 }
 
 export function createAerospaceProvider(): AerospaceProvider {
@@ -87,6 +88,12 @@ export function createAerospaceProvider(): AerospaceProvider {
       }))
     },
 
+    async getAllAppIcons(): Promise<Record<string, string | null>> {
+      const icons = await invoke<Record<string, string | null>>('aerospace_get_all_app_icons')
+      Object.entries(icons).forEach(([app, icon]) => iconCache.set(app, icon))
+      return icons
+    },
+
     subscribe(callback) {
       return this.onWorkspaceChange(callback)
     },
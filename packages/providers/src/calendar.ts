@@ -0,0 +1,47 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { CalendarEvent, CalendarInfo, Provider } from './types'
+
+export interface CalendarQuery {
+  hoursAhead?: number       // Look-ahead window (default: 24)
+  calendarIds?: string[]    // Only these calendars (see getCalendars)
+}
+
+export interface CalendarProvider extends Provider<CalendarEvent[]> {
+  getUpcomingEvents(query?: CalendarQuery): Promise<CalendarEvent[]>
+  getCalendars(): Promise<CalendarInfo[]>
+  startPolling(callback: (events: CalendarEvent[]) => void, interval?: number, query?: CalendarQuery): () => void
+}
+
+// Calendar access is requested on first use; calls reject if it was denied
+export function createCalendarProvider(): CalendarProvider {
+  return {
+    async get() {
+      return this.getUpcomingEvents()
+    },
+
+    async getUpcomingEvents({ hoursAhead = 24, calendarIds }: CalendarQuery = {}) {
+      return invoke<CalendarEvent[]>('get_upcoming_events', { hoursAhead, calendarIds })
+    },
+
+    async getCalendars() {
+      return invoke<CalendarInfo[]>('get_calendars')
+    },
+
+    subscribe(callback) {
+      return this.startPolling(callback)
+    },
+
+    startPolling(callback, interval = 300000, query = {}) {
+      const tick = () => {
+        this.getUpcomingEvents(query).then(callback).catch(() => {})
+      }
+
+      tick()
+      const intervalId = setInterval(tick, interval)
+
+      return () => {
+        clearInterval(intervalId)
+      }
+    }
+  }
+}
@@ -1,9 +1,10 @@
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { CpuInfo, Provider } from './types'
+import type { CpuInfo, HistorySample, Provider } from './types'
 
 export interface CpuProvider extends Provider<CpuInfo> {
   getCpu(): Promise<CpuInfo>
+  getHistory(): Promise<HistorySample[]>
   onCpuChange(callback: (info: CpuInfo) => void): () => void
 }
 
@@ -28,6 +29,10 @@ export function createCpuProvider(): CpuProvider {
       return invoke<CpuInfo>('get_cpu_info')
     },
 
+    async getHistory() {
+      return invoke<HistorySample[]>('get_history', { metric: 'cpu' })
+    },
+
     subscribe(callback) {
       return this.onCpuChange(callback)
     },
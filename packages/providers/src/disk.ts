@@ -2,8 +2,13 @@ import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 import type { DiskInfo, Provider } from './types'
 
+export interface DiskFilter {
+  includeAll?: boolean      // Also list system volumes and snapshots
+  exclude?: string[]        // Mount-point prefixes to hide
+}
+
 export interface DiskProvider extends Provider<DiskInfo[]> {
-  getDisks(): Promise<DiskInfo[]>
+  getDisks(filter?: DiskFilter): Promise<DiskInfo[]>
   getDisk(mountPoint?: string): Promise<DiskInfo>
   onDiskChange(callback: (info: DiskInfo[]) => void): () => void
 }
@@ -25,8 +30,8 @@ export function createDiskProvider(): DiskProvider {
       return this.getDisks()
     },
 
-    async getDisks() {
-      return invoke<DiskInfo[]>('get_disk_info')
+    async getDisks(filter: DiskFilter = {}) {
+      return invoke<DiskInfo[]>('get_disk_info', filter)
     },
 
     async getDisk(mountPoint = '/') {
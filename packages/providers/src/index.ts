@@ -3,6 +3,7 @@ export * from './aerospace'
 export * from './battery'
 export * from './cpu'
 export * from './memory'
+export * from './system'
 export * from './network'
 export * from './date'
 export * from './media'
@@ -12,11 +13,13 @@ export * from './disk'
 export * from './brightness'
 export * from './bluetooth'
 export * from './youtubeMusic'
+export * from './calendar'
 
 import { createAerospaceProvider } from './aerospace'
 import { createBatteryProvider } from './battery'
 import { createCpuProvider } from './cpu'
 import { createMemoryProvider } from './memory'
+import { createSystemProvider } from './system'
 import { createNetworkProvider } from './network'
 import { createDateProvider } from './date'
 import { createMediaProvider } from './media'
@@ -26,6 +29,7 @@ import { createDiskProvider } from './disk'
 import { createBrightnessProvider } from './brightness'
 import { createBluetoothProvider } from './bluetooth'
 import { createYouTubeMusicProvider } from './youtubeMusic'
+import { createCalendarProvider } from './calendar'
 
 export function createProviders() {
   return {
@@ -33,6 +37,7 @@ export function createProviders() {
     battery: createBatteryProvider(),
     cpu: createCpuProvider(),
     memory: createMemoryProvider(),
+    system: createSystemProvider(),
     network: createNetworkProvider(),
     date: createDateProvider(),
     media: createMediaProvider(),
@@ -42,5 +47,6 @@ export function createProviders() {
     brightness: createBrightnessProvider(),
     bluetooth: createBluetoothProvider(),
     youtubeMusic: createYouTubeMusicProvider(),
+    calendar: createCalendarProvider(),
   }
 }
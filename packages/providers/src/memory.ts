@@ -1,9 +1,11 @@
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { MemoryInfo, Provider } from './types'
+import type { HistorySample, MemoryDetail, MemoryInfo, Provider } from './types'
 
 export interface MemoryProvider extends Provider<MemoryInfo> {
   getMemory(): Promise<MemoryInfo>
+  getDetail(): Promise<MemoryDetail>
+  getHistory(): Promise<HistorySample[]>
   onMemoryChange(callback: (info: MemoryInfo) => void): () => void
 }
 
@@ -28,6 +30,14 @@ export function createMemoryProvider(): MemoryProvider {
       return invoke<MemoryInfo>('get_memory_info')
     },
 
+    async getDetail() {
+      return invoke<MemoryDetail>('get_memory_detail')
+    },
+
+    async getHistory() {
+      return invoke<HistorySample[]>('get_history', { metric: 'memory' })
+    },
+
     subscribe(callback) {
       return this.onMemoryChange(callback)
     },
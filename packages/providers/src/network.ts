@@ -1,9 +1,12 @@
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { NetworkInfo, Provider } from './types'
+import type { HistorySample, NetworkInfo, NetworkInterfaceInfo, Provider } from './types'
 
 export interface NetworkProvider extends Provider<NetworkInfo> {
   getNetwork(): Promise<NetworkInfo>
+  getInterfaces(): Promise<NetworkInterfaceInfo[]>
+  /** Recent receive and send rates in bytes/s */
+  getThroughputHistory(): Promise<{ in: HistorySample[]; out: HistorySample[] }>
   onNetworkChange(callback: (info: NetworkInfo) => void): () => void
 }
 
@@ -28,6 +31,18 @@ export function createNetworkProvider(): NetworkProvider {
       return invoke<NetworkInfo>('get_network_info')
     },
 
+    async getInterfaces() {
+      return invoke<NetworkInterfaceInfo[]>('get_all_interfaces')
+    },
+
+    async getThroughputHistory() {
+      const [received, sent] = await Promise.all([
+        invoke<HistorySample[]>('get_history', { metric: 'networkIn' }),
+        invoke<HistorySample[]>('get_history', { metric: 'networkOut' }),
+      ])
+      return { in: received, out: sent }
+    },
+
     subscribe(callback) {
       return this.onNetworkChange(callback)
     },
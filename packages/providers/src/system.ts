@@ -0,0 +1,96 @@
+import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import type { FanInfo, GpuInfo, ProcessInfo, Provider, SystemStats } from './types'
+
+export interface TopProcessesOptions {
+  sortBy?: 'cpu' | 'memory'  // default: 'cpu'
+  limit?: number             // default: 5
+  withIcons?: boolean        // Include app icons
+}
+
+export interface SystemProvider extends Provider<SystemStats> {
+  getStats(): Promise<SystemStats>
+  /** Heaviest processes; CPU usage is measured since the previous call */
+  getTopProcesses(options?: TopProcessesOptions): Promise<ProcessInfo[]>
+  /** GPU utilization and memory (empty where no statistics are exposed) */
+  getGpus(): Promise<GpuInfo[]>
+  onGpuChange(callback: (gpus: GpuInfo[]) => void): () => void
+  /** Fan speeds (empty on fanless Macs) */
+  getFans(): Promise<FanInfo[]>
+  onStatsChange(callback: (stats: SystemStats) => void): () => void
+}
+
+export function createSystemProvider(): SystemProvider {
+  let unlistenFn: UnlistenFn | null = null
+  let subscribers: Set<(stats: SystemStats) => void> = new Set()
+  let gpuUnlistenFn: UnlistenFn | null = null
+  let gpuSubscribers: Set<(gpus: GpuInfo[]) => void> = new Set()
+
+  const setupListener = async () => {
+    if (unlistenFn) return
+
+    unlistenFn = await listen<SystemStats>('system-stats-changed', (event) => {
+      subscribers.forEach((callback) => callback(event.payload))
+    })
+  }
+
+  const setupGpuListener = async () => {
+    if (gpuUnlistenFn) return
+
+    gpuUnlistenFn = await listen<GpuInfo[]>('gpu-changed', (event) => {
+      gpuSubscribers.forEach((callback) => callback(event.payload))
+    })
+  }
+
+  return {
+    async get() {
+      return this.getStats()
+    },
+
+    async getStats() {
+      return invoke<SystemStats>('get_system_stats')
+    },
+
+    async getTopProcesses({ sortBy = 'cpu', limit = 5, withIcons = false }: TopProcessesOptions = {}) {
+      return invoke<ProcessInfo[]>('get_top_processes', { sortBy, limit, withIcons })
+    },
+
+    async getGpus() {
+      return invoke<GpuInfo[]>('get_gpu_info')
+    },
+
+    async getFans() {
+      return invoke<FanInfo[]>('get_fan_info')
+    },
+
+    subscribe(callback) {
+      return this.onStatsChange(callback)
+    },
+
+    onStatsChange(callback) {
+      subscribers.add(callback)
+      setupListener()
+
+      return () => {
+        subscribers.delete(callback)
+        if (subscribers.size === 0 && unlistenFn) {
+          unlistenFn()
+          unlistenFn = null
+        }
+      }
+    },
+
+    onGpuChange(callback) {
+      gpuSubscribers.add(callback)
+      setupGpuListener()
+
+      return () => {
+        gpuSubscribers.delete(callback)
+        if (gpuSubscribers.size === 0 && gpuUnlistenFn) {
+          gpuUnlistenFn()
+          gpuUnlistenFn = null
+        }
+      }
+    }
+  }
+}
@@ -32,6 +32,8 @@ export interface BatteryInfo {
   charging: boolean
   timeToEmpty?: number  // minutes
   timeToFull?: number   // minutes
+  vendor?: string | null
+  model?: string | null
 }
 
 export interface CpuInfo {
@@ -39,20 +41,89 @@ export interface CpuInfo {
   temperature?: number  // Celsius
 }
 
+export interface SystemStats {
+  uptimeSecs: number
+  loadAvg: [number, number, number]  // 1, 5 and 15 minute load averages
+  processCount: number
+  bootTime: number        // Unix timestamp (seconds)
+}
+
+export interface GpuInfo {
+  name: string
+  utilization?: number    // 0-100
+  vramUsed?: number       // bytes (unified memory on Apple Silicon)
+  vramTotal?: number      // bytes (dedicated VRAM only)
+}
+
+export interface FanInfo {
+  index: number
+  currentRpm: number
+  minRpm: number
+  maxRpm: number
+}
+
+export interface ProcessInfo {
+  pid: number
+  name: string
+  cpuUsage: number        // Percent of one core (can exceed 100)
+  memory: number          // bytes
+  icon?: string           // base64-encoded PNG (apps only, with icons requested)
+}
+
 export interface MemoryInfo {
   total: number         // bytes
   used: number          // bytes
   usage: number         // 0-100
+  swapUsed: number      // bytes
+  swapTotal: number     // bytes
+  pressure: 'normal' | 'warning' | 'critical'
+}
+
+// Breakdown matching Activity Monitor (all bytes)
+export interface MemoryDetail {
+  total: number
+  app: number           // App memory (non-purgeable anonymous pages)
+  wired: number
+  active: number
+  inactive: number
+  compressed: number
+  cached: number        // File-backed and purgeable, reclaimable
+  free: number
+  used: number          // app + wired + compressed ("Memory Used")
+  swapUsed: number
+  swapTotal: number
+  pressure: 'normal' | 'warning' | 'critical'
 }
 
+export type HistoryMetric = 'cpu' | 'memory' | 'networkIn' | 'networkOut'
+
+export interface HistorySample {
+  timestamp: number     // Unix time (ms)
+  value: number         // Percent for cpu/memory, bytes/s for network
+}
+
+export type NetworkInterfaceType = 'wifi' | 'ethernet' | 'loopback' | 'vpn' | 'bridge' | 'other'
+
 export interface NetworkInfo {
-  interface: string
-  type: 'wifi' | 'ethernet' | 'unknown'
+  interface: string         // Interface carrying the default route
+  type: NetworkInterfaceType | 'unknown'
   ssid?: string
   signalStrength?: number  // 0-100
+  band?: '2.4GHz' | '5GHz' | '6GHz'
+  channel?: number
+  txRateMbps?: number       // Current link rate
   connected: boolean
 }
 
+export interface NetworkInterfaceInfo {
+  name: string              // BSD name, e.g. "en0", "utun3"
+  type: NetworkInterfaceType
+  addresses: string[]       // CIDR notation
+  macAddress: string | null
+  connected: boolean        // Has a routable (non link-local) address
+  primary: boolean          // Carries the default route
+}
+
 export interface DateInfo {
   timestamp: number
   formatted: string
@@ -82,12 +153,48 @@ export interface ActiveAppInfo {
   pid?: number
 }
 
+export interface ActiveWindowTitle {
+  title: string | null    // null without Accessibility permission
+  bundleId: string | null
+  pid: number | null
+}
+
+export interface RunningAppInfo {
+  name: string
+  bundleId?: string
+  pid: number
+  isActive: boolean
+  isHidden: boolean
+}
+
+export interface CalendarEvent {
+  id?: string
+  title: string
+  start: number             // Unix timestamp (ms)
+  end: number               // Unix timestamp (ms)
+  allDay: boolean
+  calendar: string          // Calendar title
+  calendarId: string
+  location?: string
+}
+
+export interface CalendarInfo {
+  id: string
+  title: string
+  source?: string           // Account, e.g. "iCloud"
+}
+
 export interface DiskInfo {
   total: number           // bytes
   used: number            // bytes
   available: number       // bytes
   usage: number           // 0-100
   mountPoint: string
+  removable: boolean
+  fileSystem: string      // e.g. "apfs", "exfat"
+  // System-wide disk throughput, only on the "/" entry (null elsewhere and on first read)
+  readBytesPerSec: number | null
+  writeBytesPerSec: number | null
 }
 
 export interface BrightnessInfo {
@@ -0,0 +1,57 @@
+//! Accessibility display preferences
+//!
+//! Reads NSWorkspace's `accessibilityDisplayShould*` properties so widgets
+//! can respect reduce motion, reduce transparency and contrast settings.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityPrefs {
+    pub reduce_motion: bool,
+    pub reduce_transparency: bool,
+    pub increase_contrast: bool,
+    pub differentiate_without_color: bool,
+}
+
+/// Read the current accessibility display preferences
+#[cfg(target_os = "macos")]
+pub fn read_accessibility_prefs() -> AccessibilityPrefs {
+    use objc2::msg_send;
+    use objc2::runtime::Bool;
+    use objc2_app_kit::NSWorkspace;
+
+    let workspace = NSWorkspace::sharedWorkspace();
+    unsafe {
+        let reduce_motion: Bool = msg_send![&*workspace, accessibilityDisplayShouldReduceMotion];
+        let reduce_transparency: Bool =
+            msg_send![&*workspace, accessibilityDisplayShouldReduceTransparency];
+        let increase_contrast: Bool =
+            msg_send![&*workspace, accessibilityDisplayShouldIncreaseContrast];
+        let differentiate_without_color: Bool =
+            msg_send![&*workspace, accessibilityDisplayShouldDifferentiateWithoutColor];
+
+        AccessibilityPrefs {
+            reduce_motion: reduce_motion.as_bool(),
+            reduce_transparency: reduce_transparency.as_bool(),
+            increase_contrast: increase_contrast.as_bool(),
+            differentiate_without_color: differentiate_without_color.as_bool(),
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_accessibility_prefs() -> AccessibilityPrefs {
+    AccessibilityPrefs::default()
+}
+
+// ============================================
+// Accessibility Commands
+// ============================================
+
+/// Get reduce motion / transparency and contrast preferences
+#[command]
+pub fn get_accessibility_prefs() -> Result<AccessibilityPrefs, String> {
+    Ok(read_accessibility_prefs())
+}
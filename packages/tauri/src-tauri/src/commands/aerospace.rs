@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::process::Command;
-use tauri::command;
+use tauri::{command, AppHandle, Emitter, Manager};
+
+use super::events;
+use super::icons::app_icons_by_name;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Window {
@@ -21,6 +25,13 @@ pub struct Workspace {
     pub monitor: i32,
 }
 
+/// Focused window together with its workspace
+#[derive(Debug, Clone, Serialize)]
+pub struct FocusedWindow {
+    pub workspace: Option<Workspace>,
+    pub window: Option<Window>,
+}
+
 #[derive(Debug, Deserialize)]
 struct AerospaceWorkspace {
     workspace: String,
@@ -29,18 +40,50 @@ struct AerospaceWorkspace {
     monitor_id: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
-struct AerospaceWindow {
+#[derive(Debug, Clone, Deserialize)]
+pub struct AerospaceWindow {
     #[serde(rename = "window-id")]
-    window_id: i64,
+    pub window_id: i64,
     #[serde(rename = "app-name")]
-    app_name: String,
+    pub app_name: String,
     #[serde(rename = "window-title")]
-    window_title: Option<String>,
+    pub window_title: Option<String>,
+    pub workspace: Option<String>,
+    #[serde(rename = "app-bundle-id")]
+    pub app_bundle_id: Option<String>,
+}
+
+impl From<AerospaceWindow> for Window {
+    fn from(w: AerospaceWindow) -> Self {
+        Window {
+            id: w.window_id,
+            app: w.app_name,
+            title: w.window_title.unwrap_or_default(),
+            focused: false,
+        }
+    }
 }
 
 const AEROSPACE_PATH: &str = "/opt/homebrew/bin/aerospace";
 
+/// Output format for `list-windows` including workspace and bundle id
+const LIST_WINDOWS_FORMAT: &str =
+    "%{window-id} %{app-name} %{window-title} %{workspace} %{app-bundle-id}";
+
+/// Arguments accepted by `aerospace layout`
+const LAYOUTS: &[&str] = &[
+    "h_tiles",
+    "v_tiles",
+    "h_accordion",
+    "v_accordion",
+    "tiles",
+    "accordion",
+    "horizontal",
+    "vertical",
+    "tiling",
+    "floating",
+];
+
 /// Sync version for internal use (CLI, IPC)
 fn run_aerospace_command(args: &[&str]) -> Result<String, String> {
     let output = Command::new(AEROSPACE_PATH)
@@ -75,6 +118,18 @@ async fn run_aerospace_command_async(args: &[&str]) -> Result<String, String> {
     .map_err(|e| format!("Task join error: {}", e))?
 }
 
+/// List windows across all workspaces in a single aerospace call
+pub fn list_all_windows_sync() -> Result<Vec<AerospaceWindow>, String> {
+    let output = run_aerospace_command(&[
+        "list-windows",
+        "--all",
+        "--json",
+        "--format",
+        LIST_WINDOWS_FORMAT,
+    ])?;
+    serde_json::from_str(&output).map_err(|e| format!("Failed to parse windows JSON: {}", e))
+}
+
 /// Internal sync function for CLI use
 pub fn aerospace_get_workspaces_sync() -> Result<Vec<Workspace>, String> {
     // Get all workspaces
@@ -86,53 +141,40 @@ pub fn aerospace_get_workspaces_sync() -> Result<Vec<Workspace>, String> {
     let focused_output = run_aerospace_command(&["list-workspaces", "--focused"])?;
     let focused_id = focused_output.trim();
 
-    // Get visible workspaces by querying each monitor
-    // aerospace requires --monitor with --visible
-    let monitors_output = run_aerospace_command(&["list-monitors"]);
-    let mut visible_ids: Vec<String> = Vec::new();
-
-    if let Ok(monitors) = monitors_output {
-        for monitor in monitors.lines() {
-            let monitor_id = monitor.trim();
-            if !monitor_id.is_empty() {
-                if let Ok(visible) = run_aerospace_command(&["list-workspaces", "--monitor", monitor_id, "--visible"]) {
-                    for ws in visible.lines() {
-                        let ws_trimmed = ws.trim();
-                        if !ws_trimmed.is_empty() {
-                            visible_ids.push(ws_trimmed.to_string());
-                        }
-                    }
-                }
-            }
+    // Get visible workspaces across all monitors in one call (`--monitor all`)
+    let visible_ids: Vec<String> =
+        run_aerospace_command(&["list-workspaces", "--monitor", "all", "--visible"])
+            .map(|visible| {
+                visible
+                    .lines()
+                    .map(|ws| ws.trim())
+                    .filter(|ws| !ws.is_empty())
+                    .map(|ws| ws.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+    // Fetch all windows once and bucket them by workspace
+    let mut windows_by_workspace: HashMap<String, Vec<Window>> = HashMap::new();
+    for window in list_all_windows_sync().unwrap_or_default() {
+        if let Some(workspace) = window.workspace.clone() {
+            windows_by_workspace
+                .entry(workspace)
+                .or_default()
+                .push(Window::from(window));
         }
     }
 
     // Build workspace list with windows per workspace
     let workspaces: Vec<Workspace> = aerospace_workspaces
         .into_iter()
-        .map(|ws| {
-            // Get windows for this specific workspace
-            let ws_windows: Vec<Window> = run_aerospace_command(&["list-windows", "--workspace", &ws.workspace, "--json"])
-                .ok()
-                .and_then(|output| serde_json::from_str::<Vec<AerospaceWindow>>(&output).ok())
-                .unwrap_or_default()
-                .into_iter()
-                .map(|w| Window {
-                    id: w.window_id,
-                    app: w.app_name,
-                    title: w.window_title.unwrap_or_default(),
-                    focused: false,
-                })
-                .collect();
-
-            Workspace {
-                id: ws.workspace.clone(),
-                display_name: None,
-                focused: ws.workspace == focused_id,
-                visible: visible_ids.iter().any(|v| v == &ws.workspace),
-                windows: ws_windows,
-                monitor: ws.monitor_id.or(ws.monitor).unwrap_or(0),
-            }
+        .map(|ws| Workspace {
+            id: ws.workspace.clone(),
+            display_name: None,
+            focused: ws.workspace == focused_id,
+            visible: visible_ids.iter().any(|v| v == &ws.workspace),
+            windows: windows_by_workspace.remove(&ws.workspace).unwrap_or_default(),
+            monitor: ws.monitor_id.or(ws.monitor).unwrap_or(0),
         })
         .collect();
 
@@ -147,6 +189,27 @@ pub async fn aerospace_get_workspaces() -> Result<Vec<Workspace>, String> {
         .map_err(|e| format!("Task join error: {}", e))?
 }
 
+/// Icons for every app with a window on any workspace, keyed by app name
+///
+/// One `list-windows` call plus one batched icon lookup, instead of fetching
+/// workspaces and then calling `get_app_icons`.
+#[command]
+pub async fn aerospace_get_all_app_icons() -> Result<HashMap<String, Option<String>>, String> {
+    tauri::async_runtime::spawn_blocking(|| {
+        let mut apps: HashMap<String, Option<String>> = HashMap::new();
+        for window in list_all_windows_sync()? {
+            let bundle_id = apps.entry(window.app_name).or_default();
+            if bundle_id.is_none() {
+                *bundle_id = window.app_bundle_id.filter(|id| !id.is_empty());
+            }
+        }
+
+        Ok(app_icons_by_name(apps.into_iter().collect()))
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
 #[command]
 pub async fn aerospace_get_focused_workspace() -> Result<Option<Workspace>, String> {
     let workspaces = aerospace_get_workspaces().await?;
@@ -159,6 +222,136 @@ pub async fn aerospace_focus_workspace(id: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Focus a specific window by its aerospace window ID
+#[command]
+pub async fn aerospace_focus_window(window_id: i64) -> Result<FocusedWindow, String> {
+    let id = window_id.to_string();
+
+    if let Err(e) = run_aerospace_command_async(&["focus", "--window-id", &id]).await {
+        // Distinguish a stale window ID from other aerospace failures
+        let exists = tauri::async_runtime::spawn_blocking(list_all_windows_sync)
+            .await
+            .ok()
+            .and_then(|result| result.ok())
+            .map(|windows| windows.iter().any(|w| w.window_id == window_id))
+            .unwrap_or(true);
+
+        if !exists {
+            return Err(format!("Window {} no longer exists", window_id));
+        }
+        return Err(e);
+    }
+
+    tauri::async_runtime::spawn_blocking(get_focused_window_sync)
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Move a window to another workspace
+#[command]
+pub async fn aerospace_move_window_to_workspace(
+    window_id: i64,
+    workspace: String,
+) -> Result<(), String> {
+    let id = window_id.to_string();
+    run_aerospace_command_async(&["move-node-to-workspace", "--window-id", &id, &workspace])
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to move window {} to workspace {}: {}",
+                window_id,
+                workspace,
+                e.trim()
+            )
+        })?;
+    Ok(())
+}
+
+/// Get the root container layout of the focused workspace (e.g. `h_tiles`, `v_accordion`)
+#[command]
+pub async fn aerospace_get_layout() -> Result<String, String> {
+    let output = run_aerospace_command_async(&[
+        "list-workspaces",
+        "--focused",
+        "--format",
+        "%{workspace-root-container-layout}",
+    ])
+    .await?;
+
+    let layout = output.trim();
+    if layout.is_empty() {
+        return Err("No focused workspace".to_string());
+    }
+    Ok(layout.to_string())
+}
+
+/// Change the layout of the focused window's container
+///
+/// Nothing is returned: aerospace only reports the workspace root layout,
+/// which differs from the changed container when it is nested.
+#[command]
+pub async fn aerospace_set_layout(layout: String) -> Result<(), String> {
+    if !LAYOUTS.contains(&layout.as_str()) {
+        return Err(format!(
+            "Unknown layout '{}' (expected one of: {})",
+            layout,
+            LAYOUTS.join(", ")
+        ));
+    }
+
+    run_aerospace_command_async(&["layout", &layout])
+        .await
+        .map_err(|e| format!("Failed to set layout {}: {}", layout, e.trim()))?;
+    Ok(())
+}
+
+/// Get the focused window and its workspace
+pub fn get_focused_window_sync() -> Result<FocusedWindow, String> {
+    let focused_output = run_aerospace_command(&["list-workspaces", "--focused"])?;
+    let focused_id = focused_output.trim();
+
+    let window: Option<Window> = run_aerospace_command(&[
+        "list-windows",
+        "--focused",
+        "--json",
+        "--format",
+        LIST_WINDOWS_FORMAT,
+    ])
+    .ok()
+    .and_then(|output| serde_json::from_str::<Vec<AerospaceWindow>>(&output).ok())
+    .and_then(|windows| windows.into_iter().next())
+    .map(|w| Window {
+        focused: true,
+        ..Window::from(w)
+    });
+
+    let workspace = if focused_id.is_empty() {
+        None
+    } else {
+        get_workspace_by_id(focused_id, true).map(|mut ws| {
+            if let Some(focused) = &window {
+                for w in ws.windows.iter_mut() {
+                    w.focused = w.id == focused.id;
+                }
+            }
+            ws
+        })
+    };
+
+    Ok(FocusedWindow { workspace, window })
+}
+
+/// Get the ID of the focused workspace
+pub fn get_focused_workspace_id_sync() -> Result<String, String> {
+    let output = run_aerospace_command(&["list-workspaces", "--focused"])?;
+    Ok(output.trim().to_string())
+}
+
+/// Whether the aerospace binary is installed
+pub fn is_aerospace_available() -> bool {
+    std::path::Path::new(AEROSPACE_PATH).exists()
+}
+
 /// Get a single workspace by ID (optimized for focus change events)
 pub fn get_workspace_by_id(id: &str, is_focused: bool) -> Option<Workspace> {
     // Get windows for this workspace
@@ -167,12 +360,7 @@ pub fn get_workspace_by_id(id: &str, is_focused: bool) -> Option<Workspace> {
         .and_then(|output| serde_json::from_str::<Vec<AerospaceWindow>>(&output).ok())
         .unwrap_or_default()
         .into_iter()
-        .map(|w| Window {
-            id: w.window_id,
-            app: w.app_name,
-            title: w.window_title.unwrap_or_default(),
-            focused: false,
-        })
+        .map(Window::from)
         .collect();
 
     Some(Workspace {
@@ -184,3 +372,19 @@ pub fn get_workspace_by_id(id: &str, is_focused: bool) -> Option<Workspace> {
         monitor: 0, // Not critical for focus change events
     })
 }
+
+/// Emit `aerospace-focus-changed` with the focused and previous workspaces
+pub fn emit_focus_changed(app: &AppHandle, focused_id: &str, prev_id: Option<&str>) {
+    let focused_ws = get_workspace_by_id(focused_id, true);
+    let prev_ws = prev_id.and_then(|id| get_workspace_by_id(id, false));
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.emit(
+            events::AEROSPACE_FOCUS_CHANGED,
+            serde_json::json!({
+                "focused": focused_ws,
+                "prev": prev_ws
+            }),
+        );
+    }
+}
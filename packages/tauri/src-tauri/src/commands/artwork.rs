@@ -0,0 +1,131 @@
+//! Album artwork for Now Playing media
+//!
+//! Artwork is returned as a `data:` URL (or the https URL Spotify exposes)
+//! and cached per track so polling doesn't re-encode the same image.
+
+use base64::Engine;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Tracks kept in the artwork cache
+const ARTWORK_CACHE_MAX: usize = 16;
+
+/// Distinguishes the temp files of concurrent Music artwork fetches
+static ARTWORK_FILE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// (title, artist) -> artwork URL (None = track has no artwork)
+static ARTWORK_CACHE: Lazy<Mutex<HashMap<(String, String), Option<String>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Look up artwork for a track, calling `fetch` only on a cache miss
+pub fn cached_artwork(
+    title: Option<&str>,
+    artist: Option<&str>,
+    fetch: impl FnOnce() -> Option<String>,
+) -> Option<String> {
+    let title = title?;
+    let key = (title.to_string(), artist.unwrap_or_default().to_string());
+
+    if let Ok(cache) = ARTWORK_CACHE.lock() {
+        if let Some(artwork) = cache.get(&key) {
+            return artwork.clone();
+        }
+    }
+
+    let artwork = fetch();
+
+    if let Ok(mut cache) = ARTWORK_CACHE.lock() {
+        if cache.len() >= ARTWORK_CACHE_MAX {
+            cache.clear();
+        }
+        cache.insert(key, artwork.clone());
+    }
+
+    artwork
+}
+
+/// Encode image bytes as a data URL, sniffing PNG vs JPEG when no MIME type is given
+pub fn to_data_url(bytes: &[u8], mime_type: Option<&str>) -> Option<String> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mime_type = mime_type.unwrap_or(if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "image/png"
+    } else {
+        "image/jpeg"
+    });
+
+    Some(format!(
+        "data:{};base64,{}",
+        mime_type,
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    ))
+}
+
+/// Create an empty owner-only temp file with a name unique to this call
+///
+/// `create_new` refuses an existing file or symlink, so another user can't
+/// plant the file AppleScript writes to.
+fn create_artwork_file() -> Option<PathBuf> {
+    let id = ARTWORK_FILE_ID.fetch_add(1, Ordering::Relaxed);
+    let path =
+        std::env::temp_dir().join(format!("fluopanel-artwork-{}-{}", std::process::id(), id));
+
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)
+        .ok()?;
+    Some(path)
+}
+
+/// Read the current track's artwork from Spotify or Music via AppleScript
+pub fn fetch_applescript_artwork(app: &str) -> Option<String> {
+    match app {
+        // Spotify only exposes a CDN URL for the artwork
+        "Spotify" => {
+            let output = Command::new("osascript")
+                .args(["-e", r#"tell application "Spotify" to return artwork url of current track"#])
+                .output()
+                .ok()?;
+            let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (output.status.success() && url.starts_with("http")).then_some(url)
+        }
+        // Music exposes raw image data; write it to a temp file and encode it
+        "Music" => {
+            let path = create_artwork_file()?;
+            let script = format!(
+                r#"
+                tell application "Music"
+                    if (count of artworks of current track) is 0 then return ""
+                    set artData to raw data of artwork 1 of current track
+                end tell
+                set artFile to open for access (POSIX file "{}") with write permission
+                set eof artFile to 0
+                write artData to artFile
+                close access artFile
+                return "ok"
+                "#,
+                path.display()
+            );
+
+            let output = Command::new("osascript").args(["-e", &script]).output();
+            let written = output.is_ok_and(|output| {
+                output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "ok"
+            });
+            let bytes = if written { std::fs::read(&path).ok() } else { None };
+            let _ = std::fs::remove_file(&path);
+
+            to_data_url(&bytes?, None)
+        }
+        _ => None,
+    }
+}
@@ -1,6 +1,7 @@
 //! Brightness control for macOS displays
 //!
 //! Uses IOKit DisplayServices for native brightness control.
+//! Displays are addressed by CGDirectDisplayID; `None` means the main display.
 
 #![cfg(target_os = "macos")]
 
@@ -20,25 +21,144 @@ extern "C" {
         parameter: *const i8,
         value: f32,
     ) -> i32;
+
+    fn IODisplayCreateInfoDictionary(
+        framebuffer: u32,
+        options: u32,
+    ) -> core_foundation_sys::dictionary::CFDictionaryRef;
 }
 
 #[link(name = "CoreGraphics", kind = "framework")]
 extern "C" {
     fn CGMainDisplayID() -> u32;
     fn CGDisplayIOServicePort(display: u32) -> u32;
+    fn CGGetActiveDisplayList(max_displays: u32, displays: *mut u32, count: *mut u32) -> i32;
 }
 
 const IOKIT_SUCCESS: i32 = 0;
 const BRIGHTNESS_KEY: &[u8] = b"brightness\0";
 
-/// Get the current brightness of the main display (0.0 - 1.0)
-pub fn get_brightness() -> Result<f32, String> {
+/// kIODisplayOnlyPreferredName: only include the preferred-language product name
+const IODISPLAY_ONLY_PREFERRED_NAME: u32 = 0x0000_0200;
+
+/// Upper bound for CGGetActiveDisplayList
+const MAX_DISPLAYS: u32 = 16;
+
+/// Resolve an optional display id (None = main display)
+fn resolve_display(display_id: Option<u32>) -> u32 {
+    display_id.unwrap_or_else(main_display_id)
+}
+
+/// CGDirectDisplayID of the main display
+pub fn main_display_id() -> u32 {
+    unsafe { CGMainDisplayID() }
+}
+
+/// CGDirectDisplayIDs of all active displays
+pub fn active_display_ids() -> Result<Vec<u32>, String> {
+    let mut displays = vec![0u32; MAX_DISPLAYS as usize];
+    let mut count: u32 = 0;
+
+    let result = unsafe { CGGetActiveDisplayList(MAX_DISPLAYS, displays.as_mut_ptr(), &mut count) };
+    if result != 0 {
+        return Err(format!("Failed to list displays: {}", result));
+    }
+
+    displays.truncate(count as usize);
+    Ok(displays)
+}
+
+/// Product name of a display (IOKit, falling back to NSScreen.localizedName)
+pub fn display_name(display_id: u32) -> Option<String> {
+    product_name_from_iokit(display_id).or_else(|| screen_localized_name(display_id))
+}
+
+/// Read DisplayProductName from the display's IOKit info dictionary
+fn product_name_from_iokit(display_id: u32) -> Option<String> {
+    use core_foundation_sys::base::CFRelease;
+    use core_foundation_sys::dictionary::{
+        CFDictionaryGetCount, CFDictionaryGetKeysAndValues, CFDictionaryGetValue,
+        CFDictionaryRef,
+    };
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
     unsafe {
-        let display_id = CGMainDisplayID();
         let service = CGDisplayIOServicePort(display_id);
+        if service == 0 {
+            return None;
+        }
+
+        let info = IODisplayCreateInfoDictionary(service, IODISPLAY_ONLY_PREFERRED_NAME);
+        if info.is_null() {
+            return None;
+        }
+
+        // DisplayProductName maps language codes to names; take the (only) entry
+        let key = NSString::from_str("DisplayProductName");
+        let names = CFDictionaryGetValue(info, &*key as *const NSString as *const c_void)
+            as CFDictionaryRef;
+
+        let mut name = None;
+        if !names.is_null() && CFDictionaryGetCount(names) > 0 {
+            let count = CFDictionaryGetCount(names) as usize;
+            let mut keys = vec![std::ptr::null(); count];
+            let mut values = vec![std::ptr::null(); count];
+            CFDictionaryGetKeysAndValues(names, keys.as_mut_ptr(), values.as_mut_ptr());
+
+            // CFString is toll-free bridged to NSString
+            if let Some(value) = values.first().filter(|v| !v.is_null()) {
+                name = Some((*(*value as *const NSString)).to_string());
+            }
+        }
+
+        CFRelease(info as *const c_void);
+        name.filter(|n| !n.is_empty())
+    }
+}
 
+/// NSScreen.localizedName for the screen with this display id
+fn screen_localized_name(display_id: u32) -> Option<String> {
+    use objc2::{class, msg_send, runtime::AnyObject};
+    use objc2_foundation::NSString;
+
+    unsafe {
+        let screens: *const AnyObject = msg_send![class!(NSScreen), screens];
+        if screens.is_null() {
+            return None;
+        }
+
+        let count: usize = msg_send![screens, count];
+        for i in 0..count {
+            let screen: *const AnyObject = msg_send![screens, objectAtIndex: i];
+            let description: *const AnyObject = msg_send![screen, deviceDescription];
+            let number: *const AnyObject =
+                msg_send![description, objectForKey: &*NSString::from_str("NSScreenNumber")];
+            if number.is_null() {
+                continue;
+            }
+
+            let screen_id: u32 = msg_send![number, unsignedIntValue];
+            if screen_id == display_id {
+                let name: *const NSString = msg_send![screen, localizedName];
+                return (!name.is_null()).then(|| (*name).to_string());
+            }
+        }
+        None
+    }
+}
+
+/// Whether the display's brightness can be read natively
+pub fn supports_brightness(display_id: u32) -> bool {
+    read_native_brightness(display_id).is_some()
+}
+
+/// Read brightness via IODisplay (None if unsupported, e.g. most external displays)
+fn read_native_brightness(display_id: u32) -> Option<f32> {
+    unsafe {
+        let service = CGDisplayIOServicePort(display_id);
         if service == 0 {
-            return Err("Failed to get display service port".to_string());
+            return None;
         }
 
         let mut brightness: f32 = 0.0;
@@ -49,21 +169,29 @@ pub fn get_brightness() -> Result<f32, String> {
             &mut brightness,
         );
 
-        if result == IOKIT_SUCCESS {
-            Ok(brightness)
-        } else {
-            // Fallback: try using AppleScript for external displays
-            get_brightness_fallback()
-        }
+        (result == IOKIT_SUCCESS).then_some(brightness)
+    }
+}
+
+/// Get the current brightness of a display (0.0 - 1.0)
+/// Fails for displays without native brightness (see `supports_brightness`)
+pub fn get_brightness(display_id: Option<u32>) -> Result<f32, String> {
+    let display_id = resolve_display(display_id);
+
+    if unsafe { CGDisplayIOServicePort(display_id) } == 0 {
+        return Err("Failed to get display service port".to_string());
     }
+
+    read_native_brightness(display_id)
+        .ok_or_else(|| "Brightness is not available for this display".to_string())
 }
 
-/// Set the brightness of the main display (0.0 - 1.0)
-pub fn set_brightness(brightness: f32) -> Result<(), String> {
+/// Set the brightness of a display (0.0 - 1.0)
+pub fn set_brightness(brightness: f32, display_id: Option<u32>) -> Result<(), String> {
     let brightness = brightness.clamp(0.0, 1.0);
+    let display_id = resolve_display(display_id);
 
     unsafe {
-        let display_id = CGMainDisplayID();
         let service = CGDisplayIOServicePort(display_id);
 
         if service == 0 {
@@ -86,29 +214,6 @@ pub fn set_brightness(brightness: f32) -> Result<(), String> {
     }
 }
 
-/// Fallback brightness getter using system_profiler
-fn get_brightness_fallback() -> Result<f32, String> {
-    use std::process::Command;
-
-    // Use osascript as fallback for external displays
-    let _ = Command::new("osascript")
-        .args(["-e", "tell application \"System Preferences\" to quit"])
-        .output();
-
-    // Try brightness from system_profiler
-    let output = Command::new("system_profiler")
-        .args(["SPDisplaysDataType", "-json"])
-        .output()
-        .map_err(|e| format!("Failed to get display info: {}", e))?;
-
-    if output.status.success() {
-        // Default to 0.5 if we can't parse
-        Ok(0.5)
-    } else {
-        Err("Failed to get brightness".to_string())
-    }
-}
-
 /// Fallback brightness setter
 fn set_brightness_fallback(_brightness: f32) -> Result<(), String> {
     // Native brightness control not available for external displays
@@ -0,0 +1,272 @@
+//! Calendar (read-only)
+//!
+//! Reads events and calendars through EventKit. Calendar access is requested
+//! on first use; macOS only prompts once and afterwards answers with the
+//! stored decision, so a denied permission has to be changed in System
+//! Settings > Privacy & Security > Calendars.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    pub id: Option<String>,
+    pub title: String,
+    /// Unix timestamp (ms)
+    pub start: i64,
+    /// Unix timestamp (ms)
+    pub end: i64,
+    pub all_day: bool,
+    pub calendar: String,
+    pub calendar_id: String,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarInfo {
+    pub id: String,
+    pub title: String,
+    /// Account the calendar belongs to (e.g. "iCloud")
+    pub source: Option<String>,
+}
+
+#[cfg(target_os = "macos")]
+mod event_kit {
+    use super::{CalendarEvent, CalendarInfo};
+    use block2::RcBlock;
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyObject, Bool};
+    use objc2::{class, msg_send, sel};
+    use objc2_foundation::NSString;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[link(name = "EventKit", kind = "framework")]
+    extern "C" {}
+
+    /// EKEntityTypeEvent
+    const ENTITY_TYPE_EVENT: usize = 0;
+
+    /// EKAuthorizationStatus values
+    const STATUS_NOT_DETERMINED: isize = 0;
+    const STATUS_FULL_ACCESS: isize = 3;
+
+    /// How long to wait for the user to answer the permission prompt
+    const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+    const DENIED: &str =
+        "Calendar access denied (enable it in System Settings > Privacy & Security > Calendars)";
+
+    unsafe fn string(object: *const AnyObject) -> Option<String> {
+        (!object.is_null()).then(|| (*(object as *const NSString)).to_string())
+    }
+
+    unsafe fn timestamp_ms(date: *const AnyObject) -> i64 {
+        if date.is_null() {
+            return 0;
+        }
+        let seconds: f64 = msg_send![date, timeIntervalSince1970];
+        (seconds * 1000.0) as i64
+    }
+
+    fn authorization_status() -> isize {
+        unsafe {
+            msg_send![
+                class!(EKEventStore),
+                authorizationStatusForEntityType: ENTITY_TYPE_EVENT
+            ]
+        }
+    }
+
+    /// Ask for calendar access (prompts only while undetermined)
+    fn request_access(store: &AnyObject) -> Result<(), String> {
+        let (tx, rx) = mpsc::channel();
+        let block = RcBlock::new(move |granted: Bool, _error: *mut AnyObject| {
+            let _ = tx.send(granted.as_bool());
+        });
+
+        unsafe {
+            // macOS 14 replaced requestAccessToEntityType: with full/write-only variants
+            let full_access: bool =
+                msg_send![store, respondsToSelector: sel!(requestFullAccessToEventsWithCompletion:)];
+            if full_access {
+                let _: () = msg_send![store, requestFullAccessToEventsWithCompletion: &*block];
+            } else {
+                let _: () = msg_send![
+                    store,
+                    requestAccessToEntityType: ENTITY_TYPE_EVENT,
+                    completion: &*block
+                ];
+            }
+        }
+
+        match rx.recv_timeout(AUTHORIZATION_TIMEOUT) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(DENIED.to_string()),
+            Err(_) => Err("Timed out waiting for calendar permission".to_string()),
+        }
+    }
+
+    /// An event store with read access, requesting it on first use
+    fn authorized_store() -> Result<Retained<AnyObject>, String> {
+        let status = authorization_status();
+        // Authorized (pre-14) and full access share the same value
+        if status != STATUS_FULL_ACCESS && status != STATUS_NOT_DETERMINED {
+            return Err(DENIED.to_string());
+        }
+
+        let store: Option<Retained<AnyObject>> = unsafe { msg_send![class!(EKEventStore), new] };
+        let store = store.ok_or("Failed to create event store")?;
+
+        if status == STATUS_NOT_DETERMINED {
+            request_access(&store)?;
+            // A store created before access was granted doesn't see any calendars
+            let store: Option<Retained<AnyObject>> =
+                unsafe { msg_send![class!(EKEventStore), new] };
+            return store.ok_or_else(|| "Failed to create event store".to_string());
+        }
+
+        Ok(store)
+    }
+
+    unsafe fn calendar_info(calendar: *const AnyObject) -> Option<CalendarInfo> {
+        let id: *const AnyObject = msg_send![calendar, calendarIdentifier];
+        let title: *const AnyObject = msg_send![calendar, title];
+        let source: *const AnyObject = msg_send![calendar, source];
+        let source_title: *const AnyObject = if source.is_null() {
+            std::ptr::null()
+        } else {
+            msg_send![source, title]
+        };
+
+        Some(CalendarInfo {
+            id: string(id)?,
+            title: string(title).unwrap_or_default(),
+            source: string(source_title),
+        })
+    }
+
+    pub fn calendars() -> Result<Vec<CalendarInfo>, String> {
+        let store = authorized_store()?;
+
+        objc2::rc::autoreleasepool(|_| unsafe {
+            let calendars: *const AnyObject =
+                msg_send![&*store, calendarsForEntityType: ENTITY_TYPE_EVENT];
+            if calendars.is_null() {
+                return Ok(Vec::new());
+            }
+
+            let count: usize = msg_send![calendars, count];
+            Ok((0..count)
+                .filter_map(|i| {
+                    let calendar: *const AnyObject = msg_send![calendars, objectAtIndex: i];
+                    calendar_info(calendar)
+                })
+                .collect())
+        })
+    }
+
+    /// Events overlapping the next `hours_ahead` hours, sorted by start
+    pub fn upcoming_events(hours_ahead: u32) -> Result<Vec<CalendarEvent>, String> {
+        let store = authorized_store()?;
+
+        objc2::rc::autoreleasepool(|_| unsafe {
+            let start: *const AnyObject = msg_send![class!(NSDate), date];
+            let end: *const AnyObject = msg_send![
+                class!(NSDate),
+                dateWithTimeIntervalSinceNow: f64::from(hours_ahead) * 3600.0
+            ];
+
+            // nil calendars = all calendars
+            let predicate: *const AnyObject = msg_send![
+                &*store,
+                predicateForEventsWithStartDate: start,
+                endDate: end,
+                calendars: std::ptr::null::<AnyObject>()
+            ];
+            let events: *const AnyObject = msg_send![&*store, eventsMatchingPredicate: predicate];
+            if events.is_null() {
+                return Ok(Vec::new());
+            }
+
+            let count: usize = msg_send![events, count];
+            let mut upcoming: Vec<CalendarEvent> = (0..count)
+                .filter_map(|i| {
+                    let event: *const AnyObject = msg_send![events, objectAtIndex: i];
+                    let calendar: *const AnyObject = msg_send![event, calendar];
+                    let calendar = calendar_info(calendar)?;
+
+                    let id: *const AnyObject = msg_send![event, eventIdentifier];
+                    let title: *const AnyObject = msg_send![event, title];
+                    let start: *const AnyObject = msg_send![event, startDate];
+                    let end: *const AnyObject = msg_send![event, endDate];
+                    let all_day: Bool = msg_send![event, isAllDay];
+                    let location: *const AnyObject = msg_send![event, location];
+
+                    Some(CalendarEvent {
+                        id: string(id),
+                        title: string(title).unwrap_or_default(),
+                        start: timestamp_ms(start),
+                        end: timestamp_ms(end),
+                        all_day: all_day.as_bool(),
+                        calendar: calendar.title,
+                        calendar_id: calendar.id,
+                        location: string(location).filter(|location| !location.is_empty()),
+                    })
+                })
+                .collect();
+
+            upcoming.sort_by_key(|event| event.start);
+            Ok(upcoming)
+        })
+    }
+}
+
+// ============================================
+// Calendar Commands
+// ============================================
+
+/// Events in the next `hours_ahead` hours (asks for calendar access the first time)
+/// `calendar_ids` limits the result to those calendars
+#[command]
+pub async fn get_upcoming_events(
+    hours_ahead: u32,
+    calendar_ids: Option<Vec<String>>,
+) -> Result<Vec<CalendarEvent>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        let mut events =
+            tauri::async_runtime::spawn_blocking(move || event_kit::upcoming_events(hours_ahead))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))??;
+
+        if let Some(ids) = calendar_ids {
+            events.retain(|event| ids.contains(&event.calendar_id));
+        }
+        Ok(events)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (hours_ahead, calendar_ids);
+        Err("Calendar access is only supported on macOS".to_string())
+    }
+}
+
+/// Calendars the user can filter events by
+#[command]
+pub async fn get_calendars() -> Result<Vec<CalendarInfo>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        tauri::async_runtime::spawn_blocking(event_kit::calendars)
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("Calendar access is only supported on macOS".to_string())
+    }
+}
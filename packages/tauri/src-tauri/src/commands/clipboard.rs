@@ -0,0 +1,179 @@
+//! Clipboard access via NSPasteboard
+//!
+//! Reads and writes the general pasteboard. Writes record the resulting
+//! `changeCount` so the clipboard watcher can skip changes we caused.
+
+use std::sync::atomic::{AtomicIsize, Ordering};
+use tauri::command;
+
+/// changeCount right after our last write (-1 = never wrote)
+static OWN_CHANGE_COUNT: AtomicIsize = AtomicIsize::new(-1);
+
+/// Whether the pasteboard's current contents came from `clipboard_write_text`
+pub fn is_own_change(change_count: isize) -> bool {
+    OWN_CHANGE_COUNT.load(Ordering::SeqCst) == change_count
+}
+
+#[cfg(target_os = "macos")]
+mod pasteboard {
+    use objc2::runtime::{AnyObject, Bool};
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+
+    const TYPE_STRING: &str = "public.utf8-plain-text";
+    const TYPE_PNG: &str = "public.png";
+    const TYPE_TIFF: &str = "public.tiff";
+    /// NSBitmapImageFileTypePNG
+    const BITMAP_FILE_TYPE_PNG: usize = 4;
+
+    fn general() -> *const AnyObject {
+        unsafe { msg_send![class!(NSPasteboard), generalPasteboard] }
+    }
+
+    pub fn change_count() -> isize {
+        unsafe { msg_send![general(), changeCount] }
+    }
+
+    pub fn read_text() -> Option<String> {
+        unsafe {
+            let text: *const NSString =
+                msg_send![general(), stringForType: &*NSString::from_str(TYPE_STRING)];
+            (!text.is_null()).then(|| (*text).to_string())
+        }
+    }
+
+    /// Write text and return the new changeCount
+    pub fn write_text(text: &str) -> Result<isize, String> {
+        unsafe {
+            let pasteboard = general();
+            let _: isize = msg_send![pasteboard, clearContents];
+            let ok: Bool = msg_send![
+                pasteboard,
+                setString: &*NSString::from_str(text),
+                forType: &*NSString::from_str(TYPE_STRING)
+            ];
+            if !ok.as_bool() {
+                return Err("Failed to write to clipboard".to_string());
+            }
+        }
+        Ok(change_count())
+    }
+
+    unsafe fn data_bytes(data: *const AnyObject) -> Vec<u8> {
+        let length: usize = msg_send![data, length];
+        let bytes: *const u8 = msg_send![data, bytes];
+        if bytes.is_null() || length == 0 {
+            return Vec::new();
+        }
+        std::slice::from_raw_parts(bytes, length).to_vec()
+    }
+
+    /// PNG bytes of the image on the pasteboard (TIFF is converted to PNG)
+    pub fn read_image_png() -> Option<Vec<u8>> {
+        unsafe {
+            let pasteboard = general();
+
+            let png: *const AnyObject =
+                msg_send![pasteboard, dataForType: &*NSString::from_str(TYPE_PNG)];
+            if !png.is_null() {
+                return Some(data_bytes(png));
+            }
+
+            let tiff: *const AnyObject =
+                msg_send![pasteboard, dataForType: &*NSString::from_str(TYPE_TIFF)];
+            if tiff.is_null() {
+                return None;
+            }
+
+            let rep: *const AnyObject = msg_send![class!(NSBitmapImageRep), imageRepWithData: tiff];
+            if rep.is_null() {
+                return None;
+            }
+            let properties: *const AnyObject = msg_send![class!(NSDictionary), dictionary];
+            let png: *const AnyObject = msg_send![
+                rep,
+                representationUsingType: BITMAP_FILE_TYPE_PNG,
+                properties: properties
+            ];
+            (!png.is_null()).then(|| data_bytes(png))
+        }
+    }
+
+    /// Checks the declared types only, without copying the image data
+    pub fn has_image() -> bool {
+        let types = [NSString::from_str(TYPE_PNG), NSString::from_str(TYPE_TIFF)];
+        let pointers: Vec<*const NSString> =
+            types.iter().map(|t| &**t as *const NSString).collect();
+
+        unsafe {
+            let array: *const AnyObject = msg_send![
+                class!(NSArray),
+                arrayWithObjects: pointers.as_ptr(),
+                count: pointers.len()
+            ];
+            let available: *const NSString = msg_send![general(), availableTypeFromArray: array];
+            !available.is_null()
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub use pasteboard::{change_count, has_image, read_text};
+
+// ============================================
+// Clipboard Commands
+// ============================================
+
+/// Read plain text from the clipboard (None if it holds no text)
+#[command]
+pub fn clipboard_read_text() -> Result<Option<String>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        Ok(pasteboard::read_text())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("Clipboard access is only supported on macOS".to_string())
+    }
+}
+
+/// Replace the clipboard contents with text
+#[command]
+pub fn clipboard_write_text(text: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let change_count = pasteboard::write_text(&text)?;
+        OWN_CHANGE_COUNT.store(change_count, Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = text;
+        Err("Clipboard access is only supported on macOS".to_string())
+    }
+}
+
+/// Read an image from the clipboard as a base64 PNG data URL (None if no image)
+#[command]
+pub fn clipboard_read_image() -> Result<Option<String>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use base64::Engine;
+
+        Ok(pasteboard::read_image_png()
+            .filter(|bytes| !bytes.is_empty())
+            .map(|bytes| {
+                format!(
+                    "data:image/png;base64,{}",
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                )
+            }))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("Clipboard access is only supported on macOS".to_string())
+    }
+}
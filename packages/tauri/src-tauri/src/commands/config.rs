@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use tauri::command;
+use tauri::{command, AppHandle};
+
+use super::constants::hover;
+use super::login_items::apply_launch_at_login;
+use super::theme::{emit_theme_resolved, reset_theme_override};
 
 // ============================================
 // Global Config (fluopanel.json)
@@ -19,6 +23,16 @@ pub struct ThemeConfig {
 pub struct GlobalSettings {
     pub hot_reload: bool,
     pub dev_mode: bool,
+    /// Dwell time before hover focus (autoraise) focuses a window
+    #[serde(default = "default_hover_focus_delay_ms")]
+    pub hover_focus_delay_ms: u64,
+    /// Start Fluopanel at login (unset = leave the system setting alone)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_at_login: Option<bool>,
+}
+
+fn default_hover_focus_delay_ms() -> u64 {
+    120
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +55,219 @@ pub struct UiConfig {
     pub dist_path: Option<String>,
 }
 
+/// Aerospace integration settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AerospaceConfig {
+    /// Poll aerospace for focus changes (disable to rely on `fluopanel focus-changed` via IPC)
+    #[serde(default = "default_aerospace_watch")]
+    pub watch: bool,
+    /// Focus poll interval in milliseconds
+    #[serde(default = "default_aerospace_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+}
+
+fn default_aerospace_watch() -> bool {
+    true
+}
+
+fn default_aerospace_poll_interval_ms() -> u64 {
+    500
+}
+
+impl Default for AerospaceConfig {
+    fn default() -> Self {
+        AerospaceConfig {
+            watch: default_aerospace_watch(),
+            poll_interval_ms: default_aerospace_poll_interval_ms(),
+        }
+    }
+}
+
+/// IPC server settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IpcConfig {
+    /// Optional TCP port for a loopback-only listener alongside the Unix socket
+    ///
+    /// Clients must first send `auth:{token}` with the token from
+    /// `~/.config/fluopanel/ipc-token`, which is regenerated every launch.
+    /// Only enable this when needed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp_port: Option<u16>,
+}
+
+/// Media integration settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaConfig {
+    /// "auto" (Spotify, then Music), "Spotify" or "Music"
+    #[serde(default = "default_media_preferred_app")]
+    pub preferred_app: String,
+}
+
+fn default_media_preferred_app() -> String {
+    "auto".to_string()
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        MediaConfig {
+            preferred_app: default_media_preferred_app(),
+        }
+    }
+}
+
+/// Battery threshold events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryConfig {
+    /// Percent below which `battery-low` fires
+    #[serde(default = "default_battery_low_threshold")]
+    pub low_threshold: f32,
+    /// Percent below which `battery-critical` fires
+    #[serde(default = "default_battery_critical_threshold")]
+    pub critical_threshold: f32,
+}
+
+fn default_battery_low_threshold() -> f32 {
+    20.0
+}
+
+fn default_battery_critical_threshold() -> f32 {
+    10.0
+}
+
+impl Default for BatteryConfig {
+    fn default() -> Self {
+        BatteryConfig {
+            low_threshold: default_battery_low_threshold(),
+            critical_threshold: default_battery_critical_threshold(),
+        }
+    }
+}
+
+/// Clipboard watcher settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardConfig {
+    /// Pasteboard poll interval in milliseconds
+    #[serde(default = "default_clipboard_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+}
+
+fn default_clipboard_poll_interval_ms() -> u64 {
+    500
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        ClipboardConfig {
+            poll_interval_ms: default_clipboard_poll_interval_ms(),
+        }
+    }
+}
+
+/// Shell command restrictions for `execute_shell` / `run_shell`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShellConfig {
+    /// Programs widgets may run, by name ("open") or path ("/usr/bin/open");
+    /// "*" allows any command line. Unset allows everything in debug builds
+    /// and nothing in release builds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// Default time limit before the command is killed
+    #[serde(default = "default_shell_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_shell_timeout_ms() -> u64 {
+    30_000
+}
+
+impl Default for ShellConfig {
+    fn default() -> Self {
+        ShellConfig {
+            allow: None,
+            timeout_ms: default_shell_timeout_ms(),
+        }
+    }
+}
+
+/// Metric history ring buffers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryConfig {
+    /// Samples kept per metric
+    #[serde(default = "default_history_length")]
+    pub length: usize,
+}
+
+fn default_history_length() -> usize {
+    60
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        HistoryConfig {
+            length: default_history_length(),
+        }
+    }
+}
+
+/// Poll intervals of the polling watchers (unset = the watcher's default)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchersConfig {
+    /// Now playing check (default 5000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_interval_ms: Option<u64>,
+    /// Network fallback poll; changes are also picked up from events (default 30000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_interval_ms: Option<u64>,
+    /// CPU, memory, GPU and system stats (default 5000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_interval_ms: Option<u64>,
+}
+
+/// Dismissal tuning for hover-mode popovers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopoverConfig {
+    /// How long the cursor may be outside a hover popover before it closes
+    #[serde(default = "default_hover_close_delay_ms")]
+    pub hover_close_delay_ms: u64,
+    /// Extra hit area (px) around the trigger element
+    #[serde(default = "default_trigger_padding")]
+    pub trigger_padding: f64,
+    /// Cursor polling interval in milliseconds while a hover popover is open
+    #[serde(default = "default_monitor_interval_ms")]
+    pub monitor_interval_ms: u64,
+}
+
+fn default_hover_close_delay_ms() -> u64 {
+    hover::HOVER_CLOSE_DELAY_MS
+}
+
+fn default_trigger_padding() -> f64 {
+    hover::TRIGGER_PADDING
+}
+
+fn default_monitor_interval_ms() -> u64 {
+    hover::WINDOW_MONITOR_INTERVAL_MS
+}
+
+impl Default for PopoverConfig {
+    fn default() -> Self {
+        PopoverConfig {
+            hover_close_delay_ms: default_hover_close_delay_ms(),
+            trigger_padding: default_trigger_padding(),
+            monitor_interval_ms: default_monitor_interval_ms(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FluopanelConfig {
     pub version: u32,
@@ -50,6 +277,24 @@ pub struct FluopanelConfig {
     pub secrets: Option<SecretsConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ui: Option<UiConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aerospace: Option<AerospaceConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipc: Option<IpcConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<MediaConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clipboard: Option<ClipboardConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<BatteryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history: Option<HistoryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<ShellConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub popover: Option<PopoverConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchers: Option<WatchersConfig>,
 }
 
 impl Default for FluopanelConfig {
@@ -63,9 +308,20 @@ impl Default for FluopanelConfig {
             settings: GlobalSettings {
                 hot_reload: true,
                 dev_mode: false,
+                hover_focus_delay_ms: default_hover_focus_delay_ms(),
+                launch_at_login: None,
             },
             secrets: None,
             ui: None,
+            aerospace: None,
+            ipc: None,
+            media: None,
+            clipboard: None,
+            battery: None,
+            history: None,
+            shell: None,
+            popover: None,
+            watchers: None,
         }
     }
 }
@@ -92,24 +348,9 @@ fn get_config_path() -> PathBuf {
 /// 3. None
 pub fn get_ui_dist_path() -> Option<PathBuf> {
     // 1. Check config for custom distPath
-    if let Ok(config) = get_config_sync() {
-        if let Some(ui) = &config.ui {
-            if let Some(dist_path) = &ui.dist_path {
-                // Expand ~ to home directory
-                let expanded = if dist_path.starts_with("~/") {
-                    if let Some(home) = dirs::home_dir() {
-                        home.join(&dist_path[2..])
-                    } else {
-                        PathBuf::from(dist_path)
-                    }
-                } else {
-                    PathBuf::from(dist_path)
-                };
-
-                if expanded.exists() && expanded.join("index.html").exists() {
-                    return Some(expanded);
-                }
-            }
+    if let Some(expanded) = get_configured_ui_dist_path() {
+        if expanded.exists() && expanded.join("index.html").exists() {
+            return Some(expanded);
         }
     }
 
@@ -123,6 +364,53 @@ pub fn get_ui_dist_path() -> Option<PathBuf> {
     None
 }
 
+/// fluopanel.json ui.distPath with ~ expanded (not checked for existence)
+fn get_configured_ui_dist_path() -> Option<PathBuf> {
+    let config = get_config_sync().ok()?;
+    let dist_path = config.ui?.dist_path?;
+
+    // Expand ~ to home directory
+    if let Some(rest) = dist_path.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return Some(home.join(rest));
+        }
+    }
+    Some(PathBuf::from(dist_path))
+}
+
+/// Where the UI is expected to be served from, and what is missing
+#[derive(Debug, Clone)]
+pub struct UiServeInfo {
+    /// Configured ui.distPath, or the default ~/.config/fluopanel/dist/
+    pub expected_path: PathBuf,
+    /// Whether expected_path came from fluopanel.json
+    pub from_config: bool,
+    pub dir_exists: bool,
+    pub index_exists: bool,
+}
+
+impl UiServeInfo {
+    /// The folder exists but has no index.html, so the UI probably hasn't been built
+    pub fn needs_build(&self) -> bool {
+        self.dir_exists && !self.index_exists
+    }
+}
+
+/// Describe where the UI is expected, for the "UI not found" page
+pub fn get_ui_serve_info() -> UiServeInfo {
+    let (expected_path, from_config) = match get_configured_ui_dist_path() {
+        Some(path) => (path, true),
+        None => (get_config_dir().join("dist"), false),
+    };
+
+    UiServeInfo {
+        dir_exists: expected_path.is_dir(),
+        index_exists: expected_path.join("index.html").exists(),
+        expected_path,
+        from_config,
+    }
+}
+
 /// Synchronous config reader for protocol handler
 fn get_config_sync() -> Result<FluopanelConfig, String> {
     let config_path = get_config_path();
@@ -154,8 +442,8 @@ pub fn get_config() -> Result<FluopanelConfig, String> {
     }
 }
 
-#[command]
-pub fn save_config(config: FluopanelConfig) -> Result<(), String> {
+/// Write the config file without side effects
+pub fn write_config(config: &FluopanelConfig) -> Result<(), String> {
     let config_path = get_config_path();
 
     // Create parent directories if they don't exist
@@ -168,7 +456,30 @@ pub fn save_config(config: FluopanelConfig) -> Result<(), String> {
         .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
     fs::write(&config_path, content)
-        .map_err(|e| format!("Failed to write config: {}", e))?;
+        .map_err(|e| format!("Failed to write config: {}", e))
+}
+
+#[command]
+pub fn save_config(app: AppHandle, config: FluopanelConfig) -> Result<(), String> {
+    write_config(&config)?;
+
+    // Keep the login item registration in sync with the saved setting
+    if let Some(enabled) = config.settings.launch_at_login {
+        if let Err(e) = apply_launch_at_login(enabled) {
+            eprintln!("[config] Failed to apply launchAtLogin: {}", e);
+        }
+    }
+
+    // Watcher intervals and popover tuning apply without a restart
+    crate::watchers::apply_intervals(&config.watchers.unwrap_or_default());
+    #[cfg(target_os = "macos")]
+    super::popover_dismiss::apply_config(&config.popover.unwrap_or_default());
+
+    // Saving applies any previewed theme, so the override is no longer needed
+    reset_theme_override();
+
+    // Theme settings may have changed
+    emit_theme_resolved(&app);
 
     Ok(())
 }
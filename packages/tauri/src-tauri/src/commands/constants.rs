@@ -11,4 +11,53 @@ pub mod geometry {
 
     /// Minimum available height for popovers
     pub const MIN_AVAILABLE_HEIGHT: f64 = 100.0;
+
+    /// Default share of a window's width/height kept inside the visible frame
+    pub const DEFAULT_MIN_VISIBLE: f64 = 0.5;
+
+    /// Max width/height used when only the other dimension is limited
+    /// (f64::MAX overflows once scaled to physical pixels)
+    pub const UNBOUNDED_WINDOW_SIZE: f64 = 100_000.0;
+}
+
+/// Display reconfiguration handling (sleep/wake, KVM switches)
+pub mod screen {
+    /// Attempts made while the OS transiently reports zero screens
+    pub const SCREEN_RETRY_ATTEMPTS: u32 = 4;
+
+    /// Initial backoff between attempts (doubled each retry, ~420ms total)
+    pub const SCREEN_RETRY_BASE_DELAY_MS: u64 = 60;
+
+    /// Fallback visible frame (x, y, width, height) when no screen can be found
+    pub const FALLBACK_SCREEN_FRAME: (f64, f64, f64, f64) = (0.0, 25.0, 1440.0, 875.0);
+}
+
+/// Content-driven window resizing
+pub mod resize {
+    /// Default quiet period before a debounced resize is applied
+    pub const DEFAULT_DEBOUNCE_MS: u64 = 50;
+
+    /// Upper bound so a typo can't stall resizes for seconds
+    pub const MAX_DEBOUNCE_MS: u64 = 1000;
+}
+
+/// Dismissal of hover-mode popovers (defaults for the `popover` config section)
+pub mod hover {
+    /// How long the cursor may be outside a hover popover before it closes
+    pub const HOVER_CLOSE_DELAY_MS: u64 = 150;
+
+    /// Longest configurable close delay, so a typo can't pin a popover open
+    pub const MAX_HOVER_CLOSE_DELAY_MS: u64 = 5_000;
+
+    /// Extra hit area around the trigger so the gap to the popover is bridged
+    pub const TRIGGER_PADDING: f64 = 15.0;
+
+    /// Largest configurable trigger padding, so the hit area stays near the trigger
+    pub const MAX_TRIGGER_PADDING: f64 = 100.0;
+
+    /// Cursor polling interval while a hover popover is open
+    pub const WINDOW_MONITOR_INTERVAL_MS: u64 = 100;
+
+    /// Fastest configurable polling interval
+    pub const MIN_WINDOW_MONITOR_INTERVAL_MS: u64 = 16;
 }
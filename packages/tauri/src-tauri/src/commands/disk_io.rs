@@ -0,0 +1,108 @@
+//! System-wide disk throughput
+//!
+//! Sums the cumulative "Bytes (Read)" / "Bytes (Write)" statistics of every
+//! `IOBlockStorageDriver` and turns the difference between two calls into
+//! bytes per second. IOKit doesn't attribute I/O to mount points, so the
+//! rates cover all physical disks together.
+
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Previous sample: (taken at, bytes read, bytes written)
+static LAST_SAMPLE: Lazy<Mutex<Option<(Instant, u64, u64)>>> = Lazy::new(|| Mutex::new(None));
+
+#[cfg(target_os = "macos")]
+mod iokit {
+    use crate::commands::iokit::{
+        cf_str, IOIteratorNext, IOObjectRelease, IORegistryEntryCreateCFProperty,
+        IOServiceGetMatchingServices, IOServiceMatching, IoObject,
+    };
+    use core_foundation_sys::base::CFRelease;
+    use core_foundation_sys::dictionary::{CFDictionaryGetValue, CFDictionaryRef};
+    use core_foundation_sys::number::{kCFNumberSInt64Type, CFNumberGetValue, CFNumberRef};
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
+    unsafe fn dictionary_u64(dictionary: CFDictionaryRef, key: &NSString) -> u64 {
+        let number = CFDictionaryGetValue(dictionary, cf_str(key) as *const c_void) as CFNumberRef;
+        if number.is_null() {
+            return 0;
+        }
+        let mut value: i64 = 0;
+        CFNumberGetValue(number, kCFNumberSInt64Type, &mut value as *mut i64 as *mut c_void);
+        value.max(0) as u64
+    }
+
+    /// Cumulative (bytes read, bytes written) across all block storage drivers
+    pub fn read_counters() -> Option<(u64, u64)> {
+        unsafe {
+            // IOServiceGetMatchingServices consumes the matching dictionary
+            let matching = IOServiceMatching(c"IOBlockStorageDriver".as_ptr());
+            if matching.is_null() {
+                return None;
+            }
+
+            let mut iterator: IoObject = 0;
+            if IOServiceGetMatchingServices(0, matching, &mut iterator) != 0 {
+                return None;
+            }
+
+            let statistics_key = NSString::from_str("Statistics");
+            let read_key = NSString::from_str("Bytes (Read)");
+            let write_key = NSString::from_str("Bytes (Write)");
+
+            let (mut read, mut written) = (0u64, 0u64);
+            loop {
+                let driver = IOIteratorNext(iterator);
+                if driver == 0 {
+                    break;
+                }
+
+                let statistics = IORegistryEntryCreateCFProperty(
+                    driver,
+                    cf_str(&statistics_key),
+                    std::ptr::null(),
+                    0,
+                );
+                if !statistics.is_null() {
+                    read += dictionary_u64(statistics as CFDictionaryRef, &read_key);
+                    written += dictionary_u64(statistics as CFDictionaryRef, &write_key);
+                    CFRelease(statistics);
+                }
+                IOObjectRelease(driver);
+            }
+            IOObjectRelease(iterator);
+
+            Some((read, written))
+        }
+    }
+}
+
+/// System-wide (read, write) bytes per second since the previous call
+///
+/// Returns `None` on the first call (no baseline yet) and off macOS.
+pub fn disk_io_rates() -> Option<(u64, u64)> {
+    #[cfg(target_os = "macos")]
+    let counters = iokit::read_counters();
+    #[cfg(not(target_os = "macos"))]
+    let counters: Option<(u64, u64)> = None;
+
+    let (read, written) = counters?;
+    let now = Instant::now();
+
+    let mut last = LAST_SAMPLE.lock().ok()?;
+    let previous = last.replace((now, read, written));
+    let (taken_at, last_read, last_written) = previous?;
+
+    let elapsed = now.duration_since(taken_at).as_secs_f64();
+    if elapsed <= 0.0 {
+        return None;
+    }
+
+    // Counters reset when a disk is ejected; treat a drop as no activity
+    Some((
+        (read.saturating_sub(last_read) as f64 / elapsed) as u64,
+        (written.saturating_sub(last_written) as f64 / elapsed) as u64,
+    ))
+}
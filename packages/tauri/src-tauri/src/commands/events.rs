@@ -0,0 +1,409 @@
+//! Event Registry
+//!
+//! Every event the backend emits is named here. Emitters use these
+//! constants so `list_events` can't drift from what is actually sent.
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::command;
+
+// ============================================
+// Event Names
+// ============================================
+
+pub const ACCESSIBILITY_PREFS_CHANGED: &str = "accessibility-prefs-changed";
+pub const ACTIVE_APP_CHANGED: &str = "active-app-changed";
+pub const ACTIVE_WINDOW_TITLE_CHANGED: &str = "active-window-title-changed";
+pub const AEROSPACE_FOCUS_CHANGED: &str = "aerospace-focus-changed";
+pub const AEROSPACE_WORKSPACE_CHANGED: &str = "aerospace-workspace-changed";
+pub const ARCANA_FOCUS_CHANGED: &str = "arcana-focus-changed";
+pub const BATTERY_CHANGED: &str = "battery-changed";
+pub const BATTERY_CHARGING_CHANGED: &str = "battery-charging-changed";
+pub const BATTERY_CRITICAL: &str = "battery-critical";
+pub const BATTERY_LOW: &str = "battery-low";
+pub const CLIPBOARD_CHANGED: &str = "clipboard-changed";
+pub const CPU_CHANGED: &str = "cpu-changed";
+pub const FOCUS_MODE_CHANGED: &str = "focus-mode-changed";
+pub const GPU_CHANGED: &str = "gpu-changed";
+pub const LIBS_CHANGED: &str = "libs-changed";
+pub const MEDIA_CHANGED: &str = "media-changed";
+pub const MEMORY_CHANGED: &str = "memory-changed";
+pub const MONITOR_CHANGED: &str = "monitor-changed";
+pub const NETWORK_CHANGED: &str = "network-changed";
+pub const NOTIFICATION_COUNT_CHANGED: &str = "notification-count-changed";
+pub const POPOVER_CLOSED: &str = "popover-closed";
+pub const SYSTEM_STATS_CHANGED: &str = "system-stats-changed";
+pub const THEME_RESOLVED: &str = "theme-resolved";
+pub const THERMAL_CHANGED: &str = "thermal-changed";
+pub const VOLUME_CHANGED: &str = "volume-changed";
+pub const WIDGET_WILL_RELOAD: &str = "widget-will-reload";
+pub const WIDGETS_RELOADED: &str = "widgets-reloaded";
+
+/// Prefix for shared store events (`store-changed:{key}`)
+pub const STORE_CHANGED_PREFIX: &str = "store-changed:";
+
+/// Prefixes for streamed shell command events (`shell-output:{id}`, `shell-exit:{id}`)
+pub const SHELL_OUTPUT_PREFIX: &str = "shell-output:";
+pub const SHELL_EXIT_PREFIX: &str = "shell-exit:";
+
+/// Prefixes for scheduled events (`tick:{id}`, `alarm:{id}`)
+pub const TICK_PREFIX: &str = "tick:";
+pub const ALARM_PREFIX: &str = "alarm:";
+
+/// Prefix used by Tauri's own events (`tauri://...`)
+const TAURI_PREFIX: &str = "tauri";
+
+// ============================================
+// Registry
+// ============================================
+
+/// Description of an event widgets can listen to
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventInfo {
+    /// Event name (`*` marks a wildcard suffix, e.g. `store-changed:*`)
+    pub name: String,
+    pub description: String,
+    /// JSON Schema of the payload
+    pub payload_schema: Value,
+}
+
+fn event(name: &str, description: &str, payload_schema: Value) -> EventInfo {
+    EventInfo {
+        name: name.to_string(),
+        description: description.to_string(),
+        payload_schema,
+    }
+}
+
+/// Schema for an object whose properties are all required unless nullable
+fn object(properties: Value) -> Value {
+    json!({ "type": "object", "properties": properties })
+}
+
+fn nullable(type_name: &str) -> Value {
+    json!({ "type": [type_name, "null"] })
+}
+
+fn workspace_schema() -> Value {
+    object(json!({
+        "id": { "type": "string" },
+        "displayName": nullable("string"),
+        "focused": { "type": "boolean" },
+        "visible": { "type": "boolean" },
+        "windows": {
+            "type": "array",
+            "items": object(json!({
+                "id": { "type": "integer" },
+                "app": { "type": "string" },
+                "title": { "type": "string" },
+                "focused": { "type": "boolean" }
+            }))
+        },
+        "monitor": { "type": "integer" }
+    }))
+}
+
+fn battery_schema() -> Value {
+    object(json!({
+        "percent": { "type": "number" },
+        "charging": { "type": "boolean" },
+        "timeToEmpty": nullable("integer"),
+        "timeToFull": nullable("integer")
+    }))
+}
+
+fn monitor_schema() -> Value {
+    object(json!({
+        "id": { "type": "string" },
+        "name": { "type": "string" },
+        "width": { "type": "number" },
+        "height": { "type": "number" },
+        "x": { "type": "number" },
+        "y": { "type": "number" },
+        "scaleFactor": { "type": "number" }
+    }))
+}
+
+/// All events emitted by the backend
+pub fn registry() -> Vec<EventInfo> {
+    let monitors = json!({ "type": "array", "items": monitor_schema() });
+
+    vec![
+        event(
+            ACCESSIBILITY_PREFS_CHANGED,
+            "Reduce motion, reduce transparency, increase contrast or differentiate without color changed",
+            object(json!({
+                "reduceMotion": { "type": "boolean" },
+                "reduceTransparency": { "type": "boolean" },
+                "increaseContrast": { "type": "boolean" },
+                "differentiateWithoutColor": { "type": "boolean" }
+            })),
+        ),
+        event(
+            ACTIVE_APP_CHANGED,
+            "Frontmost application changed",
+            object(json!({
+                "name": { "type": "string" },
+                "bundleId": nullable("string"),
+                "pid": nullable("integer")
+            })),
+        ),
+        event(
+            ACTIVE_WINDOW_TITLE_CHANGED,
+            "Frontmost app or its focused window title changed (title is null without Accessibility permission)",
+            object(json!({
+                "title": nullable("string"),
+                "bundleId": nullable("string"),
+                "pid": nullable("integer")
+            })),
+        ),
+        event(
+            AEROSPACE_FOCUS_CHANGED,
+            "Focused aerospace workspace changed (main window only)",
+            object(json!({
+                "focused": workspace_schema(),
+                "prev": { "anyOf": [workspace_schema(), { "type": "null" }] }
+            })),
+        ),
+        event(
+            AEROSPACE_WORKSPACE_CHANGED,
+            "Full aerospace workspace list refreshed (main window only)",
+            json!({ "type": "array", "items": workspace_schema() }),
+        ),
+        event(
+            ARCANA_FOCUS_CHANGED,
+            "Keyboard focus moved between Fluopanel windows; payload is the key window label",
+            nullable("string"),
+        ),
+        event(
+            BATTERY_CHANGED,
+            "Battery level or charging state changed",
+            battery_schema(),
+        ),
+        event(
+            BATTERY_CHARGING_CHANGED,
+            "Charger connected or disconnected",
+            battery_schema(),
+        ),
+        event(
+            BATTERY_CRITICAL,
+            "Battery dropped below the critical threshold (battery.criticalThreshold, default 10%)",
+            battery_schema(),
+        ),
+        event(
+            BATTERY_LOW,
+            "Battery dropped below the low threshold (battery.lowThreshold, default 20%)",
+            battery_schema(),
+        ),
+        event(
+            CLIPBOARD_CHANGED,
+            "Clipboard contents changed outside Fluopanel",
+            object(json!({
+                "text": nullable("string"),
+                "hasImage": { "type": "boolean" }
+            })),
+        ),
+        event(
+            CPU_CHANGED,
+            "Periodic CPU usage sample",
+            object(json!({
+                "usage": { "type": "number" },
+                "temperature": nullable("number")
+            })),
+        ),
+        event(
+            FOCUS_MODE_CHANGED,
+            "Focus / Do Not Disturb was turned on, off or switched (enabled is null when unreadable)",
+            object(json!({
+                "enabled": nullable("boolean"),
+                "modeId": nullable("string"),
+                "name": nullable("string")
+            })),
+        ),
+        event(
+            GPU_CHANGED,
+            "Periodic GPU utilization and memory sample (not emitted when no GPU exposes statistics)",
+            json!({
+                "type": "array",
+                "items": object(json!({
+                    "name": { "type": "string" },
+                    "utilization": nullable("number"),
+                    "vramUsed": nullable("integer"),
+                    "vramTotal": nullable("integer")
+                }))
+            }),
+        ),
+        event(
+            LIBS_CHANGED,
+            "A shared runtime library in the dev libs/ directory changed (debug builds only)",
+            object(json!({
+                "file": { "type": "string" }
+            })),
+        ),
+        event(
+            MEDIA_CHANGED,
+            "Now playing media changed",
+            object(json!({
+                "playing": { "type": "boolean" },
+                "title": nullable("string"),
+                "artist": nullable("string"),
+                "album": nullable("string"),
+                "duration": nullable("number"),
+                "position": nullable("number"),
+                "app": nullable("string"),
+                "artworkUrl": nullable("string")
+            })),
+        ),
+        event(
+            MEMORY_CHANGED,
+            "Periodic memory usage sample",
+            object(json!({
+                "total": { "type": "integer" },
+                "used": { "type": "integer" },
+                "usage": { "type": "number" },
+                "swapUsed": { "type": "integer" },
+                "swapTotal": { "type": "integer" },
+                "pressure": { "enum": ["normal", "warning", "critical"] }
+            })),
+        ),
+        event(
+            MONITOR_CHANGED,
+            "Displays were connected, disconnected, rearranged or rescaled",
+            object(json!({
+                "added": monitors,
+                "removed": monitors,
+                "changed": monitors,
+                "monitors": monitors
+            })),
+        ),
+        event(
+            NETWORK_CHANGED,
+            "Primary network interface or Wi-Fi changed",
+            object(json!({
+                "interface": { "type": "string" },
+                "type": { "type": "string" },
+                "ssid": nullable("string"),
+                "signalStrength": nullable("integer"),
+                "band": { "enum": ["2.4GHz", "5GHz", "6GHz", null] },
+                "channel": nullable("integer"),
+                "txRateMbps": nullable("integer"),
+                "connected": { "type": "boolean" }
+            })),
+        ),
+        event(
+            NOTIFICATION_COUNT_CHANGED,
+            "Number of notifications in Notification Center changed",
+            json!({ "type": "integer", "minimum": 0 }),
+        ),
+        event(
+            POPOVER_CLOSED,
+            "A popover was closed; payload is the popover id",
+            json!({ "type": "string" }),
+        ),
+        event(
+            SYSTEM_STATS_CHANGED,
+            "Periodic uptime, load average and process count sample",
+            object(json!({
+                "uptimeSecs": { "type": "integer" },
+                "loadAvg": { "type": "array", "items": { "type": "number" }, "minItems": 3, "maxItems": 3 },
+                "processCount": { "type": "integer" },
+                "bootTime": { "type": "integer" }
+            })),
+        ),
+        event(
+            THEME_RESOLVED,
+            "Effective theme changed (config, preview override or system appearance)",
+            object(json!({
+                "mode": { "enum": ["light", "dark"] },
+                "accentColor": { "type": "string" }
+            })),
+        ),
+        event(
+            THERMAL_CHANGED,
+            "Thermal pressure changed (anything above nominal means throttling)",
+            json!({ "enum": ["nominal", "fair", "serious", "critical"] }),
+        ),
+        event(
+            VOLUME_CHANGED,
+            "Output volume, mute state or output device changed",
+            object(json!({
+                "volume": { "type": "number" },
+                "muted": { "type": "boolean" },
+                "outputDevice": nullable("string")
+            })),
+        ),
+        event(
+            WIDGET_WILL_RELOAD,
+            "Sent to a window about to reload; save state, then call ack_widget_reload",
+            object(json!({
+                "label": { "type": "string" },
+                "timeoutMs": { "type": "integer" }
+            })),
+        ),
+        event(
+            WIDGETS_RELOADED,
+            "reload_all_widgets finished; labels of the windows that reloaded",
+            object(json!({
+                "labels": { "type": "array", "items": { "type": "string" } }
+            })),
+        ),
+        event(
+            &format!("{}*", STORE_CHANGED_PREFIX),
+            "Shared store key was set or deleted (null payload on delete)",
+            json!({}),
+        ),
+        event(
+            &format!("{}*", SHELL_OUTPUT_PREFIX),
+            "A line of output from a command started with execute_shell_stream",
+            object(json!({
+                "stream": { "enum": ["stdout", "stderr"] },
+                "line": { "type": "string" }
+            })),
+        ),
+        event(
+            &format!("{}*", SHELL_EXIT_PREFIX),
+            "A command started with execute_shell_stream exited",
+            object(json!({
+                "code": nullable("integer"),
+                "killed": { "type": "boolean" }
+            })),
+        ),
+        event(
+            &format!("{}*", TICK_PREFIX),
+            "A tick scheduled with schedule_tick is due (sent to the scheduling window)",
+            object(json!({
+                "timestamp": { "type": "integer" }
+            })),
+        ),
+        event(
+            &format!("{}*", ALARM_PREFIX),
+            "An alarm scheduled with schedule_at fired (sent to the scheduling window)",
+            object(json!({
+                "timestamp": { "type": "integer" },
+                "scheduledAt": { "type": "integer" }
+            })),
+        ),
+    ]
+}
+
+/// Whether an event name belongs to the backend or Tauri itself
+pub fn is_reserved(name: &str) -> bool {
+    name.starts_with(TAURI_PREFIX)
+        || name.starts_with(STORE_CHANGED_PREFIX)
+        || name.starts_with(SHELL_OUTPUT_PREFIX)
+        || name.starts_with(SHELL_EXIT_PREFIX)
+        || name.starts_with(TICK_PREFIX)
+        || name.starts_with(ALARM_PREFIX)
+        || registry().iter().any(|event| event.name == name)
+}
+
+// ============================================
+// Event Commands
+// ============================================
+
+/// List all events the backend emits, with payload schemas
+#[command]
+pub fn list_events() -> Vec<EventInfo> {
+    registry()
+}
@@ -0,0 +1,52 @@
+//! Fan speeds
+//!
+//! Reads fan count and speeds from the SMC (`FNum`, `F{n}Ac`, `F{n}Mn`,
+//! `F{n}Mx`). Fanless Macs report no fans.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FanInfo {
+    pub index: u32,
+    pub current_rpm: f64,
+    pub min_rpm: f64,
+    pub max_rpm: f64,
+}
+
+/// Read every fan (empty on fanless Macs or if the SMC is unavailable)
+#[cfg(target_os = "macos")]
+pub fn read_fans() -> Vec<FanInfo> {
+    use super::smc::with_smc;
+
+    with_smc(|smc| {
+        let count = smc.read("FNum").unwrap_or(0.0) as u32;
+        (0..count)
+            .filter_map(|index| {
+                Some(FanInfo {
+                    index,
+                    current_rpm: smc.read(&format!("F{}Ac", index))?,
+                    min_rpm: smc.read(&format!("F{}Mn", index)).unwrap_or(0.0),
+                    max_rpm: smc.read(&format!("F{}Mx", index)).unwrap_or(0.0),
+                })
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_fans() -> Vec<FanInfo> {
+    Vec::new()
+}
+
+// ============================================
+// Fan Commands
+// ============================================
+
+/// Current, minimum and maximum speed of each fan
+#[command]
+pub fn get_fan_info() -> Result<Vec<FanInfo>, String> {
+    Ok(read_fans())
+}
@@ -0,0 +1,114 @@
+//! Focus / Do Not Disturb state
+//!
+//! macOS 12+ keeps the active Focus in `~/Library/DoNotDisturb/DB`:
+//! `Assertions.json` holds the manually enabled mode and
+//! `ModeConfigurations.json` maps mode identifiers to names.
+//! Scheduled Focus modes don't create assertions, and the DB needs
+//! Full Disk Access on recent releases; older systems fall back to the
+//! `com.apple.notificationcenterui` DND flag (on/off only). When neither
+//! can be read the state is reported as unknown rather than off.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::command;
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusInfo {
+    /// None when the state can't be read (e.g. no Full Disk Access)
+    pub enabled: Option<bool>,
+    /// e.g. `com.apple.donotdisturb.mode.default` (None when unknown)
+    pub mode_id: Option<String>,
+    /// e.g. "Do Not Disturb", "Work", "Sleep" (None when unknown)
+    pub name: Option<String>,
+}
+
+/// Directory holding the Focus databases
+pub fn focus_db_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join("Library/DoNotDisturb/DB"))
+}
+
+fn read_json(name: &str) -> Option<Value> {
+    let contents = std::fs::read_to_string(focus_db_dir()?.join(name)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Mode identifier of the active assertion, Some(None) if no Focus is on,
+/// None if the database can't be read
+fn active_mode_id() -> Option<Option<String>> {
+    let assertions = read_json("Assertions.json")?;
+    let records = assertions["data"]
+        .as_array()?
+        .iter()
+        .filter_map(|entry| entry["storeAssertionRecords"].as_array())
+        .flatten();
+
+    let mode_id = records
+        .filter_map(|record| {
+            record["assertionDetails"]["assertionDetailsModeIdentifier"].as_str()
+        })
+        .last()
+        .map(|id| id.to_string());
+
+    Some(mode_id)
+}
+
+/// Display name for a mode identifier
+fn mode_name(mode_id: &str) -> Option<String> {
+    let configurations = read_json("ModeConfigurations.json")?;
+    configurations["data"]
+        .as_array()?
+        .iter()
+        .find_map(|entry| entry["modeConfigurations"][mode_id]["mode"]["name"].as_str())
+        .map(|name| name.to_string())
+}
+
+/// Legacy (pre-Monterey) DND flag, None where the key doesn't exist
+fn legacy_dnd_enabled() -> Option<bool> {
+    let output = Command::new("defaults")
+        .args(["-currentHost", "read", "com.apple.notificationcenterui", "doNotDisturb"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Read the current Focus state
+pub fn read_focus_info() -> FocusInfo {
+    match active_mode_id() {
+        Some(Some(mode_id)) => FocusInfo {
+            enabled: Some(true),
+            name: mode_name(&mode_id),
+            mode_id: Some(mode_id),
+        },
+        Some(None) => FocusInfo {
+            enabled: Some(false),
+            ..FocusInfo::default()
+        },
+        None => FocusInfo {
+            enabled: legacy_dnd_enabled(),
+            ..FocusInfo::default()
+        },
+    }
+}
+
+// ============================================
+// Focus Commands
+// ============================================
+
+/// Get whether a Focus mode is on, and its name when available
+#[command]
+pub async fn get_focus_mode() -> Result<FocusInfo, String> {
+    tauri::async_runtime::spawn_blocking(read_focus_info)
+        .await
+        .map_err(|e| format!("Failed to read focus mode: {}", e))
+}
@@ -0,0 +1,256 @@
+//! Screen Geometry
+//!
+//! Monitor lookup, anchored placement and coordinate conversion shared by
+//! popovers, windows and the screen commands, so clamping and flipping
+//! behave the same everywhere. All values are logical pixels with a
+//! top-left origin unless noted.
+
+use tauri::{AppHandle, Monitor};
+
+use super::constants::geometry::*;
+use super::constants::screen::FALLBACK_SCREEN_FRAME;
+use super::popover::{PopoverAlign, PopoverAnchor};
+
+/// Monitor bounds (x, y, width, height) in logical pixels
+pub type MonitorBounds = (f64, f64, f64, f64);
+
+/// A monitor's bounds in logical pixels
+pub fn logical_bounds(monitor: &Monitor) -> MonitorBounds {
+    let pos = monitor.position();
+    let size = monitor.size();
+    let scale = monitor.scale_factor();
+
+    (
+        pos.x as f64 / scale,
+        pos.y as f64 / scale,
+        size.width as f64 / scale,
+        size.height as f64 / scale,
+    )
+}
+
+/// Get monitor bounds containing a point
+/// Falls back to a default frame while monitors are transiently unavailable
+pub fn get_monitor_at_point(app: &AppHandle, x: f64, y: f64) -> Result<MonitorBounds, String> {
+    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
+    if monitors.is_empty() {
+        eprintln!("[screen] No monitors available, using fallback screen frame");
+        return Ok(FALLBACK_SCREEN_FRAME);
+    }
+
+    // Find monitor containing the point
+    for monitor in &monitors {
+        let (monitor_x, monitor_y, monitor_width, monitor_height) = logical_bounds(monitor);
+
+        if x >= monitor_x
+            && x < monitor_x + monitor_width
+            && y >= monitor_y
+            && y < monitor_y + monitor_height
+        {
+            return Ok((monitor_x, monitor_y, monitor_width, monitor_height));
+        }
+    }
+
+    // Fallback to primary monitor
+    let monitor = app
+        .primary_monitor()
+        .map_err(|e| e.to_string())?
+        .unwrap_or_else(|| monitors[0].clone());
+
+    Ok(logical_bounds(&monitor))
+}
+
+/// Calculate popover position based on anchor, alignment, and monitor bounds
+pub fn calculate_popover_position(
+    anchor: &PopoverAnchor,
+    popover_width: f64,
+    popover_height: f64,
+    align: &PopoverAlign,
+    offset_y: f64,
+    monitor: MonitorBounds,
+) -> (f64, f64) {
+    let (monitor_x, monitor_y, monitor_width, monitor_height) = monitor;
+
+    // Y: below anchor with offset
+    let mut y = anchor.y + anchor.height + offset_y;
+
+    // X: based on alignment
+    let mut x = match align {
+        PopoverAlign::Start => anchor.x,
+        PopoverAlign::Center => anchor.x + (anchor.width - popover_width) / 2.0,
+        PopoverAlign::End => anchor.x + anchor.width - popover_width,
+    };
+
+    // Clamp to monitor bounds
+    x = x.max(monitor_x).min(monitor_x + monitor_width - popover_width);
+    y = y
+        .max(monitor_y)
+        .min(monitor_y + monitor_height - popover_height);
+
+    (x, y)
+}
+
+/// Calculate maximum available height from anchor bottom to screen bottom
+pub fn calculate_available_height(
+    anchor: &PopoverAnchor,
+    offset_y: f64,
+    monitor_y: f64,
+    monitor_height: f64,
+) -> f64 {
+    let popover_top = anchor.y + anchor.height + offset_y;
+    (monitor_y + monitor_height - popover_top).max(MIN_AVAILABLE_HEIGHT)
+}
+
+/// Cursor position in logical pixels (same space as popover anchors)
+///
+/// Quartz events use a top-left origin, so no flipping is needed, and unlike
+/// NSScreen this is safe off the main thread.
+#[cfg(target_os = "macos")]
+pub fn cursor_position() -> Option<(f64, f64)> {
+    use core_foundation_sys::base::CFRelease;
+    use objc2_foundation::NSPoint;
+    use std::ffi::c_void;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGEventCreate(source: *const c_void) -> *const c_void;
+        fn CGEventGetLocation(event: *const c_void) -> NSPoint;
+    }
+
+    unsafe {
+        let event = CGEventCreate(std::ptr::null());
+        if event.is_null() {
+            return None;
+        }
+        let location = CGEventGetLocation(event);
+        CFRelease(event);
+        Some((location.x, location.y))
+    }
+}
+
+/// Constrain dimensions to screen bounds (excluding shadow padding and top margin)
+pub fn constrain_to_screen(
+    width: f64,
+    height: f64,
+    monitor_width: f64,
+    monitor_height: f64,
+) -> (f64, f64) {
+    let max_w = monitor_width - SHADOW_PADDING;
+    let max_h = monitor_height - SHADOW_PADDING - TOP_MARGIN;
+    (width.min(max_w), height.min(max_h))
+}
+
+/// Flip a rect's global y between top-left (web) and bottom-left (macOS) origins
+///
+/// Both spaces are anchored to the primary screen: the web origin is its
+/// top-left corner and Cocoa's y = 0 is its bottom edge, so
+/// `y_macos = primary_height - y_js - height` on every monitor. The flip is
+/// its own inverse, so the same function converts in both directions.
+pub fn flip_y(y: f64, height: f64, primary_height: f64) -> f64 {
+    primary_height - y - height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_popover_position, flip_y, MonitorBounds};
+    use crate::commands::popover::{PopoverAlign, PopoverAnchor};
+
+    /// 1440x900 primary with a 1920x1080 monitor stacked above it:
+    /// its top-left origin is y = -1080 and its Cocoa origin y = 900
+    const PRIMARY_HEIGHT: f64 = 900.0;
+
+    #[test]
+    fn flips_on_the_primary_monitor() {
+        assert_eq!(flip_y(0.0, 0.0, PRIMARY_HEIGHT), 900.0);
+        assert_eq!(flip_y(100.0, 50.0, PRIMARY_HEIGHT), 750.0);
+    }
+
+    #[test]
+    fn flips_on_a_monitor_above_the_primary() {
+        // Top edge of the upper monitor is Cocoa y = 900 + 1080
+        assert_eq!(flip_y(-1080.0, 0.0, PRIMARY_HEIGHT), 1980.0);
+        // A 100pt-tall rect at its top has its Cocoa origin 100pt lower
+        assert_eq!(flip_y(-1080.0, 100.0, PRIMARY_HEIGHT), 1880.0);
+        // Its bottom edge meets the primary's top edge
+        assert_eq!(flip_y(0.0, 0.0, PRIMARY_HEIGHT), PRIMARY_HEIGHT);
+    }
+
+    #[test]
+    fn round_trips_between_origins() {
+        for (y, height) in [(-1080.0, 100.0), (-500.0, 0.0), (300.0, 240.0)] {
+            let macos = flip_y(y, height, PRIMARY_HEIGHT);
+            assert_eq!(flip_y(macos, height, PRIMARY_HEIGHT), y);
+        }
+    }
+
+    const MONITOR: MonitorBounds = (0.0, 0.0, 1440.0, 900.0);
+
+    /// 40x24 menu bar item with its top-left corner at (x, y)
+    fn anchor(x: f64, y: f64) -> PopoverAnchor {
+        PopoverAnchor {
+            x,
+            y,
+            width: 40.0,
+            height: 24.0,
+        }
+    }
+
+    #[test]
+    fn places_popovers_below_the_anchor() {
+        let position = |align| {
+            calculate_popover_position(&anchor(700.0, 0.0), 300.0, 400.0, &align, 8.0, MONITOR)
+        };
+        assert_eq!(position(PopoverAlign::Start), (700.0, 32.0));
+        assert_eq!(position(PopoverAlign::Center), (570.0, 32.0));
+        assert_eq!(position(PopoverAlign::End), (440.0, 32.0));
+    }
+
+    #[test]
+    fn clamps_popovers_to_the_monitor_edges() {
+        // End-aligned near the left edge would start at x = -250
+        let (x, _) = calculate_popover_position(
+            &anchor(10.0, 0.0),
+            300.0,
+            400.0,
+            &PopoverAlign::End,
+            8.0,
+            MONITOR,
+        );
+        assert_eq!(x, 0.0);
+
+        // Start-aligned near the right edge would overflow by 260
+        let (x, _) = calculate_popover_position(
+            &anchor(1400.0, 0.0),
+            300.0,
+            400.0,
+            &PopoverAlign::Start,
+            8.0,
+            MONITOR,
+        );
+        assert_eq!(x, 1140.0);
+
+        // Anchored low on the screen, the popover is pushed up to fit
+        let (_, y) = calculate_popover_position(
+            &anchor(700.0, 800.0),
+            300.0,
+            400.0,
+            &PopoverAlign::Center,
+            8.0,
+            MONITOR,
+        );
+        assert_eq!(y, 500.0);
+    }
+
+    #[test]
+    fn clamps_popovers_to_a_secondary_monitor() {
+        let secondary: MonitorBounds = (1440.0, -180.0, 1920.0, 1080.0);
+        let position = calculate_popover_position(
+            &anchor(1450.0, -180.0),
+            300.0,
+            400.0,
+            &PopoverAlign::End,
+            8.0,
+            secondary,
+        );
+        assert_eq!(position, (1440.0, -148.0));
+    }
+}
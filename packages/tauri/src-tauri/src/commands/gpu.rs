@@ -0,0 +1,182 @@
+//! GPU usage
+//!
+//! Reads the `PerformanceStatistics` dictionary every `IOAccelerator`
+//! publishes (AGXAccelerator on Apple Silicon, AMD/Intel drivers on Intel
+//! Macs). Keys differ between drivers, so each value is optional, and GPUs
+//! that publish no statistics are skipped.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuInfo {
+    pub name: String,
+    /// Device utilization (0-100)
+    pub utilization: Option<f32>,
+    /// Memory in use by the GPU (bytes; unified memory on Apple Silicon)
+    pub vram_used: Option<u64>,
+    /// Dedicated VRAM (bytes; None for unified memory)
+    pub vram_total: Option<u64>,
+}
+
+#[cfg(target_os = "macos")]
+mod iokit {
+    use super::GpuInfo;
+    use crate::commands::iokit::{
+        cf_str, IOIteratorNext, IOObjectRelease, IORegistryEntryCreateCFProperty,
+        IORegistryEntrySearchCFProperty, IOServiceGetMatchingServices, IOServiceMatching, IoObject,
+    };
+    use core_foundation_sys::base::{CFGetTypeID, CFRelease, CFTypeRef};
+    use core_foundation_sys::data::{CFDataGetBytePtr, CFDataGetLength, CFDataGetTypeID, CFDataRef};
+    use core_foundation_sys::dictionary::{CFDictionaryGetValue, CFDictionaryRef};
+    use core_foundation_sys::number::{
+        kCFNumberFloat64Type, CFNumberGetTypeID, CFNumberGetValue, CFNumberRef,
+    };
+    use core_foundation_sys::string::CFStringGetTypeID;
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
+    /// kIORegistryIterateRecursively | kIORegistryIterateParents
+    const SEARCH_PARENTS: u32 = 0x1 | 0x2;
+
+    unsafe fn number(value: CFTypeRef) -> Option<f64> {
+        if value.is_null() || CFGetTypeID(value) != CFNumberGetTypeID() {
+            return None;
+        }
+        let mut number = 0f64;
+        CFNumberGetValue(
+            value as CFNumberRef,
+            kCFNumberFloat64Type,
+            &mut number as *mut f64 as *mut c_void,
+        );
+        Some(number)
+    }
+
+    unsafe fn dictionary_number(dictionary: CFDictionaryRef, key: &str) -> Option<f64> {
+        let key = NSString::from_str(key);
+        number(CFDictionaryGetValue(dictionary, cf_str(&key) as *const c_void))
+    }
+
+    /// A string property (CFString, or NUL-terminated CFData as on PCI devices)
+    unsafe fn string(value: CFTypeRef) -> Option<String> {
+        if value.is_null() {
+            return None;
+        }
+        if CFGetTypeID(value) == CFStringGetTypeID() {
+            return Some((*(value as *const NSString)).to_string());
+        }
+        if CFGetTypeID(value) == CFDataGetTypeID() {
+            let data = value as CFDataRef;
+            let bytes =
+                std::slice::from_raw_parts(CFDataGetBytePtr(data), CFDataGetLength(data) as usize);
+            let text = String::from_utf8_lossy(bytes);
+            return Some(text.trim_end_matches('\0').to_string());
+        }
+        None
+    }
+
+    /// Look a property up on the accelerator or its ancestors (e.g. the PCI device)
+    unsafe fn search_property<T>(
+        entry: IoObject,
+        key: &str,
+        read: unsafe fn(CFTypeRef) -> Option<T>,
+    ) -> Option<T> {
+        let key = NSString::from_str(key);
+        let value = IORegistryEntrySearchCFProperty(
+            entry,
+            c"IOService".as_ptr(),
+            cf_str(&key),
+            std::ptr::null(),
+            SEARCH_PARENTS,
+        );
+        if value.is_null() {
+            return None;
+        }
+        let result = read(value);
+        CFRelease(value);
+        result
+    }
+
+    unsafe fn read_accelerator(accelerator: IoObject) -> Option<GpuInfo> {
+        let key = NSString::from_str("PerformanceStatistics");
+        let statistics =
+            IORegistryEntryCreateCFProperty(accelerator, cf_str(&key), std::ptr::null(), 0);
+        if statistics.is_null() {
+            return None;
+        }
+        let statistics_dict = statistics as CFDictionaryRef;
+
+        let utilization = dictionary_number(statistics_dict, "Device Utilization %")
+            .or_else(|| dictionary_number(statistics_dict, "GPU Activity(%)"))
+            .map(|percent| percent.clamp(0.0, 100.0) as f32);
+        let vram_used = dictionary_number(statistics_dict, "vramUsedBytes")
+            .or_else(|| dictionary_number(statistics_dict, "In use system memory"))
+            .map(|bytes| bytes as u64);
+        CFRelease(statistics);
+
+        let vram_total = search_property(accelerator, "VRAM,totalMB", number)
+            .map(|megabytes| megabytes as u64 * 1024 * 1024);
+        let name = search_property(accelerator, "model", string)
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "GPU".to_string());
+
+        Some(GpuInfo {
+            name,
+            utilization,
+            vram_used,
+            vram_total,
+        })
+    }
+
+    /// Statistics of every accelerator that publishes them
+    pub fn read_gpus() -> Vec<GpuInfo> {
+        let mut gpus = Vec::new();
+
+        unsafe {
+            // IOServiceGetMatchingServices consumes the matching dictionary
+            let matching = IOServiceMatching(c"IOAccelerator".as_ptr());
+            if matching.is_null() {
+                return gpus;
+            }
+
+            let mut iterator: IoObject = 0;
+            if IOServiceGetMatchingServices(0, matching, &mut iterator) != 0 {
+                return gpus;
+            }
+
+            loop {
+                let accelerator = IOIteratorNext(iterator);
+                if accelerator == 0 {
+                    break;
+                }
+                gpus.extend(read_accelerator(accelerator));
+                IOObjectRelease(accelerator);
+            }
+            IOObjectRelease(iterator);
+        }
+
+        gpus
+    }
+}
+
+/// Read every GPU's statistics (empty where none are exposed)
+#[cfg(target_os = "macos")]
+pub fn read_gpu_info() -> Vec<GpuInfo> {
+    iokit::read_gpus()
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_gpu_info() -> Vec<GpuInfo> {
+    Vec::new()
+}
+
+// ============================================
+// GPU Commands
+// ============================================
+
+/// Utilization and memory of each GPU
+#[command]
+pub fn get_gpu_info() -> Result<Vec<GpuInfo>, String> {
+    Ok(read_gpu_info())
+}
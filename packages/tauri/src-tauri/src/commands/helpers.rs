@@ -1,6 +1,6 @@
 use tauri::{AppHandle, Manager, WebviewWindow};
 
-use super::constants::geometry::*;
+use super::constants::screen::*;
 
 /// Get target window by label, or use the current window if no label provided
 pub fn get_target_window(
@@ -16,14 +16,44 @@ pub fn get_target_window(
     }
 }
 
-/// Constrain dimensions to screen bounds (excluding shadow padding and top margin)
-pub fn constrain_to_screen(
-    width: f64,
-    height: f64,
-    monitor_width: f64,
-    monitor_height: f64,
-) -> (f64, f64) {
-    let max_w = monitor_width - SHADOW_PADDING;
-    let max_h = monitor_height - SHADOW_PADDING - TOP_MARGIN;
-    (width.min(max_w), height.min(max_h))
+/// Check an id that becomes part of an event name (Tauri restricts those)
+pub fn validate_event_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/' | ':'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid id '{}' (use letters, digits, '-', '_', '/', ':')",
+            id
+        ))
+    }
+}
+
+/// Wait with exponential backoff until the OS reports at least one screen
+///
+/// During display reconfiguration the OS can briefly report no screens.
+/// Async callers wait here before screen-dependent work, so the backoff
+/// never blocks the main thread; the screen queries themselves read once
+/// and fall back to a default frame.
+pub async fn wait_for_screens(app: &AppHandle) {
+    let mut delay = SCREEN_RETRY_BASE_DELAY_MS;
+
+    for attempt in 1..SCREEN_RETRY_ATTEMPTS {
+        match app.available_monitors() {
+            Ok(monitors) if !monitors.is_empty() => return,
+            Ok(_) => eprintln!(
+                "[screen] No screens (attempt {}/{}), retrying in {}ms",
+                attempt, SCREEN_RETRY_ATTEMPTS, delay
+            ),
+            Err(e) => eprintln!(
+                "[screen] Query failed (attempt {}/{}): {}, retrying in {}ms",
+                attempt, SCREEN_RETRY_ATTEMPTS, e, delay
+            ),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        delay *= 2;
+    }
 }
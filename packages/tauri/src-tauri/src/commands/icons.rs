@@ -1,5 +1,6 @@
 use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 use tauri::command;
 
@@ -8,7 +9,11 @@ lazy_static::lazy_static! {
 }
 
 const MAX_CACHE_SIZE: usize = 100;
+/// Upper bound on concurrent icon lookups (each may shell out to mdfind)
+const MAX_FETCH_THREADS: usize = 6;
 const ICON_SIZE: f64 = 16.0;
+/// Largest size accepted by `get_file_icon` (points)
+const MAX_FILE_ICON_SIZE: f64 = 512.0;
 
 #[derive(Debug, Serialize, Clone)]
 pub struct AppIcon {
@@ -32,35 +37,122 @@ pub fn get_app_icon(app_name: String) -> Result<AppIcon, String> {
     let icon_data = fetch_icon_for_app(&app_name);
 
     // Store in cache
-    {
-        let mut cache = ICON_CACHE.lock().map_err(|e| e.to_string())?;
+    insert_into_cache(app_name.clone(), icon_data.clone())?;
 
-        // Simple eviction: clear half the cache if full
-        if cache.len() >= MAX_CACHE_SIZE {
-            let keys_to_remove: Vec<_> = cache.keys().take(MAX_CACHE_SIZE / 2).cloned().collect();
-            for key in keys_to_remove {
-                cache.remove(&key);
-            }
-        }
+    Ok(AppIcon {
+        app: app_name,
+        icon: icon_data,
+    })
+}
+
+/// Get an app icon by bundle identifier (skips the filesystem/mdfind search)
+#[command]
+pub fn get_app_icon_by_bundle_id(bundle_id: String) -> Result<AppIcon, String> {
+    let cache_key = format!("bundle:{}", bundle_id);
 
-        cache.insert(app_name.clone(), icon_data.clone());
+    // Check cache first
+    {
+        let cache = ICON_CACHE.lock().map_err(|e| e.to_string())?;
+        if let Some(cached) = cache.get(&cache_key) {
+            return Ok(AppIcon {
+                app: bundle_id.clone(),
+                icon: cached.clone(),
+            });
+        }
     }
 
+    let icon_data = fetch_icon_for_bundle_id(&bundle_id);
+    insert_into_cache(cache_key, icon_data.clone())?;
+
     Ok(AppIcon {
-        app: app_name,
+        app: bundle_id,
         icon: icon_data,
     })
 }
 
+/// Icons for several apps, in input order
+///
+/// Cached icons are returned directly; the rest are fetched in parallel.
 #[command]
 pub fn get_app_icons(app_names: Vec<String>) -> Result<Vec<AppIcon>, String> {
-    let mut results = Vec::with_capacity(app_names.len());
+    let icons = app_icons_by_name(app_names.iter().map(|name| (name.clone(), None)).collect());
+
+    Ok(app_names
+        .into_iter()
+        .map(|app| {
+            let icon = icons.get(&app).cloned().flatten();
+            AppIcon { app, icon }
+        })
+        .collect())
+}
 
-    for app_name in app_names {
-        results.push(get_app_icon(app_name)?);
+/// Icon for a document: a file extension ("pdf", ".pdf") or UTI gets the
+/// generic icon of that type, a path (containing '/') gets the file's own icon
+///
+/// `size` is in points (defaults to the app icon size). Icons are cached by
+/// extension and size; directories, bundles and extensionless files are
+/// cached by path, as their icons are specific to them.
+#[command]
+pub fn get_file_icon(path_or_extension: String, size: Option<f64>) -> Result<AppIcon, String> {
+    let size = size.unwrap_or(ICON_SIZE);
+    if !(1.0..=MAX_FILE_ICON_SIZE).contains(&size) {
+        return Err(format!(
+            "Icon size must be between 1 and {}",
+            MAX_FILE_ICON_SIZE
+        ));
     }
 
-    Ok(results)
+    let is_path = path_or_extension.contains('/');
+    let path = if is_path {
+        match path_or_extension.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()
+                .map(|home| home.join(rest))
+                .ok_or("Failed to resolve home directory")?,
+            None => std::path::PathBuf::from(&path_or_extension),
+        }
+    } else {
+        std::path::PathBuf::new()
+    };
+
+    // Documents share their type's icon; anything else may have its own
+    let extension = if is_path {
+        path.extension()
+            .filter(|_| path.is_file())
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+    } else {
+        let extension = path_or_extension.trim_start_matches('.');
+        if extension.is_empty() {
+            return Err("File extension is empty".to_string());
+        }
+        Some(extension.to_lowercase())
+    };
+    let cache_key = match &extension {
+        Some(extension) => format!("file:{}:{}", extension, size),
+        None => format!("path:{}:{}", path.display(), size),
+    };
+
+    // Check cache first
+    {
+        let cache = ICON_CACHE.lock().map_err(|e| e.to_string())?;
+        if let Some(cached) = cache.get(&cache_key) {
+            return Ok(AppIcon {
+                app: path_or_extension,
+                icon: cached.clone(),
+            });
+        }
+    }
+
+    let icon_data = match extension {
+        _ if is_path => fetch_icon_for_file(&path.to_string_lossy(), size),
+        Some(extension) => fetch_icon_for_file_type(&extension, size),
+        None => None,
+    };
+    insert_into_cache(cache_key, icon_data.clone())?;
+
+    Ok(AppIcon {
+        app: path_or_extension,
+        icon: icon_data,
+    })
 }
 
 #[command]
@@ -70,27 +162,150 @@ pub fn clear_icon_cache() -> Result<(), String> {
     Ok(())
 }
 
+/// Icons for many apps at once, keyed by app name
+///
+/// Each entry is `(app name, bundle id)`; the bundle id lookup is preferred
+/// when known. Cached icons are returned directly and the misses are fetched
+/// concurrently on up to `MAX_FETCH_THREADS` threads.
+pub fn app_icons_by_name(apps: Vec<(String, Option<String>)>) -> HashMap<String, Option<String>> {
+    let cache_key = |name: &String, bundle_id: &Option<String>| match bundle_id {
+        Some(bundle_id) => format!("bundle:{}", bundle_id),
+        None => name.clone(),
+    };
+
+    let mut icons = HashMap::with_capacity(apps.len());
+    let mut misses = Vec::new();
+    if let Ok(cache) = ICON_CACHE.lock() {
+        for (name, bundle_id) in apps {
+            match cache.get(&cache_key(&name, &bundle_id)) {
+                Some(cached) => {
+                    icons.insert(name, cached.clone());
+                }
+                None if !misses.contains(&(name.clone(), bundle_id.clone())) => {
+                    misses.push((name, bundle_id));
+                }
+                None => {}
+            }
+        }
+    }
+
+    for (name, bundle_id, icon) in fetch_icons(misses) {
+        let _ = insert_into_cache(cache_key(&name, &bundle_id), icon.clone());
+        icons.insert(name, icon);
+    }
+
+    icons
+}
+
+/// Fetch icons on a bounded set of worker threads, each with its own
+/// autorelease pool
+fn fetch_icons(
+    apps: Vec<(String, Option<String>)>,
+) -> Vec<(String, Option<String>, Option<String>)> {
+    let next = AtomicUsize::new(0);
+    let workers = apps.len().min(MAX_FETCH_THREADS);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut fetched = Vec::new();
+                    while let Some((name, bundle_id)) =
+                        apps.get(next.fetch_add(1, Ordering::Relaxed))
+                    {
+                        let icon = with_autorelease_pool(|| match bundle_id {
+                            Some(bundle_id) => fetch_icon_for_bundle_id(bundle_id)
+                                .or_else(|| fetch_icon_for_app(name)),
+                            None => fetch_icon_for_app(name),
+                        });
+                        fetched.push((name.clone(), bundle_id.clone(), icon));
+                    }
+                    fetched
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().ok())
+            .flatten()
+            .collect()
+    })
+}
+
+/// Drain autoreleased AppKit objects created on worker threads
 #[cfg(target_os = "macos")]
-fn fetch_icon_for_app(app_name: &str) -> Option<String> {
-    use base64::Engine;
-    use objc2::msg_send;
-    use objc2_app_kit::{NSBitmapImageFileType, NSBitmapImageRep, NSWorkspace};
-    use objc2_foundation::{NSDictionary, NSSize, NSString};
+fn with_autorelease_pool<T>(f: impl FnOnce() -> T) -> T {
+    objc2::rc::autoreleasepool(|_| f())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn with_autorelease_pool<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+/// Insert an entry into the icon cache, evicting when full
+fn insert_into_cache(key: String, icon: Option<String>) -> Result<(), String> {
+    let mut cache = ICON_CACHE.lock().map_err(|e| e.to_string())?;
+
+    // Simple eviction: clear half the cache if full
+    if cache.len() >= MAX_CACHE_SIZE {
+        let keys_to_remove: Vec<_> = cache.keys().take(MAX_CACHE_SIZE / 2).cloned().collect();
+        for key in keys_to_remove {
+            cache.remove(&key);
+        }
+    }
 
+    cache.insert(key, icon);
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn fetch_icon_for_app(app_name: &str) -> Option<String> {
     // Try to find the app bundle path
     let bundle_path = find_app_bundle_path(app_name)?;
+    encode_icon_for_path(&bundle_path)
+}
+
+#[cfg(target_os = "macos")]
+fn fetch_icon_for_bundle_id(bundle_id: &str) -> Option<String> {
+    use objc2_app_kit::NSWorkspace;
+    use objc2_foundation::NSString;
+
+    let workspace = NSWorkspace::sharedWorkspace();
+    let bundle_id_nsstring = NSString::from_str(bundle_id);
+    let bundle_url = workspace.URLForApplicationWithBundleIdentifier(&bundle_id_nsstring)?;
+    let bundle_path = bundle_url.path()?.to_string();
+
+    encode_icon_for_path(&bundle_path)
+}
+
+/// Render the Finder icon of a file or bundle as base64 PNG
+#[cfg(target_os = "macos")]
+fn encode_icon_for_path(bundle_path: &str) -> Option<String> {
+    use objc2_app_kit::NSWorkspace;
+    use objc2_foundation::NSString;
 
     let workspace = NSWorkspace::sharedWorkspace();
 
     // Create NSString from path
-    let path_nsstring = NSString::from_str(&bundle_path);
+    let path_nsstring = NSString::from_str(bundle_path);
 
     // Get the icon for the application
     let icon = workspace.iconForFile(&path_nsstring);
+    encode_icon(&icon, ICON_SIZE)
+}
+
+/// Encode an icon at `size` points as base64 PNG
+#[cfg(target_os = "macos")]
+fn encode_icon(icon: &objc2_app_kit::NSImage, size: f64) -> Option<String> {
+    use base64::Engine;
+    use objc2::msg_send;
+    use objc2_app_kit::{NSBitmapImageFileType, NSBitmapImageRep};
+    use objc2_foundation::{NSDictionary, NSSize, NSString};
 
     // Resize icon to desired size
-    let size = NSSize::new(ICON_SIZE, ICON_SIZE);
-    icon.setSize(size);
+    icon.setSize(NSSize::new(size, size));
 
     // Convert to PNG data via NSBitmapImageRep
     let tiff_data = icon.TIFFRepresentation()?;
@@ -112,6 +327,32 @@ fn fetch_icon_for_app(app_name: &str) -> Option<String> {
     Some(base64::engine::general_purpose::STANDARD.encode(slice))
 }
 
+/// Icon for a concrete file or directory
+#[cfg(target_os = "macos")]
+fn fetch_icon_for_file(path: &str, size: f64) -> Option<String> {
+    use objc2_app_kit::NSWorkspace;
+    use objc2_foundation::NSString;
+
+    let icon = NSWorkspace::sharedWorkspace().iconForFile(&NSString::from_str(path));
+    encode_icon(&icon, size)
+}
+
+/// Generic document icon for a file extension (or UTI)
+#[cfg(target_os = "macos")]
+fn fetch_icon_for_file_type(file_type: &str, size: f64) -> Option<String> {
+    use objc2::msg_send;
+    use objc2::rc::Retained;
+    use objc2_app_kit::{NSImage, NSWorkspace};
+    use objc2_foundation::NSString;
+
+    let workspace = NSWorkspace::sharedWorkspace();
+    let file_type = NSString::from_str(file_type);
+    // iconForFileType: is deprecated in favor of iconForContentType:, which
+    // needs a UTType; it still accepts both extensions and UTIs
+    let icon: Retained<NSImage> = unsafe { msg_send![&*workspace, iconForFileType: &*file_type] };
+    encode_icon(&icon, size)
+}
+
 #[cfg(target_os = "macos")]
 fn find_app_bundle_path(app_name: &str) -> Option<String> {
     use objc2_app_kit::NSWorkspace;
@@ -177,3 +418,18 @@ fn find_app_bundle_path(app_name: &str) -> Option<String> {
 fn fetch_icon_for_app(_app_name: &str) -> Option<String> {
     None
 }
+
+#[cfg(not(target_os = "macos"))]
+fn fetch_icon_for_bundle_id(_bundle_id: &str) -> Option<String> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+fn fetch_icon_for_file(_path: &str, _size: f64) -> Option<String> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+fn fetch_icon_for_file_type(_file_type: &str, _size: f64) -> Option<String> {
+    None
+}
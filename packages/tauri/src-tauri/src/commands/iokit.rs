@@ -0,0 +1,53 @@
+//! IOKit declarations
+//!
+//! The IOKit functions used by the GPU, disk I/O and SMC readers, declared
+//! once so every caller links against the same signatures.
+
+use core_foundation_sys::base::{CFAllocatorRef, CFTypeRef};
+use core_foundation_sys::dictionary::{CFDictionaryRef, CFMutableDictionaryRef};
+use core_foundation_sys::string::CFStringRef;
+use objc2_foundation::NSString;
+use std::ffi::{c_char, c_void};
+
+pub type IoObject = u32;
+
+#[link(name = "IOKit", kind = "framework")]
+extern "C" {
+    pub fn IOServiceMatching(name: *const c_char) -> CFMutableDictionaryRef;
+    pub fn IOServiceGetMatchingService(main_port: u32, matching: CFDictionaryRef) -> IoObject;
+    pub fn IOServiceGetMatchingServices(
+        main_port: u32,
+        matching: CFDictionaryRef,
+        existing: *mut IoObject,
+    ) -> i32;
+    pub fn IOIteratorNext(iterator: IoObject) -> IoObject;
+    pub fn IOObjectRelease(object: IoObject) -> i32;
+    pub fn IORegistryEntryCreateCFProperty(
+        entry: IoObject,
+        key: CFStringRef,
+        allocator: CFAllocatorRef,
+        options: u32,
+    ) -> CFTypeRef;
+    pub fn IORegistryEntrySearchCFProperty(
+        entry: IoObject,
+        plane: *const c_char,
+        key: CFStringRef,
+        allocator: CFAllocatorRef,
+        options: u32,
+    ) -> CFTypeRef;
+    pub fn IOServiceOpen(service: IoObject, owning_task: u32, kind: u32, connect: *mut u32) -> i32;
+    pub fn IOServiceClose(connect: u32) -> i32;
+    pub fn IOConnectCallStructMethod(
+        connection: u32,
+        selector: u32,
+        input: *const c_void,
+        input_size: usize,
+        output: *mut c_void,
+        output_size: *mut usize,
+    ) -> i32;
+}
+
+/// CFString is toll-free bridged to NSString
+pub fn cf_str(string: &NSString) -> CFStringRef {
+    string as *const NSString as CFStringRef
+}
@@ -0,0 +1,125 @@
+//! Keyboard backlight control
+//!
+//! Uses `KeyboardBrightnessClient` from the private CoreBrightness framework,
+//! which works on both Intel and Apple Silicon Macs (the older IOKit
+//! `AppleLMUController` service only exists on Intel).
+
+use tauri::command;
+
+#[cfg(target_os = "macos")]
+mod client {
+    use objc2::msg_send;
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyClass, AnyObject, Bool};
+    use std::ffi::{c_char, c_int, c_void, CStr};
+    use std::sync::OnceLock;
+
+    const FRAMEWORK_PATH: &CStr =
+        c"/System/Library/PrivateFrameworks/CoreBrightness.framework/CoreBrightness";
+    const RTLD_LAZY: c_int = 0x1;
+
+    extern "C" {
+        fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    }
+
+    /// Load CoreBrightness and look up KeyboardBrightnessClient
+    fn client_class() -> Option<&'static AnyClass> {
+        static LOADED: OnceLock<bool> = OnceLock::new();
+        let loaded = *LOADED.get_or_init(|| unsafe { !dlopen(FRAMEWORK_PATH.as_ptr(), RTLD_LAZY).is_null() });
+        if !loaded {
+            return None;
+        }
+        AnyClass::get(c"KeyboardBrightnessClient")
+    }
+
+    /// A client plus the id of the built-in backlit keyboard
+    pub struct Keyboard {
+        client: Retained<AnyObject>,
+        id: u64,
+    }
+
+    impl Keyboard {
+        /// Find the backlit keyboard (prefers the built-in one)
+        pub fn find() -> Result<Self, String> {
+            let class = client_class().ok_or("Keyboard backlight control is unavailable")?;
+
+            unsafe {
+                let client: Option<Retained<AnyObject>> = msg_send![class, new];
+                let client = client.ok_or("Failed to create keyboard brightness client")?;
+
+                let ids: *const AnyObject = msg_send![&*client, copyKeyboardBacklightIDs];
+                let count: usize = if ids.is_null() { 0 } else { msg_send![ids, count] };
+
+                let mut found = None;
+                for i in 0..count {
+                    let number: *const AnyObject = msg_send![ids, objectAtIndex: i];
+                    let id: u64 = msg_send![number, unsignedLongLongValue];
+                    let built_in: Bool = msg_send![&*client, isKeyboardBuiltIn: id];
+                    if found.is_none() || built_in.as_bool() {
+                        found = Some(id);
+                    }
+                    if built_in.as_bool() {
+                        break;
+                    }
+                }
+
+                // copy* follows the Create rule
+                if !ids.is_null() {
+                    let _: () = msg_send![ids, release];
+                }
+
+                let id = found.ok_or("No backlit keyboard found")?;
+                Ok(Keyboard { client, id })
+            }
+        }
+
+        pub fn brightness(&self) -> f32 {
+            unsafe { msg_send![&*self.client, brightnessForKeyboard: self.id] }
+        }
+
+        pub fn set_brightness(&self, level: f32) -> Result<(), String> {
+            let ok: Bool =
+                unsafe { msg_send![&*self.client, setBrightness: level, forKeyboard: self.id] };
+            if ok.as_bool() {
+                Ok(())
+            } else {
+                Err("Failed to set keyboard brightness".to_string())
+            }
+        }
+    }
+}
+
+// ============================================
+// Keyboard Backlight Commands
+// ============================================
+
+/// Get the keyboard backlight level (0.0 - 1.0)
+#[command]
+pub fn get_keyboard_brightness() -> Result<f32, String> {
+    #[cfg(target_os = "macos")]
+    {
+        Ok(client::Keyboard::find()?.brightness())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("Keyboard backlight control is only supported on macOS".to_string())
+    }
+}
+
+/// Set the keyboard backlight level (0.0 - 1.0)
+#[command]
+pub fn set_keyboard_brightness(level: f32) -> Result<(), String> {
+    let level = level.clamp(0.0, 1.0);
+
+    #[cfg(target_os = "macos")]
+    {
+        client::Keyboard::find()?.set_brightness(level)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = level;
+        Err("Keyboard backlight control is only supported on macOS".to_string())
+    }
+}
@@ -0,0 +1,241 @@
+//! Login items (apps launched at login)
+//!
+//! Uses System Events via AppleScript, which can list and manage other
+//! apps' login items. SMAppService only manages the calling app's own items.
+//! On macOS 13+ the first call prompts for Automation permission for
+//! System Events; apps registered as background items are not listed.
+//!
+//! Fluopanel's own launch-at-login uses `SMAppService.mainAppService`
+//! (macOS 13+), mirrored in `settings.launchAtLogin`.
+
+use serde::Serialize;
+use std::process::Command;
+use tauri::command;
+
+use super::config::{get_config, write_config};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginItem {
+    pub name: String,
+    pub path: String,
+}
+
+/// Run an AppleScript and return trimmed stdout, surfacing stderr on failure
+fn run_system_events_script(script: &str, error_context: &str) -> Result<String, String> {
+    let output = Command::new("osascript")
+        .args(["-e", script])
+        .output()
+        .map_err(|e| format!("{}: {}", error_context, e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // -1743: user denied (or hasn't granted) Automation access to System Events
+        if stderr.contains("-1743") {
+            return Err(format!(
+                "{}: Automation permission for System Events is required",
+                error_context
+            ));
+        }
+        return Err(format!("{}: {}", error_context, stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Escape a string for use inside an AppleScript string literal
+fn applescript_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// ============================================
+// Login Item Commands
+// ============================================
+
+/// List login items
+/// System Events only lists registered items; disabling one removes it
+#[command]
+pub fn list_login_items() -> Result<Vec<LoginItem>, String> {
+    let script = r#"
+        set output to ""
+        tell application "System Events"
+            repeat with loginItem in login items
+                set output to output & (name of loginItem) & tab & (path of loginItem) & linefeed
+            end repeat
+        end tell
+        return output
+    "#;
+
+    let stdout = run_system_events_script(script, "Failed to list login items")?;
+
+    Ok(stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, path) = line.split_once('\t')?;
+            Some(LoginItem {
+                name: name.to_string(),
+                path: path.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Add (enabled) or remove (disabled) a login item by app path
+#[command]
+pub fn set_login_item(path: String, enabled: bool) -> Result<(), String> {
+    let path = applescript_string(&path);
+
+    let script = if enabled {
+        format!(
+            r#"
+            tell application "System Events"
+                if not (exists (first login item whose path is "{0}")) then
+                    make login item at end with properties {{path:"{0}", hidden:false}}
+                end if
+            end tell
+            "#,
+            path
+        )
+    } else {
+        format!(
+            r#"
+            tell application "System Events"
+                delete (every login item whose path is "{}")
+            end tell
+            "#,
+            path
+        )
+    };
+
+    run_system_events_script(&script, "Failed to update login item")?;
+    Ok(())
+}
+
+// ============================================
+// Launch at Login (SMAppService, macOS 13+)
+// ============================================
+
+/// SMAppServiceStatus values
+#[cfg(target_os = "macos")]
+mod app_service_status {
+    pub const ENABLED: isize = 1;
+    pub const REQUIRES_APPROVAL: isize = 2;
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "ServiceManagement", kind = "framework")]
+extern "C" {}
+
+/// `SMAppService.mainAppService`, or an error before macOS 13
+#[cfg(target_os = "macos")]
+fn main_app_service() -> Result<*const objc2::runtime::AnyObject, String> {
+    use objc2::msg_send;
+    use objc2::runtime::{AnyClass, AnyObject};
+
+    let class = AnyClass::get(c"SMAppService")
+        .ok_or("Launch at login requires macOS 13 or later")?;
+    let service: *const AnyObject = unsafe { msg_send![class, mainAppService] };
+    if service.is_null() {
+        return Err("Failed to get SMAppService".to_string());
+    }
+    Ok(service)
+}
+
+/// Whether Fluopanel is registered to launch at login
+#[cfg(target_os = "macos")]
+fn launch_at_login_status() -> Result<bool, String> {
+    use objc2::msg_send;
+
+    let service = main_app_service()?;
+    let status: isize = unsafe { msg_send![service, status] };
+    Ok(status == app_service_status::ENABLED)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn launch_at_login_status() -> Result<bool, String> {
+    Ok(false)
+}
+
+/// Register or unregister Fluopanel as a login item (no config change)
+#[cfg(target_os = "macos")]
+pub fn apply_launch_at_login(enabled: bool) -> Result<(), String> {
+    use objc2::msg_send;
+    use objc2::runtime::{AnyObject, Bool};
+    use objc2_foundation::NSString;
+
+    if launch_at_login_status()? == enabled {
+        return Ok(());
+    }
+
+    let service = main_app_service()?;
+    let mut error: *mut AnyObject = std::ptr::null_mut();
+    let ok: Bool = unsafe {
+        if enabled {
+            msg_send![service, registerAndReturnError: &mut error]
+        } else {
+            msg_send![service, unregisterAndReturnError: &mut error]
+        }
+    };
+
+    if !ok.as_bool() {
+        let message = if error.is_null() {
+            "unknown error".to_string()
+        } else {
+            unsafe {
+                let description: *const NSString = msg_send![error, localizedDescription];
+                if description.is_null() {
+                    "unknown error".to_string()
+                } else {
+                    (*description).to_string()
+                }
+            }
+        };
+        return Err(format!("Failed to update login item: {}", message));
+    }
+
+    // The user may have disabled Fluopanel in System Settings before;
+    // registration then needs their approval there
+    let status: isize = unsafe { msg_send![service, status] };
+    if enabled && status == app_service_status::REQUIRES_APPROVAL {
+        unsafe {
+            let _: () = msg_send![objc2::class!(SMAppService), openSystemSettingsLoginItems];
+        }
+        return Err("Approve Fluopanel in System Settings > General > Login Items".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn apply_launch_at_login(_enabled: bool) -> Result<(), String> {
+    Err("Launch at login is only supported on macOS".to_string())
+}
+
+/// Apply `settings.launchAtLogin` at startup (if set)
+pub fn sync_launch_at_login() {
+    let enabled = get_config()
+        .ok()
+        .and_then(|config| config.settings.launch_at_login);
+
+    if let Some(enabled) = enabled {
+        if let Err(e) = apply_launch_at_login(enabled) {
+            eprintln!("[login_items] Failed to apply launchAtLogin: {}", e);
+        }
+    }
+}
+
+/// Whether Fluopanel launches at login
+#[command]
+pub fn get_launch_at_login() -> Result<bool, String> {
+    launch_at_login_status()
+}
+
+/// Enable or disable launching Fluopanel at login and save the setting
+#[command]
+pub fn set_launch_at_login(enabled: bool) -> Result<(), String> {
+    apply_launch_at_login(enabled)?;
+
+    let mut config = get_config()?;
+    config.settings.launch_at_login = Some(enabled);
+    write_config(&config)
+}
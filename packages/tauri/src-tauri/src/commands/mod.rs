@@ -1,23 +1,70 @@
+pub mod accessibility;
 pub mod aerospace;
+pub mod artwork;
 #[cfg(target_os = "macos")]
 pub mod audio;
 #[cfg(target_os = "macos")]
 pub mod brightness;
+pub mod calendar;
+pub mod clipboard;
 pub mod config;
 pub mod constants;
+pub mod disk_io;
+pub mod events;
+pub mod fans;
+pub mod focus;
+pub mod geometry;
+pub mod gpu;
 pub mod helpers;
 pub mod icons;
+#[cfg(target_os = "macos")]
+pub mod iokit;
+pub mod keyboard_backlight;
+pub mod login_items;
+pub mod network;
+pub mod permissions;
 pub mod popover;
+#[cfg(target_os = "macos")]
+pub mod popover_dismiss;
+pub mod power_metrics;
+pub mod resource_usage;
+pub mod scheduler;
 pub mod shell;
+pub mod sleep;
+#[cfg(target_os = "macos")]
+pub mod smc;
 pub mod store;
 pub mod system;
+pub mod theme;
+pub mod thermal;
+pub mod user_notifications;
 pub mod window;
+pub mod window_title;
 
+pub use accessibility::*;
 pub use aerospace::*;
+pub use calendar::*;
+pub use clipboard::*;
 pub use config::*;
+pub use events::*;
+pub use fans::*;
+pub use focus::*;
+pub use gpu::*;
+pub use permissions::*;
 pub use popover::*;
+pub use power_metrics::*;
+pub use resource_usage::*;
 pub use icons::*;
+pub use keyboard_backlight::*;
+pub use login_items::*;
+pub use network::*;
+pub use scheduler::*;
 pub use shell::*;
+pub use sleep::*;
 pub use store::*;
 pub use system::*;
+pub use theme::*;
+pub use thermal::*;
+pub use user_notifications::*;
 pub use window::*;
+pub use window_title::*;
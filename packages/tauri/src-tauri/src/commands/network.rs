@@ -0,0 +1,349 @@
+//! Network interfaces
+//!
+//! Lists every interface sysinfo knows about and classifies it using the
+//! SystemConfiguration interface type (Wi-Fi vs. Ethernet can't be told
+//! apart by the `en*` name alone), falling back to the BSD name prefix for
+//! virtual interfaces SystemConfiguration doesn't report (utun, lo, ...).
+//! The primary interface is the one carrying the default route.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Command;
+use sysinfo::Networks;
+use tauri::command;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInterfaceInfo {
+    /// BSD name, e.g. "en0", "utun3"
+    pub name: String,
+    /// "wifi", "ethernet", "loopback", "vpn", "bridge" or "other"
+    #[serde(rename = "type")]
+    pub interface_type: String,
+    /// IPv4/IPv6 addresses in CIDR notation
+    pub addresses: Vec<String>,
+    pub mac_address: Option<String>,
+    /// Has a routable (non link-local) address
+    pub connected: bool,
+    /// Carries the default route
+    pub primary: bool,
+}
+
+#[cfg(target_os = "macos")]
+mod system_configuration {
+    use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex, CFArrayRef};
+    use core_foundation_sys::base::CFRelease;
+    use core_foundation_sys::string::CFStringRef;
+    use objc2_foundation::NSString;
+    use std::collections::HashMap;
+    use std::ffi::c_void;
+
+    #[link(name = "SystemConfiguration", kind = "framework")]
+    extern "C" {
+        fn SCNetworkInterfaceCopyAll() -> CFArrayRef;
+        fn SCNetworkInterfaceGetBSDName(interface: *const c_void) -> CFStringRef;
+        fn SCNetworkInterfaceGetInterfaceType(interface: *const c_void) -> CFStringRef;
+    }
+
+    /// CFString is toll-free bridged to NSString
+    unsafe fn cf_to_string(string: CFStringRef) -> Option<String> {
+        (!string.is_null()).then(|| (*(string as *const NSString)).to_string())
+    }
+
+    /// BSD name -> SCNetworkInterface type ("IEEE80211", "Ethernet", "Bridge", ...)
+    pub fn interface_types() -> HashMap<String, String> {
+        let mut types = HashMap::new();
+
+        unsafe {
+            let interfaces = SCNetworkInterfaceCopyAll();
+            if interfaces.is_null() {
+                return types;
+            }
+
+            for i in 0..CFArrayGetCount(interfaces) {
+                let interface = CFArrayGetValueAtIndex(interfaces, i);
+                let name = cf_to_string(SCNetworkInterfaceGetBSDName(interface));
+                let kind = cf_to_string(SCNetworkInterfaceGetInterfaceType(interface));
+                if let (Some(name), Some(kind)) = (name, kind) {
+                    types.insert(name, kind);
+                }
+            }
+
+            CFRelease(interfaces as *const c_void);
+        }
+
+        types
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn interface_types() -> HashMap<String, String> {
+    system_configuration::interface_types()
+}
+
+#[cfg(not(target_os = "macos"))]
+fn interface_types() -> HashMap<String, String> {
+    HashMap::new()
+}
+
+/// Classify an interface from its SystemConfiguration type, else its name
+fn classify(name: &str, sc_type: Option<&str>) -> &'static str {
+    match sc_type {
+        Some("IEEE80211") => return "wifi",
+        Some("Ethernet") => return "ethernet",
+        Some("Bridge") => return "bridge",
+        Some("PPP" | "IPSec" | "L2TP" | "VPN") => return "vpn",
+        _ => {}
+    }
+
+    const VPN_PREFIXES: &[&str] = &["utun", "ipsec", "ppp", "tun", "tap", "wg"];
+    if name.starts_with("lo") {
+        "loopback"
+    } else if VPN_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+        "vpn"
+    } else if name.starts_with("bridge") {
+        "bridge"
+    } else if name.starts_with("en") {
+        "ethernet"
+    } else {
+        "other"
+    }
+}
+
+/// Interface carrying the default route (`route -n get default`)
+pub fn default_route_interface() -> Option<String> {
+    let output = Command::new("/sbin/route")
+        .args(["-n", "get", "default"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("interface:"))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Every network interface, primary first
+pub fn list_interfaces() -> Vec<NetworkInterfaceInfo> {
+    let networks = Networks::new_with_refreshed_list();
+    let types = interface_types();
+    let primary = default_route_interface();
+
+    let mut interfaces: Vec<NetworkInterfaceInfo> = networks
+        .iter()
+        .map(|(name, data)| {
+            let interface_type = classify(name, types.get(name).map(String::as_str));
+            let connected = interface_type != "loopback"
+                && data.ip_networks().iter().any(|network| match network.addr {
+                    std::net::IpAddr::V4(addr) => !addr.is_link_local() && !addr.is_loopback(),
+                    // fe80::/10 is link-local
+                    std::net::IpAddr::V6(addr) => {
+                        (addr.segments()[0] & 0xffc0) != 0xfe80 && !addr.is_loopback()
+                    }
+                });
+            let mac = data.mac_address();
+
+            NetworkInterfaceInfo {
+                name: name.clone(),
+                interface_type: interface_type.to_string(),
+                addresses: data
+                    .ip_networks()
+                    .iter()
+                    .map(|network| format!("{}/{}", network.addr, network.prefix))
+                    .collect(),
+                mac_address: (!mac.is_unspecified()).then(|| mac.to_string()),
+                connected,
+                primary: primary.as_deref() == Some(name.as_str()),
+            }
+        })
+        .collect();
+
+    interfaces.sort_by(|a, b| b.primary.cmp(&a.primary).then_with(|| a.name.cmp(&b.name)));
+    interfaces
+}
+
+// ============================================
+// Wi-Fi
+// ============================================
+
+/// Details of the current Wi-Fi association
+#[derive(Debug, Clone, Default)]
+pub struct WifiInfo {
+    pub ssid: Option<String>,
+    /// 0-100, derived from RSSI
+    pub signal_strength: Option<i32>,
+    pub band: Option<String>,
+    pub channel: Option<u32>,
+    pub tx_rate_mbps: Option<u32>,
+}
+
+/// Convert RSSI to a rough percentage
+/// RSSI typically ranges from -30 (excellent) to -90 (very weak)
+fn rssi_to_percent(rssi: i32) -> i32 {
+    ((rssi + 90) * 100 / 60).clamp(0, 100)
+}
+
+/// Band of a channel number when the API doesn't report it
+/// (6 GHz channels reuse the same numbers, so only 2.4/5 GHz are inferred)
+fn band_for_channel(channel: u32) -> Option<String> {
+    match channel {
+        1..=14 => Some("2.4GHz".to_string()),
+        32..=177 => Some("5GHz".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod corewlan {
+    use objc2::msg_send;
+    use objc2::rc::autoreleasepool;
+    use objc2::runtime::{AnyClass, AnyObject};
+    use objc2_foundation::NSString;
+
+    use super::{rssi_to_percent, WifiInfo};
+
+    #[link(name = "CoreWLAN", kind = "framework")]
+    extern "C" {}
+
+    /// Read the association of a Wi-Fi interface through CWWiFiClient
+    ///
+    /// The SSID is nil without Location Services permission; the caller
+    /// fills it in from `networksetup`.
+    pub fn read(interface: &str) -> Option<WifiInfo> {
+        // Called from blocking-pool threads, which have no autorelease pool
+        autoreleasepool(|_| {
+            let client_class = AnyClass::get(c"CWWiFiClient")?;
+
+            unsafe {
+                let client: *const AnyObject = msg_send![client_class, sharedWiFiClient];
+                if client.is_null() {
+                    return None;
+                }
+                let wifi: *const AnyObject =
+                    msg_send![client, interfaceWithName: &*NSString::from_str(interface)];
+                if wifi.is_null() {
+                    return None;
+                }
+
+                // No channel means not associated
+                let channel: *const AnyObject = msg_send![wifi, wlanChannel];
+                if channel.is_null() {
+                    return None;
+                }
+                let number: isize = msg_send![channel, channelNumber];
+                // CWChannelBand: 1 = 2 GHz, 2 = 5 GHz, 3 = 6 GHz
+                let band: isize = msg_send![channel, channelBand];
+
+                let ssid: *const NSString = msg_send![wifi, ssid];
+                let rssi: isize = msg_send![wifi, rssiValue];
+                let tx_rate: f64 = msg_send![wifi, transmitRate];
+
+                Some(WifiInfo {
+                    ssid: (!ssid.is_null()).then(|| (*ssid).to_string()),
+                    signal_strength: (rssi != 0).then(|| rssi_to_percent(rssi as i32)),
+                    band: match band {
+                        1 => Some("2.4GHz".to_string()),
+                        2 => Some("5GHz".to_string()),
+                        3 => Some("6GHz".to_string()),
+                        _ => super::band_for_channel(number as u32),
+                    },
+                    channel: (number > 0).then_some(number as u32),
+                    tx_rate_mbps: (tx_rate > 0.0).then_some(tx_rate.round() as u32),
+                })
+            }
+        })
+    }
+}
+
+/// Current network name from `networksetup`
+fn networksetup_ssid(interface: &str) -> Option<String> {
+    let output = Command::new("/usr/sbin/networksetup")
+        .args(["-getairportnetwork", interface])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Output format: "Current Wi-Fi Network: NetworkName"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .strip_prefix("Current Wi-Fi Network: ")
+        .map(|ssid| ssid.trim().to_string())
+        .filter(|ssid| !ssid.is_empty())
+}
+
+/// RSSI, channel and link rate from the (deprecated) `airport -I` utility
+fn airport_info() -> WifiInfo {
+    let mut info = WifiInfo::default();
+
+    let Ok(output) = Command::new(
+        "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport",
+    )
+    .args(["-I"])
+    .output() else {
+        return info;
+    };
+    if !output.status.success() {
+        return info;
+    }
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "agrCtlRSSI" => info.signal_strength = value.parse().ok().map(rssi_to_percent),
+            // e.g. "149,80" (channel, width)
+            "channel" => {
+                info.channel = value.split(',').next().and_then(|c| c.trim().parse().ok());
+                info.band = info.channel.and_then(band_for_channel);
+            }
+            "lastTxRate" => info.tx_rate_mbps = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    info
+}
+
+/// Wi-Fi association details, or None when not associated
+///
+/// CoreWLAN is the primary source; the `airport` CLI (removed in recent
+/// macOS releases) is the fallback.
+pub fn get_wifi_info(interface: &str) -> Option<WifiInfo> {
+    #[cfg(target_os = "macos")]
+    let native = corewlan::read(interface);
+    #[cfg(not(target_os = "macos"))]
+    let native: Option<WifiInfo> = None;
+
+    let ssid = native
+        .as_ref()
+        .and_then(|info| info.ssid.clone())
+        .or_else(|| networksetup_ssid(interface));
+
+    match native {
+        Some(info) => Some(WifiInfo { ssid, ..info }),
+        None => ssid.map(|ssid| WifiInfo {
+            ssid: Some(ssid),
+            ..airport_info()
+        }),
+    }
+}
+
+// ============================================
+// Network Commands
+// ============================================
+
+/// List all network interfaces with type, addresses and state
+#[command]
+pub async fn get_all_interfaces() -> Result<Vec<NetworkInterfaceInfo>, String> {
+    tauri::async_runtime::spawn_blocking(list_interfaces)
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+}
@@ -0,0 +1,338 @@
+//! Privacy Permissions
+//!
+//! Reports which TCC permissions Fluopanel holds so an onboarding widget can
+//! guide users through granting them. Automation is per target app; its
+//! state is None until macOS has asked (or while the target isn't running).
+//! `request_permission` shows the system prompt where macOS allows one and
+//! opens the matching Privacy & Security pane only if the permission had
+//! already been denied, since macOS won't prompt again once it has been.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::command;
+
+/// Apps the media integrations script (checked when no targets are given)
+const DEFAULT_AUTOMATION_TARGETS: &[&str] = &["com.spotify.client", "com.apple.Music"];
+
+/// Permissions already prompted for in this session
+/// (Accessibility and Screen Recording have no readable "denied" state)
+static PROMPTED: Lazy<Mutex<HashSet<PermissionKind>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionKind {
+    Accessibility,
+    ScreenRecording,
+    Automation,
+    InputMonitoring,
+}
+
+impl PermissionKind {
+    /// Privacy & Security pane for this permission
+    fn settings_url(self) -> &'static str {
+        match self {
+            PermissionKind::Accessibility => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility"
+            }
+            PermissionKind::ScreenRecording => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_ScreenCapture"
+            }
+            PermissionKind::Automation => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Automation"
+            }
+            PermissionKind::InputMonitoring => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_ListenEvent"
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionStatus {
+    pub accessibility: bool,
+    pub screen_recording: bool,
+    pub input_monitoring: bool,
+    /// Bundle id -> granted (None: not asked yet, or the app isn't running)
+    pub automation: HashMap<String, Option<bool>>,
+}
+
+#[cfg(target_os = "macos")]
+mod tcc {
+    use core_foundation_sys::base::{kCFAllocatorDefault, CFRelease};
+    use core_foundation_sys::dictionary::{
+        kCFTypeDictionaryKeyCallBacks, kCFTypeDictionaryValueCallBacks, CFDictionaryCreate,
+        CFDictionaryRef,
+    };
+    use core_foundation_sys::number::kCFBooleanTrue;
+    use core_foundation_sys::string::CFStringRef;
+    use std::ffi::c_void;
+
+    /// `typeApplicationBundleID` ('bund')
+    const TYPE_APPLICATION_BUNDLE_ID: u32 = u32::from_be_bytes(*b"bund");
+    /// `typeWildCard` ('****')
+    const TYPE_WILD_CARD: u32 = u32::from_be_bytes(*b"****");
+    /// `errAEEventNotPermitted`
+    const ERR_AE_EVENT_NOT_PERMITTED: i32 = -1743;
+    /// `kIOHIDRequestTypeListenEvent`
+    const IOHID_REQUEST_LISTEN_EVENT: u32 = 1;
+    /// `kIOHIDAccessTypeGranted`
+    const IOHID_ACCESS_GRANTED: u32 = 0;
+    /// `kIOHIDAccessTypeDenied`
+    const IOHID_ACCESS_DENIED: u32 = 1;
+
+    #[repr(C)]
+    struct AEDesc {
+        descriptor_type: u32,
+        data_handle: *mut c_void,
+    }
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        static kAXTrustedCheckOptionPrompt: CFStringRef;
+        fn AXIsProcessTrusted() -> u8;
+        fn AXIsProcessTrustedWithOptions(options: CFDictionaryRef) -> u8;
+    }
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGPreflightScreenCaptureAccess() -> bool;
+        fn CGRequestScreenCaptureAccess() -> bool;
+    }
+
+    #[link(name = "CoreServices", kind = "framework")]
+    extern "C" {
+        fn AECreateDesc(type_code: u32, data: *const c_void, size: isize, result: *mut AEDesc)
+            -> i16;
+        fn AEDisposeDesc(desc: *mut AEDesc) -> i16;
+        fn AEDeterminePermissionToAutomateTarget(
+            target: *const AEDesc,
+            event_class: u32,
+            event_id: u32,
+            ask_user_if_needed: u8,
+        ) -> i32;
+    }
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOHIDCheckAccess(request_type: u32) -> u32;
+        fn IOHIDRequestAccess(request_type: u32) -> bool;
+    }
+
+    pub fn accessibility() -> bool {
+        unsafe { AXIsProcessTrusted() != 0 }
+    }
+
+    /// Shows the "would like to control this computer" prompt if not trusted
+    pub fn request_accessibility() -> bool {
+        unsafe {
+            let keys = [kAXTrustedCheckOptionPrompt as *const c_void];
+            let values = [kCFBooleanTrue as *const c_void];
+            let options = CFDictionaryCreate(
+                kCFAllocatorDefault,
+                keys.as_ptr(),
+                values.as_ptr(),
+                1,
+                &kCFTypeDictionaryKeyCallBacks,
+                &kCFTypeDictionaryValueCallBacks,
+            );
+            let trusted = AXIsProcessTrustedWithOptions(options) != 0;
+            if !options.is_null() {
+                CFRelease(options as *const c_void);
+            }
+            trusted
+        }
+    }
+
+    pub fn screen_recording() -> bool {
+        unsafe { CGPreflightScreenCaptureAccess() }
+    }
+
+    pub fn request_screen_recording() -> bool {
+        unsafe { CGRequestScreenCaptureAccess() }
+    }
+
+    pub fn input_monitoring() -> bool {
+        unsafe { IOHIDCheckAccess(IOHID_REQUEST_LISTEN_EVENT) == IOHID_ACCESS_GRANTED }
+    }
+
+    pub fn input_monitoring_denied() -> bool {
+        unsafe { IOHIDCheckAccess(IOHID_REQUEST_LISTEN_EVENT) == IOHID_ACCESS_DENIED }
+    }
+
+    pub fn request_input_monitoring() -> bool {
+        unsafe { IOHIDRequestAccess(IOHID_REQUEST_LISTEN_EVENT) }
+    }
+
+    /// Whether we may send Apple Events to an app
+    ///
+    /// Blocks while the consent prompt is shown when `ask` is set.
+    pub fn automation(bundle_id: &str, ask: bool) -> Option<bool> {
+        unsafe {
+            let mut target = AEDesc {
+                descriptor_type: 0,
+                data_handle: std::ptr::null_mut(),
+            };
+            let created = AECreateDesc(
+                TYPE_APPLICATION_BUNDLE_ID,
+                bundle_id.as_ptr() as *const c_void,
+                bundle_id.len() as isize,
+                &mut target,
+            );
+            if created != 0 {
+                return None;
+            }
+
+            let status = AEDeterminePermissionToAutomateTarget(
+                &target,
+                TYPE_WILD_CARD,
+                TYPE_WILD_CARD,
+                ask as u8,
+            );
+            AEDisposeDesc(&mut target);
+
+            match status {
+                0 => Some(true),
+                ERR_AE_EVENT_NOT_PERMITTED => Some(false),
+                // Consent not asked yet, or the target isn't running
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Whether Fluopanel may use the Accessibility API
+#[cfg(target_os = "macos")]
+pub fn accessibility_trusted() -> bool {
+    tcc::accessibility()
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn accessibility_trusted() -> bool {
+    true
+}
+
+#[cfg(target_os = "macos")]
+fn read_status(automation_targets: &[String]) -> PermissionStatus {
+    PermissionStatus {
+        accessibility: accessibility_trusted(),
+        screen_recording: tcc::screen_recording(),
+        input_monitoring: tcc::input_monitoring(),
+        automation: automation_targets
+            .iter()
+            .map(|bundle_id| (bundle_id.clone(), tcc::automation(bundle_id, false)))
+            .collect(),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_status(automation_targets: &[String]) -> PermissionStatus {
+    PermissionStatus {
+        accessibility: accessibility_trusted(),
+        screen_recording: true,
+        input_monitoring: true,
+        automation: automation_targets
+            .iter()
+            .map(|bundle_id| (bundle_id.clone(), Some(true)))
+            .collect(),
+    }
+}
+
+/// Show the system prompt for a permission; returns whether it is granted
+#[cfg(target_os = "macos")]
+fn prompt(kind: PermissionKind, target: Option<&str>) -> Result<bool, String> {
+    Ok(match kind {
+        PermissionKind::Accessibility => tcc::request_accessibility(),
+        PermissionKind::ScreenRecording => tcc::request_screen_recording(),
+        PermissionKind::InputMonitoring => tcc::request_input_monitoring(),
+        PermissionKind::Automation => {
+            let target = target.ok_or("Automation needs a target bundle id")?;
+            tcc::automation(target, true).unwrap_or(false)
+        }
+    })
+}
+
+#[cfg(not(target_os = "macos"))]
+fn prompt(_kind: PermissionKind, _target: Option<&str>) -> Result<bool, String> {
+    Ok(true)
+}
+
+/// Whether a permission was denied before this request, so macOS won't prompt
+#[cfg(target_os = "macos")]
+fn previously_denied(kind: PermissionKind, target: Option<&str>) -> bool {
+    match kind {
+        PermissionKind::InputMonitoring => tcc::input_monitoring_denied(),
+        PermissionKind::Automation => {
+            target.is_some_and(|target| tcc::automation(target, false) == Some(false))
+        }
+        // Not granted and already prompted: macOS only prompts once
+        PermissionKind::Accessibility | PermissionKind::ScreenRecording => PROMPTED
+            .lock()
+            .map(|prompted| prompted.contains(&kind))
+            .unwrap_or(false),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn previously_denied(_kind: PermissionKind, _target: Option<&str>) -> bool {
+    false
+}
+
+fn open_settings(kind: PermissionKind) -> Result<(), String> {
+    Command::new("/usr/bin/open")
+        .arg(kind.settings_url())
+        .status()
+        .map_err(|e| format!("Failed to open System Settings: {}", e))?;
+    Ok(())
+}
+
+// ============================================
+// Permission Commands
+// ============================================
+
+/// Which privacy permissions are granted
+/// `automation_targets` lists bundle ids to check (default: Spotify and Music)
+#[command]
+pub async fn check_permissions(
+    automation_targets: Option<Vec<String>>,
+) -> Result<PermissionStatus, String> {
+    let targets = automation_targets.unwrap_or_else(|| {
+        DEFAULT_AUTOMATION_TARGETS
+            .iter()
+            .map(|target| target.to_string())
+            .collect()
+    });
+
+    tauri::async_runtime::spawn_blocking(move || read_status(&targets))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+}
+
+/// Ask for a permission, opening System Settings if it had already been denied
+/// `target` is the bundle id for automation
+#[command]
+pub async fn request_permission(
+    kind: PermissionKind,
+    target: Option<String>,
+) -> Result<bool, String> {
+    // The automation prompt blocks until answered
+    let (granted, denied_before) = tauri::async_runtime::spawn_blocking(move || {
+        let denied_before = previously_denied(kind, target.as_deref());
+        prompt(kind, target.as_deref()).map(|granted| (granted, denied_before))
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))??;
+
+    if let Ok(mut prompted) = PROMPTED.lock() {
+        prompted.insert(kind);
+    }
+
+    if !granted && denied_before {
+        open_settings(kind)?;
+    }
+
+    Ok(granted)
+}
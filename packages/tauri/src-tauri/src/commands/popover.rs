@@ -1,5 +1,8 @@
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use tauri::{command, AppHandle, Emitter, Manager, WebviewUrl};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter, Manager, WebviewUrl, WebviewWindow};
 
 #[cfg(not(target_os = "macos"))]
 use tauri::WebviewWindowBuilder;
@@ -7,8 +10,12 @@ use tauri::WebviewWindowBuilder;
 #[cfg(target_os = "macos")]
 use tauri_nspanel::{tauri_panel, ManagerExt, PanelBuilder, PanelLevel};
 
+use super::events;
 use super::constants::geometry::*;
-use super::helpers::constrain_to_screen;
+use super::geometry::{
+    calculate_available_height, calculate_popover_position, constrain_to_screen,
+    get_monitor_at_point,
+};
 
 // Define NSPanel class for popovers (macOS only)
 #[cfg(target_os = "macos")]
@@ -22,7 +29,7 @@ tauri_panel! {
 }
 
 /// Popover alignment relative to anchor element
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum PopoverAlign {
     Start,
@@ -31,8 +38,21 @@ pub enum PopoverAlign {
     End,
 }
 
+/// How a popover is dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PopoverMode {
+    /// Opening it again closes it; also closes on blur
+    #[default]
+    Toggle,
+    /// Closes once the cursor leaves both the trigger and the popover (macOS)
+    Hover,
+    /// Stays open until a click outside the trigger and popover (macOS)
+    HoverSticky,
+}
+
 /// Popover anchor position (from trigger element's getBoundingClientRect)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PopoverAnchor {
     pub x: f64,
@@ -52,100 +72,46 @@ pub struct PopoverInfo {
     pub max_height: f64,
 }
 
-/// Monitor bounds (x, y, width, height) in logical pixels
-type MonitorBounds = (f64, f64, f64, f64);
-
-// ============================================================================
-// Helper Functions
-// ============================================================================
-
-/// Get monitor bounds containing the anchor point
-fn get_monitor_at_point(app: &AppHandle, x: f64, y: f64) -> Result<MonitorBounds, String> {
-    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
-
-    if monitors.is_empty() {
-        return Err("No monitors available".to_string());
-    }
-
-    // Find monitor containing the point
-    for monitor in &monitors {
-        let pos = monitor.position();
-        let size = monitor.size();
-        let scale = monitor.scale_factor();
-
-        let monitor_x = pos.x as f64 / scale;
-        let monitor_y = pos.y as f64 / scale;
-        let monitor_width = size.width as f64 / scale;
-        let monitor_height = size.height as f64 / scale;
-
-        if x >= monitor_x
-            && x < monitor_x + monitor_width
-            && y >= monitor_y
-            && y < monitor_y + monitor_height
-        {
-            return Ok((monitor_x, monitor_y, monitor_width, monitor_height));
-        }
-    }
-
-    // Fallback to primary monitor
-    let monitor = app
-        .primary_monitor()
-        .map_err(|e| e.to_string())?
-        .unwrap_or_else(|| monitors[0].clone());
-
-    let pos = monitor.position();
-    let size = monitor.size();
-    let scale = monitor.scale_factor();
-
-    Ok((
-        pos.x as f64 / scale,
-        pos.y as f64 / scale,
-        size.width as f64 / scale,
-        size.height as f64 / scale,
-    ))
+/// Requested popover size
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OverlaySize {
+    pub width: f64,
+    pub height: f64,
 }
 
-/// Calculate popover position based on anchor, alignment, and monitor bounds
-fn calculate_popover_position(
-    anchor: &PopoverAnchor,
-    popover_width: f64,
-    popover_height: f64,
-    align: &PopoverAlign,
-    offset_y: f64,
-    monitor: MonitorBounds,
-) -> (f64, f64) {
-    let (monitor_x, monitor_y, monitor_width, monitor_height) = monitor;
-
-    // Y: below anchor with offset
-    let mut y = anchor.y + anchor.height + offset_y;
-
-    // X: based on alignment
-    let mut x = match align {
-        PopoverAlign::Start => anchor.x,
-        PopoverAlign::Center => anchor.x + (anchor.width - popover_width) / 2.0,
-        PopoverAlign::End => anchor.x + anchor.width - popover_width,
-    };
-
-    // Clamp to monitor bounds
-    x = x.max(monitor_x).min(monitor_x + monitor_width - popover_width);
-    y = y
-        .max(monitor_y)
-        .min(monitor_y + monitor_height - popover_height);
-
-    (x, y)
+/// Kind of overlay in a snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverlayType {
+    Popover,
 }
 
-/// Calculate maximum available height from anchor bottom to screen bottom
-fn calculate_available_height(
-    anchor: &PopoverAnchor,
-    offset_y: f64,
-    monitor_y: f64,
-    monitor_height: f64,
-) -> f64 {
-    let popover_top = anchor.y + anchor.height + offset_y;
-    (monitor_y + monitor_height - popover_top).max(MIN_AVAILABLE_HEIGHT)
+/// An open overlay and everything needed to reopen it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlaySnapshot {
+    #[serde(rename = "type")]
+    pub overlay_type: OverlayType,
+    pub id: String,
+    pub anchor: PopoverAnchor,
+    pub size: OverlaySize,
+    #[serde(default)]
+    pub align: PopoverAlign,
+    pub offset_y: Option<f64>,
+    #[serde(default)]
+    pub keyboard: bool,
+    #[serde(default)]
+    pub mode: PopoverMode,
 }
 
+/// Last open parameters per popover id (anchor registry)
+static POPOVER_ANCHORS: Lazy<Mutex<HashMap<String, OverlaySnapshot>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
 /// Build popover URL with parameters
 fn build_popover_url(popover_id: &str, max_height: u32) -> Result<WebviewUrl, String> {
     let url = if cfg!(debug_assertions) {
@@ -164,9 +130,90 @@ fn build_popover_url(popover_id: &str, max_height: u32) -> Result<WebviewUrl, St
     Ok(WebviewUrl::External(parsed_url))
 }
 
+/// Remember how a popover was opened so it can be snapshotted
+fn remember_popover(
+    popover_id: &str,
+    anchor: &PopoverAnchor,
+    width: f64,
+    height: f64,
+    align: &PopoverAlign,
+    offset_y: f64,
+    keyboard: bool,
+    mode: PopoverMode,
+) {
+    if let Ok(mut anchors) = POPOVER_ANCHORS.lock() {
+        anchors.insert(
+            popover_id.to_string(),
+            OverlaySnapshot {
+                overlay_type: OverlayType::Popover,
+                id: popover_id.to_string(),
+                anchor: anchor.clone(),
+                size: OverlaySize { width, height },
+                align: align.clone(),
+                offset_y: Some(offset_y),
+                keyboard,
+                mode,
+            },
+        );
+    }
+}
+
+/// Whether a popover window is currently shown
+fn is_popover_visible(app: &AppHandle, label: &str) -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        app.get_webview_panel(label)
+            .map(|panel| panel.is_visible())
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        app.get_webview_window(label)
+            .and_then(|window| window.is_visible().ok())
+            .unwrap_or(false)
+    }
+}
+
+/// Give a popover keyboard focus so arrow keys / Enter reach the page's handlers
+///
+/// The panel becomes key and the WKWebView its first responder; without the
+/// latter, key events go to the panel and the page never sees them.
+fn focus_popover_webview(window: &WebviewWindow) {
+    #[cfg(target_os = "macos")]
+    {
+        let result = window.with_webview(|webview| unsafe {
+            use objc2::msg_send;
+            use objc2::runtime::{AnyObject, Bool};
+
+            let ns_window = webview.ns_window() as *const AnyObject;
+            let wk_webview = webview.inner() as *const AnyObject;
+            if ns_window.is_null() || wk_webview.is_null() {
+                return;
+            }
+
+            let _: () = msg_send![ns_window, makeKeyWindow];
+            let _: Bool = msg_send![ns_window, makeFirstResponder: wk_webview];
+        });
+        if let Err(e) = result {
+            eprintln!("[popover] Failed to focus webview: {}", e);
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    if let Err(e) = window.set_focus() {
+        eprintln!("[popover] Failed to focus window: {}", e);
+    }
+
+    let _ = window.eval("window.focus(); window.dispatchEvent(new Event('popover-focus'))");
+}
+
 /// Emit popover-closed event with error logging
 fn emit_popover_closed(app: &AppHandle, popover_id: &str) {
-    if let Err(e) = app.emit("popover-closed", popover_id) {
+    #[cfg(target_os = "macos")]
+    super::popover_dismiss::unregister(popover_id);
+
+    if let Err(e) = app.emit(events::POPOVER_CLOSED, popover_id) {
         eprintln!("[popover] Failed to emit popover-closed event: {}", e);
     }
 }
@@ -207,6 +254,10 @@ fn create_macos_panel(
     if let Some(window) = app.get_webview_window(label) {
         window.on_window_event(move |event| {
             if let tauri::WindowEvent::Focused(false) = event {
+                // Sticky popovers close on an outside click instead
+                if !super::popover_dismiss::closes_on_blur(&popover_id_for_blur) {
+                    return;
+                }
                 // Use order_out (hide) instead of close - safe from event handler
                 if let Ok(panel) = app_for_blur.get_webview_panel(&label_for_blur) {
                     panel.hide();
@@ -280,6 +331,8 @@ fn create_standard_window(
 // ============================================================================
 
 /// Open a popover window (toggle mode: if visible, hide it; if hidden, show it; otherwise create new)
+/// With `keyboard`, the popover's webview takes keyboard focus when shown
+/// In the hover modes opening a visible popover keeps it open (moved to the anchor)
 #[command]
 pub fn open_popover(
     app: AppHandle,
@@ -289,86 +342,45 @@ pub fn open_popover(
     height: f64,
     align: Option<PopoverAlign>,
     offset_y: Option<f64>,
+    keyboard: Option<bool>,
+    mode: Option<PopoverMode>,
 ) -> Result<PopoverInfo, String> {
     let label = format!("popover-{}", popover_id);
     let align = align.unwrap_or_default();
     let offset_y = offset_y.unwrap_or(DEFAULT_POPOVER_OFFSET_Y);
+    let keyboard = keyboard.unwrap_or(false);
+    let mode = mode.unwrap_or_default();
 
-    // macOS: Check if panel already exists and reuse it
+    // macOS: panels are hidden rather than destroyed, so check visibility
     #[cfg(target_os = "macos")]
-    {
-        if let Ok(panel) = app.get_webview_panel(&label) {
-            if panel.is_visible() {
-                // Toggle off: hide it (safe from event handler)
-                panel.hide();
-                emit_popover_closed(&app, &popover_id);
-                return Ok(PopoverInfo {
-                    id: popover_id,
-                    label,
-                    closed: true,
-                    max_height: 0.0,
-                });
-            } else {
-                // Toggle on: update position and show
-                let monitor = get_monitor_at_point(&app, anchor.x, anchor.y)?;
-                let (_monitor_x, monitor_y, monitor_width, monitor_height) = monitor;
-
-                let (constrained_width, constrained_height) =
-                    constrain_to_screen(width, height, monitor_width, monitor_height);
-
-                let (x, y) = calculate_popover_position(
-                    &anchor,
-                    constrained_width,
-                    constrained_height,
-                    &align,
-                    offset_y,
-                    monitor,
-                );
-
-                let available_max_height =
-                    calculate_available_height(&anchor, offset_y, monitor_y, monitor_height);
-
-                if let Some(window) = app.get_webview_window(&label) {
-                    if let Err(e) =
-                        window.set_position(tauri::Position::Logical(tauri::LogicalPosition {
-                            x,
-                            y,
-                        }))
-                    {
-                        eprintln!("[popover] Failed to set position: {}", e);
-                    }
+    let was_open = is_popover_visible(&app, &label);
 
-                    // Dispatch event to reset animations (no reload needed)
-                    // This avoids flickering from page reload while keeping animations fresh
-                    let _ = window.eval("window.dispatchEvent(new Event('popover-reopen'))");
-                }
+    // Non-macOS: windows are destroyed on close, so any window is open
+    #[cfg(not(target_os = "macos"))]
+    let was_open = app.get_webview_window(&label).is_some();
 
-                panel.show();
-                return Ok(PopoverInfo {
-                    id: popover_id,
-                    label,
-                    closed: false,
-                    max_height: available_max_height,
-                });
-            }
+    // Toggle off
+    if was_open && mode == PopoverMode::Toggle {
+        #[cfg(target_os = "macos")]
+        if let Ok(panel) = app.get_webview_panel(&label) {
+            // Hide rather than close (safe from event handler)
+            panel.hide();
         }
-    }
 
-    // Non-macOS: Toggle using window destroy
-    #[cfg(not(target_os = "macos"))]
-    {
+        #[cfg(not(target_os = "macos"))]
         if let Some(window) = app.get_webview_window(&label) {
             if let Err(e) = window.destroy() {
                 eprintln!("[popover] Failed to destroy window: {}", e);
             }
-            emit_popover_closed(&app, &popover_id);
-            return Ok(PopoverInfo {
-                id: popover_id,
-                label,
-                closed: true,
-                max_height: 0.0,
-            });
         }
+
+        emit_popover_closed(&app, &popover_id);
+        return Ok(PopoverInfo {
+            id: popover_id,
+            label,
+            closed: true,
+            max_height: 0.0,
+        });
     }
 
     // Get monitor info
@@ -393,33 +405,79 @@ pub fn open_popover(
     let available_max_height =
         calculate_available_height(&anchor, offset_y, monitor_y, monitor_height);
 
-    // Build URL with popover parameter and maxHeight
-    let webview_url = build_popover_url(&popover_id, available_max_height as u32)?;
+    if let Some(window) = app.get_webview_window(&label) {
+        // Reuse the existing window: move it to the anchor and show it
+        if let Err(e) =
+            window.set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }))
+        {
+            eprintln!("[popover] Failed to set position: {}", e);
+        }
 
-    // Create platform-specific window
-    #[cfg(target_os = "macos")]
-    create_macos_panel(
-        &app,
-        &label,
+        // Dispatch event to reset animations (no reload needed)
+        // This avoids flickering from page reload while keeping animations fresh
+        if !was_open {
+            let _ = window.eval("window.dispatchEvent(new Event('popover-reopen'))");
+        }
+
+        #[cfg(target_os = "macos")]
+        if let Ok(panel) = app.get_webview_panel(&label) {
+            panel.show();
+        }
+    } else {
+        // Build URL with popover parameter and maxHeight
+        let webview_url = build_popover_url(&popover_id, available_max_height as u32)?;
+
+        // Create platform-specific window
+        #[cfg(target_os = "macos")]
+        create_macos_panel(
+            &app,
+            &label,
+            &popover_id,
+            webview_url,
+            x,
+            y,
+            constrained_width,
+            constrained_height,
+        )?;
+
+        #[cfg(not(target_os = "macos"))]
+        create_standard_window(
+            &app,
+            &label,
+            &popover_id,
+            webview_url,
+            x,
+            y,
+            constrained_width,
+            constrained_height,
+        )?;
+    }
+
+    if keyboard {
+        if let Some(window) = app.get_webview_window(&label) {
+            focus_popover_webview(&window);
+        }
+    }
+
+    remember_popover(
         &popover_id,
-        webview_url,
-        x,
-        y,
-        constrained_width,
-        constrained_height,
-    )?;
+        &anchor,
+        width,
+        height,
+        &align,
+        offset_y,
+        keyboard,
+        mode,
+    );
 
-    #[cfg(not(target_os = "macos"))]
-    create_standard_window(
+    #[cfg(target_os = "macos")]
+    super::popover_dismiss::register(
         &app,
-        &label,
         &popover_id,
-        webview_url,
-        x,
-        y,
-        constrained_width,
-        constrained_height,
-    )?;
+        mode,
+        (x, y, constrained_width, constrained_height),
+        &anchor,
+    );
 
     Ok(PopoverInfo {
         id: popover_id,
@@ -507,3 +565,55 @@ pub fn get_open_popovers(app: AppHandle) -> Vec<String> {
         .map(|k| k.strip_prefix("popover-").unwrap_or(k).to_string())
         .collect()
 }
+
+/// Snapshot the currently open overlays (for session restore)
+#[command]
+pub fn snapshot_overlays(app: AppHandle) -> Vec<OverlaySnapshot> {
+    let anchors = match POPOVER_ANCHORS.lock() {
+        Ok(anchors) => anchors.clone(),
+        Err(_) => return Vec::new(),
+    };
+
+    let mut snapshot: Vec<OverlaySnapshot> = anchors
+        .into_values()
+        .filter(|overlay| is_popover_visible(&app, &format!("popover-{}", overlay.id)))
+        .collect();
+    snapshot.sort_by(|a, b| a.id.cmp(&b.id));
+    snapshot
+}
+
+/// Reopen overlays from a snapshot (already open ones are left as they are)
+#[command]
+pub fn restore_overlays(
+    app: AppHandle,
+    snapshot: Vec<OverlaySnapshot>,
+) -> Result<Vec<PopoverInfo>, String> {
+    let mut restored = Vec::new();
+
+    for overlay in snapshot {
+        let label = format!("popover-{}", overlay.id);
+        // open_popover toggles, so skip anything already showing
+        if is_popover_visible(&app, &label) {
+            continue;
+        }
+
+        match overlay.overlay_type {
+            OverlayType::Popover => {
+                let info = open_popover(
+                    app.clone(),
+                    overlay.id,
+                    overlay.anchor,
+                    overlay.size.width,
+                    overlay.size.height,
+                    Some(overlay.align),
+                    overlay.offset_y,
+                    Some(overlay.keyboard),
+                    Some(overlay.mode),
+                )?;
+                restored.push(info);
+            }
+        }
+    }
+
+    Ok(restored)
+}
@@ -0,0 +1,274 @@
+//! Popover Dismissal (hover modes)
+//!
+//! `hover` popovers close once the cursor has been outside both the popover
+//! and its trigger for `popover.hoverCloseDelayMs`; the cursor is polled
+//! every `popover.monitorIntervalMs` (see `PopoverConfig` for the defaults).
+//! The `popover` section is read once and refreshed when the config is saved.
+//! `hoverSticky` popovers ignore blur and close on a mouse down outside them,
+//! seen through a global NSEvent monitor (other apps) and a local one
+//! (Fluopanel's own windows).
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Mutex, Once};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use super::config::{get_config, PopoverConfig};
+use super::constants::hover::{
+    MAX_HOVER_CLOSE_DELAY_MS, MAX_TRIGGER_PADDING, MIN_WINDOW_MONITOR_INTERVAL_MS,
+};
+use super::geometry::cursor_position;
+use super::popover::{close_popover, PopoverAnchor, PopoverMode};
+
+/// Screen rect (x, y, width, height) in logical, top-left origin coordinates
+pub type Rect = (f64, f64, f64, f64);
+
+struct Watch {
+    mode: PopoverMode,
+    popover: Rect,
+    trigger: Rect,
+    trigger_padding: f64,
+    /// Distinguishes a re-registered popover from the one a poll is tracking
+    generation: u64,
+}
+
+impl Watch {
+    fn contains(&self, (x, y): (f64, f64)) -> bool {
+        let inside = |(rx, ry, width, height): Rect, padding: f64| {
+            x >= rx - padding
+                && x <= rx + width + padding
+                && y >= ry - padding
+                && y <= ry + height + padding
+        };
+        inside(self.popover, 0.0) || inside(self.trigger, self.trigger_padding)
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    watches: HashMap<String, Watch>,
+    next_generation: u64,
+}
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| Mutex::new(Registry::default()));
+
+/// The `popover` config section, clamped to usable values
+static CONFIG: Lazy<Mutex<PopoverConfig>> = Lazy::new(|| {
+    let config = get_config()
+        .ok()
+        .and_then(|config| config.popover)
+        .unwrap_or_default();
+    Mutex::new(clamped(config))
+});
+
+fn clamped(config: PopoverConfig) -> PopoverConfig {
+    PopoverConfig {
+        hover_close_delay_ms: config.hover_close_delay_ms.min(MAX_HOVER_CLOSE_DELAY_MS),
+        trigger_padding: config.trigger_padding.max(0.0).min(MAX_TRIGGER_PADDING),
+        monitor_interval_ms: config
+            .monitor_interval_ms
+            .max(MIN_WINDOW_MONITOR_INTERVAL_MS),
+    }
+}
+
+/// Apply the `popover` section from fluopanel.json
+/// Called whenever the config is saved; open popovers keep their settings
+pub fn apply_config(config: &PopoverConfig) {
+    if let Ok(mut current) = CONFIG.lock() {
+        *current = clamped(config.clone());
+    }
+}
+
+/// Close a popover on the main thread (panels must be hidden there)
+fn close(app: &AppHandle, popover_id: String) {
+    let app_for_close = app.clone();
+    let result = app.run_on_main_thread(move || {
+        if let Err(e) = close_popover(app_for_close, popover_id) {
+            eprintln!("[popover] Failed to close popover: {}", e);
+        }
+    });
+    if let Err(e) = result {
+        eprintln!("[popover] Failed to schedule popover close: {}", e);
+    }
+}
+
+/// Close sticky popovers a mouse down landed outside of
+fn on_mouse_down(app: &AppHandle) {
+    let Some(point) = cursor_position() else {
+        return;
+    };
+
+    let outside: Vec<String> = match REGISTRY.lock() {
+        Ok(registry) => registry
+            .watches
+            .iter()
+            .filter(|(_, watch)| watch.mode == PopoverMode::HoverSticky && !watch.contains(point))
+            .map(|(popover_id, _)| popover_id.clone())
+            .collect(),
+        Err(_) => return,
+    };
+
+    for popover_id in outside {
+        close(app, popover_id);
+    }
+}
+
+/// Install the mouse-down monitors (once, kept for the app's lifetime)
+fn install_click_monitors(app: &AppHandle) {
+    use block2::RcBlock;
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+    use objc2::{msg_send, ClassType};
+    use objc2_app_kit::{NSEvent, NSEventMask};
+
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let mask =
+            NSEventMask::LeftMouseDown | NSEventMask::RightMouseDown | NSEventMask::OtherMouseDown;
+
+        // Clicks in other apps
+        let app_for_global = app.clone();
+        let global_handler = RcBlock::new(move |_event: *mut AnyObject| {
+            on_mouse_down(&app_for_global);
+        });
+
+        // Clicks in Fluopanel's own windows; the event is passed on unchanged
+        let app_for_local = app.clone();
+        let local_handler = RcBlock::new(move |event: *mut AnyObject| -> *mut AnyObject {
+            on_mouse_down(&app_for_local);
+            event
+        });
+
+        unsafe {
+            let global: Option<Retained<AnyObject>> = msg_send![
+                NSEvent::class(),
+                addGlobalMonitorForEventsMatchingMask: mask.0,
+                handler: &*global_handler
+            ];
+            let local: Option<Retained<AnyObject>> = msg_send![
+                NSEvent::class(),
+                addLocalMonitorForEventsMatchingMask: mask.0,
+                handler: &*local_handler
+            ];
+
+            // Keep the monitors alive
+            std::mem::forget(global);
+            std::mem::forget(local);
+        }
+    });
+}
+
+/// Poll the cursor and close a hover popover once it has been left
+fn spawn_hover_poll(
+    app: AppHandle,
+    popover_id: String,
+    generation: u64,
+    close_delay: Duration,
+    interval: Duration,
+) {
+    std::thread::spawn(move || {
+        let mut outside_since: Option<Instant> = None;
+
+        loop {
+            std::thread::sleep(interval);
+
+            let inside = {
+                let Ok(registry) = REGISTRY.lock() else {
+                    return;
+                };
+                // Closed, or reopened with a newer registration
+                let Some(watch) = registry
+                    .watches
+                    .get(&popover_id)
+                    .filter(|watch| watch.generation == generation)
+                else {
+                    return;
+                };
+                cursor_position().is_none_or(|point| watch.contains(point))
+            };
+
+            if inside {
+                outside_since = None;
+                continue;
+            }
+
+            let since = *outside_since.get_or_insert_with(Instant::now);
+            if since.elapsed() >= close_delay {
+                close(&app, popover_id);
+                return;
+            }
+        }
+    });
+}
+
+/// Track a shown popover so it is dismissed according to its mode
+/// `popover` is the popover's frame and `anchor` its trigger element
+pub fn register(
+    app: &AppHandle,
+    popover_id: &str,
+    mode: PopoverMode,
+    popover: Rect,
+    anchor: &PopoverAnchor,
+) {
+    if mode == PopoverMode::Toggle {
+        unregister(popover_id);
+        return;
+    }
+    if mode == PopoverMode::HoverSticky {
+        install_click_monitors(app);
+    }
+
+    let config = match CONFIG.lock() {
+        Ok(config) => config.clone(),
+        Err(_) => return,
+    };
+
+    let generation = {
+        let Ok(mut registry) = REGISTRY.lock() else {
+            return;
+        };
+        registry.next_generation += 1;
+        let generation = registry.next_generation;
+        registry.watches.insert(
+            popover_id.to_string(),
+            Watch {
+                mode,
+                popover,
+                trigger: (anchor.x, anchor.y, anchor.width, anchor.height),
+                trigger_padding: config.trigger_padding,
+                generation,
+            },
+        );
+        generation
+    };
+
+    if mode == PopoverMode::Hover {
+        spawn_hover_poll(
+            app.clone(),
+            popover_id.to_string(),
+            generation,
+            Duration::from_millis(config.hover_close_delay_ms),
+            Duration::from_millis(config.monitor_interval_ms),
+        );
+    }
+}
+
+/// Stop tracking a popover (called whenever it closes)
+pub fn unregister(popover_id: &str) {
+    if let Ok(mut registry) = REGISTRY.lock() {
+        registry.watches.remove(popover_id);
+    }
+}
+
+/// Whether losing focus should close the popover (not for sticky ones)
+pub fn closes_on_blur(popover_id: &str) -> bool {
+    let Ok(registry) = REGISTRY.lock() else {
+        return true;
+    };
+    registry
+        .watches
+        .get(popover_id)
+        .is_none_or(|watch| watch.mode != PopoverMode::HoverSticky)
+}
@@ -0,0 +1,203 @@
+//! Per-subsystem power consumption (Apple Silicon)
+//!
+//! Samples the IOReport "Energy Model" channels (the source `powermetrics`
+//! uses) twice over a short window and converts the energy delta to watts.
+//! Intel Macs don't publish these channels, so every field is `None` there.
+
+use serde::Serialize;
+use tauri::command;
+
+/// Sampling window between the two IOReport samples
+const SAMPLE_WINDOW_MS: u64 = 200;
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerMetrics {
+    pub cpu_watts: Option<f64>,
+    pub gpu_watts: Option<f64>,
+    pub ane_watts: Option<f64>,
+}
+
+#[cfg(target_os = "macos")]
+mod ioreport {
+    use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex, CFArrayRef};
+    use core_foundation_sys::base::{CFRelease, CFTypeRef};
+    use core_foundation_sys::dictionary::{
+        CFDictionaryGetValue, CFDictionaryRef, CFMutableDictionaryRef,
+    };
+    use core_foundation_sys::string::CFStringRef;
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+    use std::time::{Duration, Instant};
+
+    use super::PowerMetrics;
+
+    #[link(name = "IOReport", kind = "dylib")]
+    extern "C" {
+        fn IOReportCopyChannelsInGroup(
+            group: CFStringRef,
+            subgroup: CFStringRef,
+            a: u64,
+            b: u64,
+            c: u64,
+        ) -> CFMutableDictionaryRef;
+
+        fn IOReportCreateSubscription(
+            allocator: *const c_void,
+            channels: CFMutableDictionaryRef,
+            subscribed: *mut CFMutableDictionaryRef,
+            channel_id: u64,
+            options: CFTypeRef,
+        ) -> *const c_void;
+
+        fn IOReportCreateSamples(
+            subscription: *const c_void,
+            channels: CFMutableDictionaryRef,
+            options: CFTypeRef,
+        ) -> CFDictionaryRef;
+
+        fn IOReportCreateSamplesDelta(
+            prev: CFDictionaryRef,
+            current: CFDictionaryRef,
+            options: CFTypeRef,
+        ) -> CFDictionaryRef;
+
+        fn IOReportChannelGetChannelName(channel: CFDictionaryRef) -> CFStringRef;
+        fn IOReportChannelGetUnitLabel(channel: CFDictionaryRef) -> CFStringRef;
+        fn IOReportSimpleGetIntegerValue(channel: CFDictionaryRef, index: i32) -> i64;
+    }
+
+    /// CFString is toll-free bridged to NSString
+    unsafe fn cf_to_string(string: CFStringRef) -> Option<String> {
+        (!string.is_null()).then(|| (*(string as *const NSString)).to_string())
+    }
+
+    unsafe fn release(object: *const c_void) {
+        if !object.is_null() {
+            CFRelease(object);
+        }
+    }
+
+    /// Joules per unit for an IOReport energy unit label
+    fn joules_per_unit(label: &str) -> Option<f64> {
+        match label.trim() {
+            "mJ" => Some(1e-3),
+            "uJ" | "µJ" => Some(1e-6),
+            "nJ" => Some(1e-9),
+            _ => None,
+        }
+    }
+
+    /// Take two samples `window` apart and sum energy per subsystem
+    pub fn sample(window: Duration) -> PowerMetrics {
+        let mut metrics = PowerMetrics::default();
+
+        unsafe {
+            let group = NSString::from_str("Energy Model");
+            let channels = IOReportCopyChannelsInGroup(
+                &*group as *const NSString as CFStringRef,
+                std::ptr::null(),
+                0,
+                0,
+                0,
+            );
+            if channels.is_null() {
+                return metrics;
+            }
+
+            let mut subscribed: CFMutableDictionaryRef = std::ptr::null_mut();
+            let subscription = IOReportCreateSubscription(
+                std::ptr::null(),
+                channels,
+                &mut subscribed,
+                0,
+                std::ptr::null(),
+            );
+            if subscription.is_null() || subscribed.is_null() {
+                release(subscription);
+                release(channels as *const c_void);
+                return metrics;
+            }
+
+            let started = Instant::now();
+            let first = IOReportCreateSamples(subscription, subscribed, std::ptr::null());
+            std::thread::sleep(window);
+            let second = IOReportCreateSamples(subscription, subscribed, std::ptr::null());
+            let elapsed = started.elapsed().as_secs_f64();
+
+            if !first.is_null() && !second.is_null() && elapsed > 0.0 {
+                let delta = IOReportCreateSamplesDelta(first, second, std::ptr::null());
+                if !delta.is_null() {
+                    accumulate(delta, elapsed, &mut metrics);
+                    release(delta as *const c_void);
+                }
+            }
+
+            release(first as *const c_void);
+            release(second as *const c_void);
+            release(subscribed as *const c_void);
+            release(subscription);
+            release(channels as *const c_void);
+        }
+
+        metrics
+    }
+
+    /// Convert each energy channel in a delta sample to watts
+    unsafe fn accumulate(delta: CFDictionaryRef, elapsed: f64, metrics: &mut PowerMetrics) {
+        let key = NSString::from_str("IOReportChannels");
+        let items =
+            CFDictionaryGetValue(delta, &*key as *const NSString as *const c_void) as CFArrayRef;
+        if items.is_null() {
+            return;
+        }
+
+        for i in 0..CFArrayGetCount(items) {
+            let channel = CFArrayGetValueAtIndex(items, i) as CFDictionaryRef;
+            let Some(name) = cf_to_string(IOReportChannelGetChannelName(channel)) else {
+                continue;
+            };
+            let Some(scale) = cf_to_string(IOReportChannelGetUnitLabel(channel))
+                .as_deref()
+                .and_then(joules_per_unit)
+            else {
+                continue;
+            };
+
+            let watts = IOReportSimpleGetIntegerValue(channel, 0) as f64 * scale / elapsed;
+            let target = if name == "CPU Energy" {
+                &mut metrics.cpu_watts
+            } else if name == "GPU Energy" {
+                &mut metrics.gpu_watts
+            } else if name.starts_with("ANE") {
+                &mut metrics.ane_watts
+            } else {
+                continue;
+            };
+            *target = Some(target.unwrap_or(0.0) + watts);
+        }
+    }
+}
+
+// ============================================
+// Power Metrics Commands
+// ============================================
+
+/// CPU, GPU and ANE power draw in watts (None where unsupported)
+#[command]
+pub async fn get_power_metrics() -> Result<PowerMetrics, String> {
+    #[cfg(target_os = "macos")]
+    {
+        tauri::async_runtime::spawn_blocking(|| {
+            ioreport::sample(std::time::Duration::from_millis(SAMPLE_WINDOW_MS))
+        })
+        .await
+        .map_err(|e| format!("Failed to sample power metrics: {}", e))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = SAMPLE_WINDOW_MS;
+        Ok(PowerMetrics::default())
+    }
+}
@@ -0,0 +1,123 @@
+//! Per-widget resource usage
+//!
+//! Each WKWebView renders in its own WebContent process. The pid comes from
+//! WKWebView's private `_webProcessIdentifier`, and sysinfo reads that
+//! process's CPU and memory. Webviews sharing a process (same process pool)
+//! report the same numbers.
+
+use serde::Serialize;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{command, AppHandle, Manager, WebviewWindow};
+
+/// How long to wait for a webview to report its process
+const PID_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetResourceUsage {
+    pub label: String,
+    /// Window/popover id from the label (None for the coordinator)
+    pub widget_id: Option<String>,
+    pub pid: Option<u32>,
+    /// Percent of one core
+    pub cpu: Option<f32>,
+    pub memory_bytes: Option<u64>,
+}
+
+/// Widget id encoded in a window label
+fn widget_id(label: &str) -> Option<String> {
+    let id = label
+        .strip_prefix("inline-window-")
+        .map(|rest| rest.split(':').next().unwrap_or(rest))
+        .or_else(|| label.strip_prefix("popover-"))?;
+    Some(id.to_string())
+}
+
+/// Pid of the WebContent process rendering a window
+async fn web_process_id(window: &WebviewWindow) -> Option<u32> {
+    #[cfg(target_os = "macos")]
+    {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let result = window.with_webview(move |webview| unsafe {
+            use objc2::runtime::{AnyObject, Bool};
+            use objc2::{msg_send, sel};
+
+            let wk_webview = webview.inner() as *const AnyObject;
+            let pid = if wk_webview.is_null() {
+                None
+            } else {
+                let responds: Bool =
+                    msg_send![wk_webview, respondsToSelector: sel!(_webProcessIdentifier)];
+                if responds.as_bool() {
+                    let pid: i32 = msg_send![wk_webview, _webProcessIdentifier];
+                    (pid > 0).then_some(pid as u32)
+                } else {
+                    None
+                }
+            };
+            let _ = tx.send(pid);
+        });
+        if result.is_err() {
+            return None;
+        }
+
+        tokio::time::timeout(PID_TIMEOUT, rx).await.ok()?.ok()?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = window;
+        None
+    }
+}
+
+/// CPU (sampled over sysinfo's minimum interval) and memory per pid
+fn sample_processes(pids: Vec<Pid>) -> System {
+    let mut sys = System::new();
+    let refresh_kind = ProcessRefreshKind::new().with_cpu().with_memory();
+
+    sys.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, refresh_kind);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, refresh_kind);
+
+    sys
+}
+
+// ============================================
+// Resource Usage Commands
+// ============================================
+
+/// CPU and memory of the web process behind each window
+#[command]
+pub async fn get_widget_resource_usage(app: AppHandle) -> Result<Vec<WidgetResourceUsage>, String> {
+    let mut windows: Vec<(String, WebviewWindow)> = app.webview_windows().into_iter().collect();
+    windows.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut labeled_pids = Vec::with_capacity(windows.len());
+    for (label, window) in &windows {
+        labeled_pids.push((label.clone(), web_process_id(window).await));
+    }
+
+    let pids: Vec<Pid> = labeled_pids
+        .iter()
+        .filter_map(|(_, pid)| pid.map(Pid::from_u32))
+        .collect();
+    let sys = tauri::async_runtime::spawn_blocking(move || sample_processes(pids))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+    Ok(labeled_pids
+        .into_iter()
+        .map(|(label, pid)| {
+            let process = pid.and_then(|pid| sys.process(Pid::from_u32(pid)));
+            WidgetResourceUsage {
+                widget_id: widget_id(&label),
+                label,
+                pid,
+                cpu: process.map(|p| p.cpu_usage()),
+                memory_bytes: process.map(|p| p.memory()),
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,237 @@
+//! Scheduled Ticks and Alarms
+//!
+//! Lets widgets share the backend's clock instead of running their own
+//! `setInterval`. `schedule_tick` emits `tick:{id}` every interval and
+//! `schedule_at` emits `alarm:{id}` once. Ticks with the same interval share
+//! one timer and fire together, aligned to wall-clock multiples of the
+//! interval (a 1000ms tick lands on the second). Deadlines are checked
+//! against the wall clock, so ticks and alarms fire promptly after the Mac
+//! wakes from sleep; missed ticks are not replayed.
+//!
+//! Ticks and alarms belong to the window that scheduled them: ids are per
+//! window, events are emitted to that window only, and they stop when it
+//! closes.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::async_runtime::JoinHandle;
+use tauri::{command, AppHandle, Emitter, EventTarget, WebviewWindow};
+
+use super::events;
+use super::helpers::validate_event_id;
+
+/// Shortest tick interval
+const MIN_INTERVAL_MS: u64 = 50;
+
+/// Longest single sleep; the wall clock is re-read after each, so a deadline
+/// that passed during system sleep is noticed within this time of waking
+const WAKE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// (window label, id): ids only need to be unique within a window
+type Key = (String, String);
+
+struct Alarm {
+    /// Distinguishes a rescheduled alarm from the one a task is waiting on
+    generation: u64,
+    /// The waiting task, aborted when the alarm is cancelled or replaced
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct Scheduler {
+    /// Tick interval in milliseconds by key
+    ticks: HashMap<Key, u64>,
+    /// Intervals with a running timer
+    timers: HashSet<u64>,
+    alarms: HashMap<Key, Alarm>,
+    next_generation: u64,
+}
+
+static SCHEDULER: Lazy<Mutex<Scheduler>> = Lazy::new(|| Mutex::new(Scheduler::default()));
+
+#[derive(Debug, Clone, Serialize)]
+struct TickEvent {
+    /// Unix timestamp (ms) the tick was due
+    timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlarmEvent {
+    /// Unix timestamp (ms) the alarm fired
+    timestamp: u64,
+    /// Unix timestamp (ms) it was scheduled for
+    scheduled_at: u64,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Sleep until the wall clock reaches `deadline_ms`
+async fn sleep_until_ms(deadline_ms: u64) {
+    loop {
+        let remaining = deadline_ms.saturating_sub(now_ms());
+        if remaining == 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(remaining).min(WAKE_CHECK_INTERVAL)).await;
+    }
+}
+
+/// Timer shared by all ticks with this interval; exits once none are left
+async fn run_timer(app: AppHandle, interval_ms: u64) {
+    loop {
+        let due = (now_ms() / interval_ms + 1) * interval_ms;
+        sleep_until_ms(due).await;
+
+        let keys: Vec<Key> = {
+            let Ok(mut scheduler) = SCHEDULER.lock() else {
+                return;
+            };
+            let keys: Vec<Key> = scheduler
+                .ticks
+                .iter()
+                .filter(|(_, interval)| **interval == interval_ms)
+                .map(|(key, _)| key.clone())
+                .collect();
+            if keys.is_empty() {
+                scheduler.timers.remove(&interval_ms);
+                return;
+            }
+            keys
+        };
+
+        for (window, id) in keys {
+            let _ = app.emit_to(
+                EventTarget::webview_window(window.as_str()),
+                &format!("{}{}", events::TICK_PREFIX, id),
+                TickEvent { timestamp: due },
+            );
+        }
+    }
+}
+
+/// Drop the ticks and alarms scheduled by a window (called when it closes)
+pub fn release_window(label: &str) {
+    if let Ok(mut scheduler) = SCHEDULER.lock() {
+        scheduler.ticks.retain(|(window, _), _| window != label);
+        scheduler.alarms.retain(|(window, _), alarm| {
+            let keep = window != label;
+            if !keep {
+                alarm.task.abort();
+            }
+            keep
+        });
+    }
+}
+
+// ============================================
+// Scheduler Commands
+// ============================================
+
+/// Emit `tick:{id}` every `interval_ms` (replaces an existing tick with this id)
+#[command]
+pub fn schedule_tick(
+    app: AppHandle,
+    window: WebviewWindow,
+    id: String,
+    interval_ms: u64,
+) -> Result<(), String> {
+    validate_event_id(&id)?;
+    if interval_ms < MIN_INTERVAL_MS {
+        return Err(format!(
+            "Tick interval must be at least {}ms",
+            MIN_INTERVAL_MS
+        ));
+    }
+
+    let mut scheduler = SCHEDULER.lock().map_err(|e| e.to_string())?;
+    scheduler
+        .ticks
+        .insert((window.label().to_string(), id), interval_ms);
+    if scheduler.timers.insert(interval_ms) {
+        tauri::async_runtime::spawn(run_timer(app, interval_ms));
+    }
+
+    Ok(())
+}
+
+/// Emit `alarm:{id}` once at `unix_ms` (immediately if it has passed)
+/// Replaces an existing alarm with this id
+#[command]
+pub fn schedule_at(
+    app: AppHandle,
+    window: WebviewWindow,
+    id: String,
+    unix_ms: u64,
+) -> Result<(), String> {
+    validate_event_id(&id)?;
+
+    let key: Key = (window.label().to_string(), id);
+    // Held until the alarm is stored, so a task that is due at once finds it
+    let mut scheduler = SCHEDULER.lock().map_err(|e| e.to_string())?;
+    scheduler.next_generation += 1;
+    let generation = scheduler.next_generation;
+
+    let task_key = key.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        sleep_until_ms(unix_ms).await;
+
+        // Skip if replaced while this task was about to fire
+        let current = SCHEDULER.lock().is_ok_and(|mut scheduler| {
+            let current = scheduler
+                .alarms
+                .get(&task_key)
+                .is_some_and(|alarm| alarm.generation == generation);
+            if current {
+                scheduler.alarms.remove(&task_key);
+            }
+            current
+        });
+        if !current {
+            return;
+        }
+
+        let (window, id) = task_key;
+        let event = AlarmEvent {
+            timestamp: now_ms(),
+            scheduled_at: unix_ms,
+        };
+        let _ = app.emit_to(
+            EventTarget::webview_window(window.as_str()),
+            &format!("{}{}", events::ALARM_PREFIX, id),
+            event,
+        );
+    });
+
+    if let Some(replaced) = scheduler.alarms.insert(key, Alarm { generation, task }) {
+        replaced.task.abort();
+    }
+
+    Ok(())
+}
+
+/// Stop a tick or pending alarm scheduled by this window
+#[command]
+pub fn cancel_tick(window: WebviewWindow, id: String) -> Result<(), String> {
+    let key: Key = (window.label().to_string(), id);
+    let mut scheduler = SCHEDULER.lock().map_err(|e| e.to_string())?;
+    let tick = scheduler.ticks.remove(&key);
+    let alarm = scheduler.alarms.remove(&key);
+    if let Some(alarm) = &alarm {
+        alarm.task.abort();
+    }
+
+    let (_, id) = key;
+    if tick.is_none() && alarm.is_none() {
+        return Err(format!("No tick or alarm with id '{}'", id));
+    }
+    Ok(())
+}
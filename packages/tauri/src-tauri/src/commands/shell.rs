@@ -1,17 +1,336 @@
-use std::process::Command;
-use tauri::command;
+//! Shell Commands
+//!
+//! Widgets may come from third parties, so a command only runs if its
+//! program is in `shell.allow` (fluopanel.json): a bare entry allows the
+//! executable it resolves to on PATH, a path entry only that exact path.
+//! Without an allowlist, debug builds allow everything and release builds
+//! nothing. Command lines that could start a second program (`;`, `|`, `&`,
+//! backticks, `$(`, redirection, newlines) are refused unless the allowlist
+//! contains "*".
+//! Every command is killed once its timeout passes.
+//!
+//! Streamed commands (`execute_shell_stream`) run a program directly, without
+//! a shell, and report each output line as `shell-output:{id}` and the exit as
+//! `shell-exit:{id}`. They run until they exit, `kill_shell` is called, or
+//! the window that started them closes.
 
-#[command]
-pub fn execute_shell(command: String) -> Result<String, String> {
-    let output = Command::new("sh")
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, WebviewWindow};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::Notify;
+
+use super::config::{get_config, ShellConfig};
+use super::events;
+use super::helpers::validate_event_id;
+
+/// Characters that would let a command line run more than one program
+const CHAINING_PATTERNS: &[&str] = &[";", "|", "&", "`", "$(", ">", "<", "\n"];
+
+/// A streamed command: the window that started it and its kill signal
+struct RunningCommand {
+    window: String,
+    kill: Arc<Notify>,
+}
+
+/// Streamed commands by id
+static RUNNING: Lazy<Mutex<HashMap<String, RunningCommand>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShellOutput {
+    /// Exit code (None if killed by a signal)
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Program a command line starts (first word, unquoted)
+fn program_of(command: &str) -> Option<&str> {
+    command
+        .split_whitespace()
+        .next()
+        .map(|word| word.trim_matches(|c| c == '"' || c == '\''))
+        .filter(|word| !word.is_empty())
+}
+
+#[cfg(unix)]
+fn is_executable(meta: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    meta.is_file() && meta.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(meta: &std::fs::Metadata) -> bool {
+    meta.is_file()
+}
+
+/// Executable a bare program name runs, searched along PATH
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.metadata().is_ok_and(|meta| is_executable(&meta)))
+}
+
+/// Whether an allowlist entry names this program
+///
+/// A program given as a path must match a path entry exactly; a bare name
+/// must resolve along PATH to the same executable as the entry, so an
+/// allowed `code` never admits `/tmp/evil/code`.
+fn entry_matches(entry: &str, program: &str) -> bool {
+    if program.contains('/') {
+        return entry == program;
+    }
+
+    let Some(resolved) = find_in_path(program) else {
+        return false;
+    };
+    if entry.contains('/') {
+        Path::new(entry) == resolved
+    } else {
+        find_in_path(entry).is_some_and(|entry| entry == resolved)
+    }
+}
+
+/// The allowlist, or an error if shell commands are disabled
+fn allowlist(config: &ShellConfig) -> Result<Option<&[String]>, String> {
+    match &config.allow {
+        Some(allow) if allow.iter().any(|entry| entry == "*") => Ok(None),
+        Some(allow) => Ok(Some(allow)),
+        None if cfg!(debug_assertions) => Ok(None),
+        None => Err(
+            "Shell commands are disabled; list allowed programs in shell.allow in fluopanel.json"
+                .to_string(),
+        ),
+    }
+}
+
+/// Check a program against the allowlist
+fn check_program_allowed(allow: &[String], program: &str) -> Result<(), String> {
+    if allow.iter().any(|entry| entry_matches(entry, program)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Program '{}' is not in shell.allow in fluopanel.json",
+            program
+        ))
+    }
+}
+
+/// Check a command line against the allowlist
+fn check_allowed(config: &ShellConfig, command: &str) -> Result<(), String> {
+    let Some(allow) = allowlist(config)? else {
+        return Ok(());
+    };
+
+    if let Some(pattern) = CHAINING_PATTERNS.iter().find(|p| command.contains(*p)) {
+        return Err(format!(
+            "Command contains '{}', which is only allowed with \"*\" in shell.allow",
+            pattern.escape_debug()
+        ));
+    }
+
+    let program = program_of(command).ok_or_else(|| "Empty command".to_string())?;
+    check_program_allowed(allow, program)
+}
+
+/// Run a command line through `sh -c`, killing it after `timeout_ms`
+async fn run(command: &str, timeout_ms: Option<u64>) -> Result<ShellOutput, String> {
+    let config = get_config()?.shell.unwrap_or_default();
+    check_allowed(&config, command)?;
+
+    let child = Command::new("sh")
         .arg("-c")
-        .arg(&command)
-        .output()
-        .map_err(|e| e.to_string())?;
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to run command: {}", e))?;
 
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(config.timeout_ms));
+    // Dropping the future on timeout kills the child
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| format!("Command timed out after {}ms", timeout.as_millis()))?
+        .map_err(|e| format!("Failed to run command: {}", e))?;
+
+    Ok(ShellOutput {
+        code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OutputLine {
+    /// "stdout" or "stderr"
+    stream: &'static str,
+    line: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExitStatus {
+    code: Option<i32>,
+    /// Ended by `kill_shell` or its window closing
+    killed: bool,
+}
+
+/// Emit each line of a child's output stream
+async fn forward_lines(
+    app: AppHandle,
+    event_name: String,
+    stream: &'static str,
+    reader: impl AsyncRead + Unpin,
+) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let _ = app.emit(&event_name, OutputLine { stream, line });
+    }
+}
+
+/// Kill the streamed commands started by a window (called when it closes)
+pub fn release_window(label: &str) {
+    if let Ok(running) = RUNNING.lock() {
+        for command in running.values().filter(|command| command.window == label) {
+            command.kill.notify_one();
+        }
+    }
+}
+
+// ============================================
+// Shell Commands
+// ============================================
+
+/// Run a command and return its stdout (stderr as the error on failure)
+#[command]
+pub async fn execute_shell(command: String, timeout_ms: Option<u64>) -> Result<String, String> {
+    let output = run(&command, timeout_ms).await?;
+
+    if output.code == Some(0) {
+        Ok(output.stdout)
     } else {
-        Err(String::from_utf8_lossy(&output.stderr).to_string())
+        Err(output.stderr)
     }
 }
+
+/// Run a command and return its exit code, stdout and stderr separately
+#[command]
+pub async fn run_shell(command: String, timeout_ms: Option<u64>) -> Result<ShellOutput, String> {
+    run(&command, timeout_ms).await
+}
+
+/// Start a program and stream its output as `shell-output:{id}` events,
+/// followed by `shell-exit:{id}` with the exit code
+#[command]
+pub async fn execute_shell_stream(
+    app: AppHandle,
+    window: WebviewWindow,
+    id: String,
+    program: String,
+    args: Option<Vec<String>>,
+) -> Result<(), String> {
+    validate_event_id(&id)?;
+    let config = get_config()?.shell.unwrap_or_default();
+    if let Some(allow) = allowlist(&config)? {
+        check_program_allowed(allow, &program)?;
+    }
+
+    let kill = Arc::new(Notify::new());
+    {
+        let mut running = RUNNING.lock().map_err(|e| e.to_string())?;
+        if running.contains_key(&id) {
+            return Err(format!("A command with id '{}' is already running", id));
+        }
+        running.insert(
+            id.clone(),
+            RunningCommand {
+                window: window.label().to_string(),
+                kill: kill.clone(),
+            },
+        );
+    }
+
+    let spawned = Command::new(&program)
+        .args(args.unwrap_or_default())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            if let Ok(mut running) = RUNNING.lock() {
+                running.remove(&id);
+            }
+            return Err(format!("Failed to start '{}': {}", program, e));
+        }
+    };
+
+    let output_event = format!("{}{}", events::SHELL_OUTPUT_PREFIX, id);
+    let readers = [
+        child.stdout.take().map(|stdout| {
+            tauri::async_runtime::spawn(forward_lines(
+                app.clone(),
+                output_event.clone(),
+                "stdout",
+                stdout,
+            ))
+        }),
+        child.stderr.take().map(|stderr| {
+            tauri::async_runtime::spawn(forward_lines(
+                app.clone(),
+                output_event.clone(),
+                "stderr",
+                stderr,
+            ))
+        }),
+    ];
+
+    tauri::async_runtime::spawn(async move {
+        let (status, killed) = tokio::select! {
+            status = child.wait() => (status.ok(), false),
+            _ = kill.notified() => {
+                let _ = child.kill().await;
+                (child.wait().await.ok(), true)
+            }
+        };
+
+        // Deliver the remaining output before the exit event
+        for reader in readers.into_iter().flatten() {
+            let _ = reader.await;
+        }
+
+        if let Ok(mut running) = RUNNING.lock() {
+            running.remove(&id);
+        }
+
+        let exit = ExitStatus {
+            code: status.and_then(|status| status.code()),
+            killed,
+        };
+        let _ = app.emit(&format!("{}{}", events::SHELL_EXIT_PREFIX, id), exit);
+    });
+
+    Ok(())
+}
+
+/// Terminate a streamed command
+#[command]
+pub fn kill_shell(id: String) -> Result<(), String> {
+    let running = RUNNING.lock().map_err(|e| e.to_string())?;
+    let command = running
+        .get(&id)
+        .ok_or_else(|| format!("No running command with id '{}'", id))?;
+    command.kill.notify_one();
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Sleep prevention (caffeinate)
+//!
+//! Holds an IOKit power assertion that blocks idle system sleep.
+//! The assertion id lives in a static so it can be released later,
+//! and `release_sleep_assertion` is called when the app exits.
+
+use std::sync::Mutex;
+use tauri::command;
+
+/// Currently held assertion id (None = sleep allowed)
+static ASSERTION_ID: Mutex<Option<u32>> = Mutex::new(None);
+
+const DEFAULT_REASON: &str = "Fluopanel keep awake";
+
+#[cfg(target_os = "macos")]
+mod iokit {
+    use core_foundation_sys::base::{CFRelease, CFTypeRef};
+    use core_foundation_sys::string::{kCFStringEncodingUTF8, CFStringCreateWithBytes, CFStringRef};
+
+    pub const IOKIT_SUCCESS: i32 = 0;
+    /// kIOPMAssertionLevelOn
+    pub const ASSERTION_LEVEL_ON: u32 = 255;
+    /// kIOPMAssertionTypePreventUserIdleSystemSleep
+    pub const PREVENT_USER_IDLE_SYSTEM_SLEEP: &str = "PreventUserIdleSystemSleep";
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        pub fn IOPMAssertionCreateWithName(
+            assertion_type: CFStringRef,
+            level: u32,
+            name: CFStringRef,
+            assertion_id: *mut u32,
+        ) -> i32;
+
+        pub fn IOPMAssertionRelease(assertion_id: u32) -> i32;
+    }
+
+    /// Owned CFString, released on drop
+    pub struct CfString(pub CFStringRef);
+
+    impl CfString {
+        pub fn new(s: &str) -> Self {
+            let string = unsafe {
+                CFStringCreateWithBytes(
+                    std::ptr::null(),
+                    s.as_ptr(),
+                    s.len() as _,
+                    kCFStringEncodingUTF8,
+                    0,
+                )
+            };
+            CfString(string)
+        }
+    }
+
+    impl Drop for CfString {
+        fn drop(&mut self) {
+            if !self.0.is_null() {
+                unsafe { CFRelease(self.0 as CFTypeRef) };
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn create_assertion(reason: &str) -> Result<u32, String> {
+    use iokit::*;
+
+    let assertion_type = CfString::new(PREVENT_USER_IDLE_SYSTEM_SLEEP);
+    let name = CfString::new(reason);
+    let mut id = 0u32;
+
+    let result = unsafe {
+        IOPMAssertionCreateWithName(assertion_type.0, ASSERTION_LEVEL_ON, name.0, &mut id)
+    };
+
+    if result == IOKIT_SUCCESS {
+        Ok(id)
+    } else {
+        Err(format!("Failed to create power assertion: {:#x}", result))
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn create_assertion(_reason: &str) -> Result<u32, String> {
+    Err("Sleep prevention is only supported on macOS".to_string())
+}
+
+fn release_assertion(id: u32) {
+    #[cfg(target_os = "macos")]
+    unsafe {
+        let result = iokit::IOPMAssertionRelease(id);
+        if result != iokit::IOKIT_SUCCESS {
+            eprintln!("[sleep] Failed to release power assertion {}: {:#x}", id, result);
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    let _ = id;
+}
+
+/// Release the sleep assertion if one is held (called on app exit)
+pub fn release_sleep_assertion() {
+    let held = ASSERTION_ID.lock().ok().and_then(|mut id| id.take());
+    if let Some(id) = held {
+        release_assertion(id);
+    }
+}
+
+// ============================================
+// Sleep Commands
+// ============================================
+
+/// Block (or allow again) idle system sleep
+#[command]
+pub fn prevent_sleep(enabled: bool, reason: Option<String>) -> Result<(), String> {
+    let mut held = ASSERTION_ID
+        .lock()
+        .map_err(|e| format!("Failed to lock sleep state: {}", e))?;
+
+    if enabled {
+        // Replace any existing assertion so a new reason takes effect
+        let id = create_assertion(reason.as_deref().unwrap_or(DEFAULT_REASON))?;
+        if let Some(previous) = held.replace(id) {
+            release_assertion(previous);
+        }
+    } else if let Some(id) = held.take() {
+        release_assertion(id);
+    }
+
+    Ok(())
+}
+
+/// Whether sleep is currently being prevented
+#[command]
+pub fn is_sleep_prevented() -> bool {
+    ASSERTION_ID.lock().map(|id| id.is_some()).unwrap_or(false)
+}
@@ -0,0 +1,163 @@
+//! Apple SMC access
+//!
+//! Minimal reader for System Management Controller keys (fan speeds, and
+//! sensors such as temperatures) through the `AppleSMC` IOKit user client.
+//! The connection is opened on first use and kept for the app's lifetime.
+
+use super::iokit::{
+    IOConnectCallStructMethod, IOObjectRelease, IOServiceClose, IOServiceGetMatchingService,
+    IOServiceMatching, IOServiceOpen,
+};
+use once_cell::sync::Lazy;
+use std::ffi::c_void;
+use std::sync::Mutex;
+
+/// Selector of the SMC user client's struct method
+const KERNEL_INDEX_SMC: u32 = 2;
+const CMD_READ_BYTES: u8 = 5;
+const CMD_READ_KEY_INFO: u8 = 9;
+const KERN_SUCCESS: i32 = 0;
+
+extern "C" {
+    static mach_task_self_: u32;
+}
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct Version {
+    major: u8,
+    minor: u8,
+    build: u8,
+    reserved: u8,
+    release: u16,
+}
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct PLimitData {
+    version: u16,
+    length: u16,
+    cpu_p_limit: u32,
+    gpu_p_limit: u32,
+    mem_p_limit: u32,
+}
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct KeyInfo {
+    data_size: u32,
+    data_type: u32,
+    data_attributes: u8,
+}
+
+/// `SMCKeyData_t`, the struct exchanged with the user client
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct KeyData {
+    key: u32,
+    vers: Version,
+    p_limit_data: PLimitData,
+    key_info: KeyInfo,
+    result: u8,
+    status: u8,
+    data8: u8,
+    data32: u32,
+    bytes: [u8; 32],
+}
+
+/// An open connection to the SMC
+pub struct Smc {
+    connection: u32,
+}
+
+impl Smc {
+    fn open() -> Option<Self> {
+        unsafe {
+            // IOServiceGetMatchingService consumes the matching dictionary
+            let matching = IOServiceMatching(c"AppleSMC".as_ptr());
+            if matching.is_null() {
+                return None;
+            }
+            let service = IOServiceGetMatchingService(0, matching);
+            if service == 0 {
+                return None;
+            }
+
+            let mut connection = 0u32;
+            let result = IOServiceOpen(service, mach_task_self_, 0, &mut connection);
+            IOObjectRelease(service);
+            (result == KERN_SUCCESS).then_some(Smc { connection })
+        }
+    }
+
+    fn call(&self, input: &KeyData) -> Option<KeyData> {
+        let mut output = KeyData::default();
+        let mut output_size = std::mem::size_of::<KeyData>();
+        let result = unsafe {
+            IOConnectCallStructMethod(
+                self.connection,
+                KERNEL_INDEX_SMC,
+                input as *const KeyData as *const c_void,
+                std::mem::size_of::<KeyData>(),
+                &mut output as *mut KeyData as *mut c_void,
+                &mut output_size,
+            )
+        };
+        // A non-zero result byte means the SMC doesn't know the key
+        (result == KERN_SUCCESS && output.result == 0).then_some(output)
+    }
+
+    /// Read a key as a number, decoding the SMC data types in use on Macs
+    /// (`flt `, `fpe2`, `sp78`, `ui8 `, `ui16`, `ui32`)
+    pub fn read(&self, key: &str) -> Option<f64> {
+        let key = four_char_code(key)?;
+
+        let info = self.call(&KeyData {
+            key,
+            data8: CMD_READ_KEY_INFO,
+            ..Default::default()
+        })?;
+        let data = self.call(&KeyData {
+            key,
+            key_info: info.key_info,
+            data8: CMD_READ_BYTES,
+            ..Default::default()
+        })?;
+
+        let bytes = &data.bytes;
+        let size = info.key_info.data_size as usize;
+        match &info.key_info.data_type.to_be_bytes() {
+            b"flt " if size == 4 => {
+                Some(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64)
+            }
+            b"fpe2" if size == 2 => Some(u16::from_be_bytes([bytes[0], bytes[1]]) as f64 / 4.0),
+            b"sp78" if size == 2 => Some(i16::from_be_bytes([bytes[0], bytes[1]]) as f64 / 256.0),
+            b"ui8 " if size == 1 => Some(bytes[0] as f64),
+            b"ui16" if size == 2 => Some(u16::from_be_bytes([bytes[0], bytes[1]]) as f64),
+            b"ui32" if size == 4 => {
+                Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Drop for Smc {
+    fn drop(&mut self) {
+        unsafe { IOServiceClose(self.connection) };
+    }
+}
+
+fn four_char_code(key: &str) -> Option<u32> {
+    let bytes: [u8; 4] = key.as_bytes().try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
+
+/// Shared connection (None if the SMC couldn't be opened)
+static SMC: Lazy<Mutex<Option<Smc>>> = Lazy::new(|| Mutex::new(Smc::open()));
+
+/// Run `f` with the SMC connection; None if the SMC is unavailable
+pub fn with_smc<T>(f: impl FnOnce(&Smc) -> T) -> Option<T> {
+    let smc = SMC.lock().ok()?;
+    smc.as_ref().map(f)
+}
@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use std::sync::Mutex;
 use tauri::{command, AppHandle, Emitter};
 
+use super::events;
+
 /// Global in-memory store for cross-window state sharing
 static STORE: Lazy<Mutex<HashMap<String, Value>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 
@@ -14,7 +16,7 @@ pub fn store_set(app: AppHandle, key: String, value: Value) -> Result<(), String
     store.insert(key.clone(), value.clone());
 
     // Broadcast to all windows
-    let event_name = format!("store-changed:{}", key);
+    let event_name = format!("{}{}", events::STORE_CHANGED_PREFIX, key);
     app.emit(&event_name, value).map_err(|e| e.to_string())?;
 
     Ok(())
@@ -34,7 +36,7 @@ pub fn store_delete(app: AppHandle, key: String) -> Result<(), String> {
     store.remove(&key);
 
     // Broadcast deletion (null value indicates removal)
-    let event_name = format!("store-changed:{}", key);
+    let event_name = format!("{}{}", events::STORE_CHANGED_PREFIX, key);
     app.emit(&event_name, Value::Null).map_err(|e| e.to_string())?;
 
     Ok(())
@@ -1,9 +1,15 @@
 use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::sync::Mutex;
-use sysinfo::{Disks, Networks, System};
+use sysinfo::{Disks, ProcessRefreshKind, ProcessesToUpdate, System};
 use tauri::command;
 
+use super::artwork::{cached_artwork, fetch_applescript_artwork};
+use super::config::get_config;
+use super::disk_io::disk_io_rates;
+use super::icons::app_icons_by_name;
+use super::network::{get_wifi_info, list_interfaces, WifiInfo};
+
 // 静的 System インスタンス（再利用してメモリ節約）
 static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new()));
 static DISKS: Lazy<Mutex<Disks>> = Lazy::new(|| Mutex::new(Disks::new_with_refreshed_list()));
@@ -15,6 +21,8 @@ pub struct BatteryInfo {
     pub charging: bool,
     pub time_to_empty: Option<i32>,
     pub time_to_full: Option<i32>,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -24,10 +32,69 @@ pub struct CpuInfo {
 }
 
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MemoryInfo {
     pub total: u64,
     pub used: u64,
     pub usage: f32,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub pressure: MemoryPressure,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStats {
+    pub uptime_secs: u64,
+    /// 1, 5 and 15 minute load averages
+    pub load_avg: [f64; 3],
+    pub process_count: usize,
+    /// Unix timestamp (seconds)
+    pub boot_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    /// Percent of one core (can exceed 100 on multi-core machines)
+    pub cpu_usage: f32,
+    /// Resident memory (bytes)
+    pub memory: u64,
+    /// Base64-encoded PNG (apps only, when icons were requested)
+    pub icon: Option<String>,
+}
+
+/// Memory breakdown matching Activity Monitor (bytes)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryDetail {
+    pub total: u64,
+    /// App memory (anonymous pages that aren't purgeable)
+    pub app: u64,
+    pub wired: u64,
+    pub active: u64,
+    pub inactive: u64,
+    /// Memory occupied by the compressor
+    pub compressed: u64,
+    /// File-backed and purgeable pages macOS can reclaim
+    pub cached: u64,
+    pub free: u64,
+    /// app + wired + compressed, Activity Monitor's "Memory Used"
+    pub used: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub pressure: MemoryPressure,
+}
+
+/// System memory pressure, as shown in Activity Monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MemoryPressure {
+    Normal,
+    Warning,
+    Critical,
 }
 
 #[derive(Debug, Serialize)]
@@ -38,6 +105,10 @@ pub struct NetworkInfo {
     pub network_type: String,
     pub ssid: Option<String>,
     pub signal_strength: Option<i32>,
+    /// "2.4GHz", "5GHz" or "6GHz"
+    pub band: Option<String>,
+    pub channel: Option<u32>,
+    pub tx_rate_mbps: Option<u32>,
     pub connected: bool,
 }
 
@@ -58,6 +129,16 @@ pub struct ActiveAppInfo {
     pub pid: Option<i32>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningAppInfo {
+    pub name: String,
+    pub bundle_id: Option<String>,
+    pub pid: i32,
+    pub is_active: bool,
+    pub is_hidden: bool,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DiskInfo {
@@ -66,6 +147,15 @@ pub struct DiskInfo {
     pub available: u64,
     pub usage: f32,
     pub mount_point: String,
+    /// External/ejectable drive
+    pub removable: bool,
+    /// e.g. "apfs", "exfat", "smbfs"
+    pub file_system: String,
+    /// System-wide read throughput since the previous call
+    /// (root mount only; None on other mounts and on the first call)
+    pub read_bytes_per_sec: Option<u64>,
+    /// System-wide write throughput, reported like `read_bytes_per_sec`
+    pub write_bytes_per_sec: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -88,6 +178,19 @@ pub struct BrightnessInfo {
     pub display_name: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayInfo {
+    /// CGDirectDisplayID (same as MonitorInfo.id)
+    pub id: u32,
+    pub name: Option<String>,
+    pub is_main: bool,
+    /// Whether brightness can be read/set natively (usually built-in displays only)
+    pub supports_brightness: bool,
+    /// Current brightness (0-100), if supported
+    pub brightness: Option<f32>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BluetoothDevice {
@@ -105,41 +208,68 @@ pub struct BluetoothInfo {
     pub devices: Vec<BluetoothDevice>,
 }
 
-#[command]
-pub fn get_battery_info() -> Result<BatteryInfo, String> {
+fn to_battery_info(battery: &battery::Battery) -> BatteryInfo {
+    use battery::State;
+
+    let percent = battery.state_of_charge().value * 100.0;
+    let charging = matches!(battery.state(), State::Charging | State::Full);
+
+    let time_to_empty = battery.time_to_empty().map(|t| (t.value / 60.0) as i32);
+    let time_to_full = battery.time_to_full().map(|t| (t.value / 60.0) as i32);
+
+    BatteryInfo {
+        percent,
+        charging,
+        time_to_empty,
+        time_to_full,
+        vendor: battery.vendor().map(|v| v.trim().to_string()),
+        model: battery.model().map(|m| m.trim().to_string()),
+    }
+}
+
+/// Read every battery the system reports
+fn read_batteries() -> Result<Vec<BatteryInfo>, String> {
     let manager = battery::Manager::new()
         .map_err(|e| format!("Failed to create battery manager: {}", e))?;
 
-    let mut batteries = manager
+    let batteries = manager
         .batteries()
         .map_err(|e| format!("Failed to get batteries: {}", e))?;
 
-    if let Some(Ok(battery)) = batteries.next() {
-        use battery::State;
-
-        let percent = battery.state_of_charge().value * 100.0;
-        let charging = matches!(battery.state(), State::Charging | State::Full);
-
-        let time_to_empty = battery.time_to_empty().map(|t| (t.value / 60.0) as i32);
-        let time_to_full = battery.time_to_full().map(|t| (t.value / 60.0) as i32);
-
-        Ok(BatteryInfo {
-            percent,
-            charging,
-            time_to_empty,
-            time_to_full,
+    Ok(batteries
+        .filter_map(|battery| match battery {
+            Ok(battery) => Some(to_battery_info(&battery)),
+            Err(e) => {
+                eprintln!("[battery] Failed to read battery: {}", e);
+                None
+            }
         })
-    } else {
+        .collect())
+}
+
+/// Primary battery (convenience for single-battery widgets)
+#[command]
+pub fn get_battery_info() -> Result<BatteryInfo, String> {
+    match read_batteries()?.into_iter().next() {
+        Some(battery) => Ok(battery),
         // No battery found (desktop Mac)
-        Ok(BatteryInfo {
+        None => Ok(BatteryInfo {
             percent: 100.0,
             charging: true,
             time_to_empty: None,
             time_to_full: None,
-        })
+            vendor: None,
+            model: None,
+        }),
     }
 }
 
+/// All batteries (empty on desktop Macs)
+#[command]
+pub fn get_all_batteries() -> Result<Vec<BatteryInfo>, String> {
+    read_batteries()
+}
+
 #[command]
 pub fn get_cpu_info() -> Result<CpuInfo, String> {
     let mut sys = SYSTEM.lock().map_err(|e| format!("Lock error: {}", e))?;
@@ -166,92 +296,380 @@ pub fn get_memory_info() -> Result<MemoryInfo, String> {
         0.0
     };
 
-    Ok(MemoryInfo { total, used, usage })
+    Ok(MemoryInfo {
+        total,
+        used,
+        usage,
+        swap_used: sys.used_swap(),
+        swap_total: sys.total_swap(),
+        pressure: memory_pressure(),
+    })
 }
 
-#[command]
-pub fn get_network_info() -> Result<NetworkInfo, String> {
-    let networks = Networks::new_with_refreshed_list();
-
-    // Find the primary network interface (usually en0 for WiFi on macOS)
-    for (interface_name, _network) in &networks {
-        if interface_name.starts_with("en") {
-            // Try to get WiFi info using system_profiler
-            let wifi_info = get_wifi_info();
-
-            return Ok(NetworkInfo {
-                interface: interface_name.clone(),
-                network_type: if interface_name == "en0" {
-                    "wifi".to_string()
-                } else {
-                    "ethernet".to_string()
-                },
-                ssid: wifi_info.as_ref().map(|(ssid, _)| ssid.clone()),
-                signal_strength: wifi_info.as_ref().and_then(|(_, strength)| *strength),
-                connected: true,
-            });
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn sysctlbyname(
+        name: *const std::ffi::c_char,
+        oldp: *mut std::ffi::c_void,
+        oldlenp: *mut usize,
+        newp: *mut std::ffi::c_void,
+        newlen: usize,
+    ) -> i32;
+}
+
+/// Read `kern.memorystatus_vm_pressure_level` (1 = normal, 2 = warning, 4 = critical)
+pub fn memory_pressure() -> MemoryPressure {
+    #[cfg(target_os = "macos")]
+    {
+        let mut level: i32 = 0;
+        let mut size = std::mem::size_of::<i32>();
+        let result = unsafe {
+            sysctlbyname(
+                c"kern.memorystatus_vm_pressure_level".as_ptr(),
+                &mut level as *mut i32 as *mut std::ffi::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+
+        match (result, level) {
+            (0, 4) => MemoryPressure::Critical,
+            (0, 2) => MemoryPressure::Warning,
+            _ => MemoryPressure::Normal,
         }
     }
 
-    Ok(NetworkInfo {
-        interface: "unknown".to_string(),
-        network_type: "unknown".to_string(),
-        ssid: None,
-        signal_strength: None,
-        connected: false,
-    })
+    #[cfg(not(target_os = "macos"))]
+    {
+        MemoryPressure::Normal
+    }
 }
 
-/// Get WiFi SSID and signal strength
-pub fn get_wifi_info() -> Option<(String, Option<i32>)> {
-    use std::process::Command;
+#[cfg(target_os = "macos")]
+mod vm {
+    use std::sync::OnceLock;
+
+    /// HOST_VM_INFO64
+    const HOST_VM_INFO64: i32 = 4;
+    const KERN_SUCCESS: i32 = 0;
+
+    /// `vm_statistics64` from <mach/vm_statistics.h>
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct VmStatistics64 {
+        pub free_count: u32,
+        pub active_count: u32,
+        pub inactive_count: u32,
+        pub wire_count: u32,
+        pub zero_fill_count: u64,
+        pub reactivations: u64,
+        pub pageins: u64,
+        pub pageouts: u64,
+        pub faults: u64,
+        pub cow_faults: u64,
+        pub lookups: u64,
+        pub hits: u64,
+        pub purges: u64,
+        pub purgeable_count: u32,
+        pub speculative_count: u32,
+        pub decompressions: u64,
+        pub compressions: u64,
+        pub swapins: u64,
+        pub swapouts: u64,
+        pub compressor_page_count: u32,
+        pub throttled_count: u32,
+        pub external_page_count: u32,
+        pub internal_page_count: u32,
+        pub total_uncompressed_pages_in_compressor: u64,
+    }
 
-    // Use networksetup to get current WiFi network
-    let output = Command::new("/usr/sbin/networksetup")
-        .args(["-getairportnetwork", "en0"])
-        .output()
-        .ok()?;
+    /// `xsw_usage` from <sys/sysctl.h> (`vm.swapusage`)
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct SwapUsage {
+        pub total: u64,
+        pub avail: u64,
+        pub used: u64,
+        pub page_size: u32,
+        pub encrypted: u32,
+    }
 
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        // Output format: "Current Wi-Fi Network: NetworkName"
-        if let Some(ssid) = stdout.strip_prefix("Current Wi-Fi Network: ") {
-            let ssid = ssid.trim().to_string();
-            if !ssid.is_empty() && ssid != "You are not associated with an AirPort network." {
-                // Get signal strength using airport utility
-                let signal = get_wifi_signal_strength();
-                return Some((ssid, signal));
-            }
+    extern "C" {
+        fn mach_host_self() -> u32;
+        fn host_page_size(host: u32, page_size: *mut usize) -> i32;
+        fn host_statistics64(host: u32, flavor: i32, info: *mut i32, count: *mut u32) -> i32;
+    }
+
+    /// Host port (each mach_host_self call adds a port reference, so keep one)
+    fn host() -> u32 {
+        static HOST: OnceLock<u32> = OnceLock::new();
+        *HOST.get_or_init(|| unsafe { mach_host_self() })
+    }
+
+    pub fn page_size() -> u64 {
+        let mut size = 0usize;
+        match unsafe { host_page_size(host(), &mut size) } {
+            KERN_SUCCESS => size as u64,
+            _ => 4096,
         }
     }
 
-    None
+    pub fn statistics() -> Result<VmStatistics64, String> {
+        let mut stats = VmStatistics64::default();
+        let mut count = (std::mem::size_of::<VmStatistics64>() / std::mem::size_of::<i32>()) as u32;
+        let result = unsafe {
+            host_statistics64(
+                host(),
+                HOST_VM_INFO64,
+                &mut stats as *mut VmStatistics64 as *mut i32,
+                &mut count,
+            )
+        };
+        if result != KERN_SUCCESS {
+            return Err(format!(
+                "Failed to read VM statistics: kern_return {}",
+                result
+            ));
+        }
+        Ok(stats)
+    }
+
+    pub fn swap_usage() -> SwapUsage {
+        let mut usage = SwapUsage::default();
+        let mut size = std::mem::size_of::<SwapUsage>();
+        unsafe {
+            super::sysctlbyname(
+                c"vm.swapusage".as_ptr(),
+                &mut usage as *mut SwapUsage as *mut std::ffi::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            );
+        }
+        usage
+    }
 }
 
-fn get_wifi_signal_strength() -> Option<i32> {
-    use std::process::Command;
+/// Memory breakdown from the Mach VM statistics and `vm.swapusage`
+#[cfg(target_os = "macos")]
+fn read_memory_detail() -> Result<MemoryDetail, String> {
+    let stats = vm::statistics()?;
+    let swap = vm::swap_usage();
+    let page = vm::page_size();
+    let bytes = |pages: u32| pages as u64 * page;
+
+    let total = {
+        let mut sys = SYSTEM.lock().map_err(|e| format!("Lock error: {}", e))?;
+        sys.refresh_memory();
+        sys.total_memory()
+    };
 
-    let output = Command::new("/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport")
-        .args(["-I"])
-        .output()
-        .ok()?;
+    let app = bytes(
+        stats
+            .internal_page_count
+            .saturating_sub(stats.purgeable_count),
+    );
+    let wired = bytes(stats.wire_count);
+    let compressed = bytes(stats.compressor_page_count);
+
+    Ok(MemoryDetail {
+        total,
+        app,
+        wired,
+        active: bytes(stats.active_count),
+        inactive: bytes(stats.inactive_count),
+        compressed,
+        cached: bytes(stats.external_page_count) + bytes(stats.purgeable_count),
+        free: bytes(stats.free_count) + bytes(stats.speculative_count),
+        used: app + wired + compressed,
+        swap_used: swap.used,
+        swap_total: swap.total,
+        pressure: memory_pressure(),
+    })
+}
 
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        for line in stdout.lines() {
-            if line.contains("agrCtlRSSI:") {
-                let rssi: i32 = line.split(':').nth(1)?.trim().parse().ok()?;
-                // Convert RSSI to percentage (rough approximation)
-                // RSSI typically ranges from -30 (excellent) to -90 (very weak)
-                let percentage = ((rssi + 90) * 100 / 60).clamp(0, 100);
-                return Some(percentage);
-            }
+#[cfg(not(target_os = "macos"))]
+fn read_memory_detail() -> Result<MemoryDetail, String> {
+    let mut sys = SYSTEM.lock().map_err(|e| format!("Lock error: {}", e))?;
+    sys.refresh_memory();
+
+    Ok(MemoryDetail {
+        total: sys.total_memory(),
+        app: sys.used_memory(),
+        wired: 0,
+        active: 0,
+        inactive: 0,
+        compressed: 0,
+        cached: 0,
+        free: sys.free_memory(),
+        used: sys.used_memory(),
+        swap_used: sys.used_swap(),
+        swap_total: sys.total_swap(),
+        pressure: memory_pressure(),
+    })
+}
+
+/// Wired/active/inactive/compressed/cached breakdown plus swap and pressure
+#[command]
+pub fn get_memory_detail() -> Result<MemoryDetail, String> {
+    read_memory_detail()
+}
+
+/// Read uptime, load average and process count
+pub fn read_system_stats() -> Result<SystemStats, String> {
+    let mut sys = SYSTEM.lock().map_err(|e| format!("Lock error: {}", e))?;
+    // Only enumerate processes; counting them needs no per-process details
+    sys.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::new());
+
+    let load = System::load_average();
+
+    Ok(SystemStats {
+        uptime_secs: System::uptime(),
+        load_avg: [load.one, load.five, load.fifteen],
+        process_count: sys.processes().len(),
+        boot_time: System::boot_time(),
+    })
+}
+
+#[command]
+pub fn get_system_stats() -> Result<SystemStats, String> {
+    read_system_stats()
+}
+
+/// Bundle id of the app with the given pid (None for non-app processes)
+/// Drains its own autorelease pool since it runs on blocking-pool threads
+#[cfg(target_os = "macos")]
+fn bundle_id_for_pid(pid: u32) -> Option<String> {
+    use objc2::rc::autoreleasepool;
+    use objc2::runtime::AnyObject;
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+
+    autoreleasepool(|_| unsafe {
+        let app: *const AnyObject = msg_send![
+            class!(NSRunningApplication),
+            runningApplicationWithProcessIdentifier: pid as i32
+        ];
+        if app.is_null() {
+            return None;
         }
-    }
+        let bundle_id: *const NSString = msg_send![app, bundleIdentifier];
+        (!bundle_id.is_null()).then(|| (*bundle_id).to_string())
+    })
+}
 
+#[cfg(not(target_os = "macos"))]
+fn bundle_id_for_pid(_pid: u32) -> Option<String> {
     None
 }
 
+/// Heaviest processes by CPU or memory
+fn read_top_processes(
+    sort_by: &str,
+    limit: usize,
+    with_icons: bool,
+) -> Result<Vec<ProcessInfo>, String> {
+    let mut processes: Vec<ProcessInfo> = {
+        let mut sys = SYSTEM.lock().map_err(|e| format!("Lock error: {}", e))?;
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::new().with_cpu().with_memory(),
+        );
+
+        sys.processes()
+            .iter()
+            .map(|(pid, process)| ProcessInfo {
+                pid: pid.as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                icon: None,
+            })
+            .collect()
+    };
+
+    match sort_by {
+        "cpu" => processes.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage)),
+        "memory" => processes.sort_by(|a, b| b.memory.cmp(&a.memory)),
+        _ => {
+            return Err(format!(
+                "Invalid sort_by '{}' (expected \"cpu\" or \"memory\")",
+                sort_by
+            ))
+        }
+    }
+    processes.truncate(limit);
+
+    if with_icons {
+        // Only apps have icons; looking up daemons by name would hit mdfind
+        let apps: Vec<(String, Option<String>)> = processes
+            .iter()
+            .filter_map(|process| {
+                let bundle_id = bundle_id_for_pid(process.pid)?;
+                Some((process.name.clone(), Some(bundle_id)))
+            })
+            .collect();
+        let icons = app_icons_by_name(apps);
+        for process in &mut processes {
+            process.icon = icons.get(&process.name).cloned().flatten();
+        }
+    }
+
+    Ok(processes)
+}
+
+/// Top `limit` processes sorted by "cpu" or "memory"
+///
+/// CPU usage is measured since the previous call, so the first call reports 0.
+#[command]
+pub async fn get_top_processes(
+    sort_by: String,
+    limit: usize,
+    with_icons: Option<bool>,
+) -> Result<Vec<ProcessInfo>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        read_top_processes(&sort_by, limit, with_icons.unwrap_or(false))
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Network state of the interface carrying the default route
+#[command]
+pub fn get_network_info() -> Result<NetworkInfo, String> {
+    let Some(primary) = list_interfaces().into_iter().find(|i| i.primary) else {
+        return Ok(NetworkInfo {
+            interface: "unknown".to_string(),
+            network_type: "unknown".to_string(),
+            ssid: None,
+            signal_strength: None,
+            band: None,
+            channel: None,
+            tx_rate_mbps: None,
+            connected: false,
+        });
+    };
+
+    let wifi = if primary.interface_type == "wifi" {
+        get_wifi_info(&primary.name).unwrap_or_default()
+    } else {
+        WifiInfo::default()
+    };
+
+    Ok(NetworkInfo {
+        interface: primary.name,
+        network_type: primary.interface_type,
+        ssid: wifi.ssid,
+        signal_strength: wifi.signal_strength,
+        band: wifi.band,
+        channel: wifi.channel,
+        tx_rate_mbps: wifi.tx_rate_mbps,
+        connected: primary.connected,
+    })
+}
+
 // ============================================
 // Volume commands (Native Core Audio API)
 // ============================================
@@ -380,23 +798,113 @@ pub fn get_active_app_info() -> Result<ActiveAppInfo, String> {
     }
 }
 
+/// List running applications
+///
+/// Only regular (Dock) apps are listed unless `include_background` is set,
+/// which adds agent and accessory apps such as menu bar utilities.
+#[command]
+pub fn get_running_apps(include_background: Option<bool>) -> Result<Vec<RunningAppInfo>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use objc2_app_kit::{NSApplicationActivationPolicy, NSWorkspace};
+
+        let include_background = include_background.unwrap_or(false);
+        let workspace = NSWorkspace::sharedWorkspace();
+
+        let apps = workspace
+            .runningApplications()
+            .iter()
+            .filter(|app| !app.isTerminated())
+            .filter(|app| {
+                include_background
+                    || app.activationPolicy() == NSApplicationActivationPolicy::Regular
+            })
+            .map(|app| RunningAppInfo {
+                name: app
+                    .localizedName()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+                bundle_id: app.bundleIdentifier().map(|s| s.to_string()),
+                pid: app.processIdentifier(),
+                is_active: app.isActive(),
+                is_hidden: app.isHidden(),
+            })
+            .collect();
+
+        Ok(apps)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = include_background;
+        Ok(Vec::new())
+    }
+}
+
+/// Bring a running application to the front (unhiding it if needed)
+#[command]
+pub fn activate_app(bundle_id: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        use objc2_app_kit::{NSApplicationActivationOptions, NSRunningApplication};
+        use objc2_foundation::NSString;
+
+        let apps = NSRunningApplication::runningApplicationsWithBundleIdentifier(
+            &NSString::from_str(&bundle_id),
+        );
+        let app = apps
+            .iter()
+            .find(|app| !app.isTerminated())
+            .ok_or_else(|| format!("App '{}' is not running", bundle_id))?;
+
+        if app.isHidden() {
+            app.unhide();
+        }
+        if !app.activateWithOptions(NSApplicationActivationOptions::ActivateAllWindows) {
+            return Err(format!("Failed to activate '{}'", bundle_id));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err(format!("Activating '{}' is only supported on macOS", bundle_id))
+    }
+}
+
 // ============================================
 // Disk commands
 // ============================================
 
+/// Volumes hidden by default (system volumes and snapshots)
+fn is_system_volume(mount: &str) -> bool {
+    mount.starts_with("/System") || mount.contains("TimeMachine") || mount.contains(".Snapshot")
+}
+
+/// List mounted volumes
+///
+/// `include_all` disables the default system-volume filter; `exclude` hides
+/// any mount point starting with one of the given prefixes.
 #[command]
-pub fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
+pub fn get_disk_info(
+    include_all: Option<bool>,
+    exclude: Option<Vec<String>>,
+) -> Result<Vec<DiskInfo>, String> {
+    let include_all = include_all.unwrap_or(false);
+    let exclude = exclude.unwrap_or_default();
+
     let mut disks = DISKS.lock().map_err(|e| format!("Lock error: {}", e))?;
     disks.refresh_list();
 
+    // IOKit counters aren't per volume, so the totals go on the root mount
+    let io_rates = disk_io_rates();
+
     let result: Vec<DiskInfo> = disks
         .iter()
         .filter(|disk| {
-            // Filter out system volumes and snapshots
             let mount = disk.mount_point().to_string_lossy();
-            !mount.starts_with("/System")
-                && !mount.contains("TimeMachine")
-                && !mount.contains(".Snapshot")
+            (include_all || !is_system_volume(&mount))
+                && !exclude.iter().any(|prefix| mount.starts_with(prefix.as_str()))
         })
         .map(|disk| {
             let total = disk.total_space();
@@ -408,12 +916,19 @@ pub fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
                 0.0
             };
 
+            let mount_point = disk.mount_point().to_string_lossy().to_string();
+            let rates = io_rates.filter(|_| mount_point == "/");
+
             DiskInfo {
                 total,
                 used,
                 available,
                 usage,
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                mount_point,
+                removable: disk.is_removable(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                read_bytes_per_sec: rates.map(|(read, _)| read),
+                write_bytes_per_sec: rates.map(|(_, write)| write),
             }
         })
         .collect();
@@ -425,44 +940,64 @@ pub fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
 // Media commands
 // ============================================
 
-#[command]
-pub fn get_media_info() -> Result<MediaInfo, String> {
-    use std::process::Command;
+/// Media apps to query, in order, based on `media.preferredApp`
+///
+/// "auto" (the default) tries Spotify, then Music. A specific app limits
+/// queries and playback commands to that app only.
+pub fn media_app_order() -> Vec<&'static str> {
+    let preferred = get_config()
+        .ok()
+        .and_then(|config| config.media)
+        .unwrap_or_default()
+        .preferred_app;
+
+    match preferred.to_lowercase().as_str() {
+        "spotify" => vec!["Spotify"],
+        "music" => vec!["Music"],
+        _ => vec!["Spotify", "Music"],
+    }
+}
 
-    // Try to get Now Playing info using osascript
-    // This works with Music.app, Spotify, and other media apps
-    let script = r#"
-        set mediaInfo to ""
-
-        -- Try Spotify first
-        if application "Spotify" is running then
-            tell application "Spotify"
-                if player state is playing then
-                    set mediaInfo to "true|" & name of current track & "|" & artist of current track & "|" & album of current track & "|" & (duration of current track / 1000) & "|" & (player position) & "|Spotify|"
-                else if player state is paused then
-                    set mediaInfo to "false|" & name of current track & "|" & artist of current track & "|" & album of current track & "|" & (duration of current track / 1000) & "|" & (player position) & "|Spotify|"
-                end if
-            end tell
-        end if
+/// AppleScript that reports the now-playing track of one media app
+fn now_playing_script(app: &str) -> String {
+    // Spotify reports duration in milliseconds, Music in seconds
+    let duration = if app == "Spotify" {
+        "(duration of currentTrack / 1000)"
+    } else {
+        "(duration of currentTrack)"
+    };
 
-        -- Try Music.app if no Spotify info
-        if mediaInfo is "" and application "Music" is running then
-            tell application "Music"
-                if player state is playing then
+    format!(
+        r#"
+        if mediaInfo is "" and application "{0}" is running then
+            tell application "{0}"
+                if player state is playing or player state is paused then
                     set currentTrack to current track
-                    set mediaInfo to "true|" & name of currentTrack & "|" & artist of currentTrack & "|" & album of currentTrack & "|" & (duration of currentTrack) & "|" & player position & "|Music|"
-                else if player state is paused then
-                    set currentTrack to current track
-                    set mediaInfo to "false|" & name of currentTrack & "|" & artist of currentTrack & "|" & album of currentTrack & "|" & (duration of currentTrack) & "|" & player position & "|Music|"
+                    set mediaInfo to ((player state is playing) as string) & "|" & name of currentTrack & "|" & artist of currentTrack & "|" & album of currentTrack & "|" & {1} & "|" & (player position) & "|{0}|"
                 end if
             end tell
         end if
+        "#,
+        app, duration
+    )
+}
+
+#[command]
+pub fn get_media_info() -> Result<MediaInfo, String> {
+    use std::process::Command;
 
-        return mediaInfo
-    "#;
+    // Query each media app in preference order; the first with a track wins
+    let script = format!(
+        "set mediaInfo to \"\"\n{}\nreturn mediaInfo",
+        media_app_order()
+            .into_iter()
+            .map(now_playing_script)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
 
     let output = Command::new("osascript")
-        .args(["-e", script])
+        .args(["-e", &script])
         .output()
         .map_err(|e| format!("Failed to get media info: {}", e))?;
 
@@ -471,15 +1006,21 @@ pub fn get_media_info() -> Result<MediaInfo, String> {
         let parts: Vec<&str> = stdout.trim().split('|').collect();
 
         if parts.len() >= 7 && !parts[0].is_empty() {
+            let title = Some(parts[1].to_string()).filter(|s| !s.is_empty());
+            let artist = Some(parts[2].to_string()).filter(|s| !s.is_empty());
+            let artwork_url = cached_artwork(title.as_deref(), artist.as_deref(), || {
+                fetch_applescript_artwork(parts[6])
+            });
+
             return Ok(MediaInfo {
                 playing: parts[0] == "true",
-                title: Some(parts[1].to_string()).filter(|s| !s.is_empty()),
-                artist: Some(parts[2].to_string()).filter(|s| !s.is_empty()),
+                title,
+                artist,
                 album: Some(parts[3].to_string()).filter(|s| !s.is_empty()),
                 duration: parts[4].parse().ok(),
                 position: parts[5].parse().ok(),
                 app: Some(parts[6].to_string()).filter(|s| !s.is_empty()),
-                artwork_url: None,
+                artwork_url,
             });
         }
     }
@@ -499,105 +1040,113 @@ pub fn get_media_info() -> Result<MediaInfo, String> {
 
 #[command]
 pub fn media_play() -> Result<(), String> {
-    use std::process::Command;
-
-    // Try Spotify first, then Music
-    let script = r#"
-        if application "Spotify" is running then
-            tell application "Spotify" to play
-        else if application "Music" is running then
-            tell application "Music" to play
-        end if
-    "#;
-
-    Command::new("osascript")
-        .args(["-e", script])
-        .output()
-        .map_err(|e| format!("Failed to play: {}", e))?;
-
-    Ok(())
+    run_media_app_command("play", "Failed to play").map(|_| ())
 }
 
 #[command]
 pub fn media_pause() -> Result<(), String> {
-    use std::process::Command;
-
-    let script = r#"
-        if application "Spotify" is running then
-            tell application "Spotify" to pause
-        else if application "Music" is running then
-            tell application "Music" to pause
-        end if
-    "#;
-
-    Command::new("osascript")
-        .args(["-e", script])
-        .output()
-        .map_err(|e| format!("Failed to pause: {}", e))?;
-
-    Ok(())
+    run_media_app_command("pause", "Failed to pause").map(|_| ())
 }
 
 #[command]
 pub fn media_next() -> Result<(), String> {
-    use std::process::Command;
+    run_media_app_command("next track", "Failed to skip").map(|_| ())
+}
 
-    let script = r#"
-        if application "Spotify" is running then
-            tell application "Spotify" to next track
-        else if application "Music" is running then
-            tell application "Music" to next track
-        end if
-    "#;
+#[command]
+pub fn media_previous() -> Result<(), String> {
+    run_media_app_command("previous track", "Failed to go back").map(|_| ())
+}
 
-    Command::new("osascript")
-        .args(["-e", script])
-        .output()
-        .map_err(|e| format!("Failed to skip: {}", e))?;
+/// Seek the active media app to a position (seconds)
+///
+/// Returns the app that was targeted, or None if no media app is running.
+#[command]
+pub fn media_seek(position_secs: f64) -> Result<Option<String>, String> {
+    if !position_secs.is_finite() || position_secs < 0.0 {
+        return Err(format!("Invalid position: {}", position_secs));
+    }
 
-    Ok(())
+    run_media_app_command(
+        &format!("set player position to {}", position_secs),
+        "Failed to seek",
+    )
 }
 
+/// Set the active media app's own volume (0-100), independent of system volume
+///
+/// Returns the app that was targeted, or None if no media app is running.
 #[command]
-pub fn media_previous() -> Result<(), String> {
+pub fn media_set_volume(percent: f64) -> Result<Option<String>, String> {
+    let percent = percent.clamp(0.0, 100.0).round() as u8;
+
+    run_media_app_command(
+        &format!("set sound volume to {}", percent),
+        "Failed to set media volume",
+    )
+}
+
+/// Run an AppleScript statement against the first running media app
+/// (in `media.preferredApp` order) and return which app was targeted
+fn run_media_app_command(statement: &str, error_context: &str) -> Result<Option<String>, String> {
     use std::process::Command;
 
-    let script = r#"
-        if application "Spotify" is running then
-            tell application "Spotify" to previous track
-        else if application "Music" is running then
-            tell application "Music" to previous track
-        end if
-    "#;
+    let branches: String = media_app_order()
+        .into_iter()
+        .map(|app| {
+            format!(
+                r#"
+        if application "{0}" is running then
+            tell application "{0}" to {1}
+            return "{0}"
+        end if"#,
+                app, statement
+            )
+        })
+        .collect();
+    let script = format!("{}\n        return \"\"", branches);
 
-    Command::new("osascript")
-        .args(["-e", script])
+    let output = Command::new("osascript")
+        .args(["-e", &script])
         .output()
-        .map_err(|e| format!("Failed to go back: {}", e))?;
+        .map_err(|e| format!("{}: {}", error_context, e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{}: {}",
+            error_context,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
 
-    Ok(())
+    let app = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(Some(app).filter(|a| !a.is_empty()))
 }
 
 // ============================================
 // Brightness commands (Native IOKit API)
 // ============================================
 
+/// Get brightness of a display (main display if `display_id` is omitted)
+/// Fails for displays whose brightness can't be read natively
 #[command]
-pub fn get_brightness_info() -> Result<BrightnessInfo, String> {
+pub fn get_brightness_info(display_id: Option<u32>) -> Result<BrightnessInfo, String> {
     #[cfg(target_os = "macos")]
     {
         use super::brightness;
 
-        let brightness_value = brightness::get_brightness().unwrap_or(0.5);
+        let brightness_value = brightness::get_brightness(display_id)?;
+        let display_id = display_id.unwrap_or_else(brightness::main_display_id);
 
         Ok(BrightnessInfo {
             brightness: brightness_value * 100.0,
-            display_name: None, // TODO: Get display name
+            display_name: brightness::display_name(display_id),
         })
     }
 
     #[cfg(not(target_os = "macos"))]
     {
+        let _ = display_id;
         Ok(BrightnessInfo {
             brightness: 100.0,
             display_name: None,
@@ -605,22 +1154,57 @@ pub fn get_brightness_info() -> Result<BrightnessInfo, String> {
     }
 }
 
+/// Set brightness of a display (main display if `display_id` is omitted)
 #[command]
-pub fn set_brightness(level: f32) -> Result<(), String> {
+pub fn set_brightness(level: f32, display_id: Option<u32>) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
         use super::brightness;
         let level = level.clamp(0.0, 100.0) / 100.0;
-        brightness::set_brightness(level)
+        brightness::set_brightness(level, display_id)
     }
 
     #[cfg(not(target_os = "macos"))]
     {
-        let _ = level;
+        let _ = (level, display_id);
         Ok(())
     }
 }
 
+/// List active displays and whether their brightness is controllable
+#[command]
+pub fn list_displays() -> Result<Vec<DisplayInfo>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use super::brightness;
+
+        let main_id = brightness::main_display_id();
+        let displays = brightness::active_display_ids()?
+            .into_iter()
+            .map(|id| {
+                let supports_brightness = brightness::supports_brightness(id);
+                DisplayInfo {
+                    id,
+                    name: brightness::display_name(id),
+                    is_main: id == main_id,
+                    supports_brightness,
+                    brightness: supports_brightness
+                        .then(|| brightness::get_brightness(Some(id)).ok())
+                        .flatten()
+                        .map(|b| b * 100.0),
+                }
+            })
+            .collect();
+
+        Ok(displays)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Ok(vec![])
+    }
+}
+
 // ============================================
 // Bluetooth commands
 // ============================================
@@ -750,3 +1334,61 @@ pub fn toggle_bluetooth() -> Result<(), String> {
         Ok(())
     }
 }
+
+// ============================================
+// Notification commands
+// ============================================
+
+/// Count notifications currently held by Notification Center
+///
+/// Reads the usernoted SQLite database with the system `sqlite3` tool.
+/// Returns `None` when the database is inaccessible (Full Disk Access is
+/// required on recent macOS versions) or its schema is unrecognized.
+pub fn get_notification_count_sync() -> Option<u32> {
+    use std::process::Command;
+
+    notification_db_paths()
+        .into_iter()
+        .filter(|path| path.exists())
+        .find_map(|path| {
+            let output = Command::new("sqlite3")
+                .arg("-readonly")
+                .arg(&path)
+                .arg("SELECT COUNT(*) FROM record;")
+                .output()
+                .ok()?;
+
+            if !output.status.success() {
+                return None;
+            }
+
+            String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+        })
+}
+
+/// Candidate Notification Center database locations (newest first)
+fn notification_db_paths() -> Vec<std::path::PathBuf> {
+    use std::process::Command;
+
+    let mut paths = Vec::new();
+
+    // macOS 15+
+    if let Some(home) = dirs::home_dir() {
+        paths.push(home.join("Library/Group Containers/group.com.apple.usernoted/db2/db"));
+    }
+
+    // macOS 10.13 - 14: per-user temp dir
+    if let Ok(output) = Command::new("getconf").arg("DARWIN_USER_DIR").output() {
+        let user_dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !user_dir.is_empty() {
+            paths.push(std::path::PathBuf::from(user_dir).join("com.apple.notificationcenter/db2/db"));
+        }
+    }
+
+    paths
+}
+
+#[command]
+pub fn get_notification_count() -> Result<Option<u32>, String> {
+    Ok(get_notification_count_sync())
+}
@@ -0,0 +1,173 @@
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter};
+
+use super::config::{get_config, ThemeConfig};
+use super::events;
+
+/// Accent color used when neither config nor system provide one
+const DEFAULT_ACCENT_COLOR: &str = "#007AFF";
+
+/// In-memory theme used for previews; takes precedence over the saved config
+static THEME_OVERRIDE: Lazy<Mutex<Option<ThemeConfig>>> = Lazy::new(|| Mutex::new(None));
+
+/// Theme resolved against the system appearance
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveTheme {
+    /// "light" or "dark" (never "system")
+    pub mode: String,
+    pub accent_color: String,
+}
+
+/// Resolve a theme config into the concrete theme the frontend should use
+pub fn resolve_theme(theme: &ThemeConfig) -> EffectiveTheme {
+    let mode = match theme.mode.as_str() {
+        "light" | "dark" => theme.mode.clone(),
+        _ => {
+            if system_is_dark() {
+                "dark".to_string()
+            } else {
+                "light".to_string()
+            }
+        }
+    };
+
+    let accent_color = theme
+        .accent_color
+        .clone()
+        .or_else(system_accent_color)
+        .unwrap_or_else(|| DEFAULT_ACCENT_COLOR.to_string());
+
+    EffectiveTheme { mode, accent_color }
+}
+
+/// Resolve the theme from the preview override, or the current config
+pub fn current_effective_theme() -> Result<EffectiveTheme, String> {
+    if let Some(theme) = THEME_OVERRIDE.lock().map_err(|e| e.to_string())?.clone() {
+        return Ok(resolve_theme(&theme));
+    }
+
+    let config = get_config()?;
+    Ok(resolve_theme(&config.theme))
+}
+
+/// Drop the preview override (returns whether one was set)
+pub fn reset_theme_override() -> bool {
+    THEME_OVERRIDE
+        .lock()
+        .map(|mut theme| theme.take().is_some())
+        .unwrap_or(false)
+}
+
+/// Emit `theme-resolved` with the current effective theme
+pub fn emit_theme_resolved(app: &AppHandle) {
+    match current_effective_theme() {
+        Ok(theme) => {
+            if let Err(e) = app.emit(events::THEME_RESOLVED, theme) {
+                eprintln!("[theme] Failed to emit theme-resolved event: {}", e);
+            }
+        }
+        Err(e) => eprintln!("[theme] Failed to resolve theme: {}", e),
+    }
+}
+
+/// Whether the system appearance is dark
+#[cfg(target_os = "macos")]
+fn system_is_dark() -> bool {
+    use objc2::{msg_send, runtime::AnyObject};
+    use objc2_app_kit::NSApplication;
+    use objc2_foundation::{MainThreadMarker, NSString};
+    use std::process::Command;
+
+    // NSApp.effectiveAppearance is only safe to read on the main thread
+    if let Some(mtm) = MainThreadMarker::new() {
+        let app = NSApplication::sharedApplication(mtm);
+        unsafe {
+            let appearance: *const AnyObject = msg_send![&*app, effectiveAppearance];
+            if !appearance.is_null() {
+                let name: *const NSString = msg_send![appearance, name];
+                if !name.is_null() {
+                    return (*name).to_string().contains("Dark");
+                }
+            }
+        }
+    }
+
+    // Off the main thread: read the global interface style default
+    Command::new("defaults")
+        .args(["read", "-g", "AppleInterfaceStyle"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "Dark")
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn system_is_dark() -> bool {
+    false
+}
+
+/// System accent color as a hex string
+#[cfg(target_os = "macos")]
+fn system_accent_color() -> Option<String> {
+    use objc2::{class, msg_send, runtime::AnyObject};
+
+    unsafe {
+        let accent: *const AnyObject = msg_send![class!(NSColor), controlAccentColor];
+        if accent.is_null() {
+            return None;
+        }
+
+        // Convert from the dynamic catalog color to concrete sRGB components
+        let srgb_space: *const AnyObject = msg_send![class!(NSColorSpace), sRGBColorSpace];
+        let rgb: *const AnyObject = msg_send![accent, colorUsingColorSpace: srgb_space];
+        if rgb.is_null() {
+            return None;
+        }
+
+        let red: f64 = msg_send![rgb, redComponent];
+        let green: f64 = msg_send![rgb, greenComponent];
+        let blue: f64 = msg_send![rgb, blueComponent];
+
+        let to_byte = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Some(format!(
+            "#{:02X}{:02X}{:02X}",
+            to_byte(red),
+            to_byte(green),
+            to_byte(blue)
+        ))
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn system_accent_color() -> Option<String> {
+    None
+}
+
+// ============================================
+// Theme Commands
+// ============================================
+
+/// Get the effective theme ("system" resolved to light/dark)
+#[command]
+pub fn get_effective_theme() -> Result<EffectiveTheme, String> {
+    current_effective_theme()
+}
+
+/// Preview a theme without writing it to fluopanel.json
+#[command]
+pub fn set_theme_override(app: AppHandle, theme: ThemeConfig) -> Result<EffectiveTheme, String> {
+    *THEME_OVERRIDE.lock().map_err(|e| e.to_string())? = Some(theme);
+    emit_theme_resolved(&app);
+    current_effective_theme()
+}
+
+/// Discard the preview theme and go back to the saved config
+#[command]
+pub fn clear_theme_override(app: AppHandle) -> Result<EffectiveTheme, String> {
+    if reset_theme_override() {
+        emit_theme_resolved(&app);
+    }
+    current_effective_theme()
+}
@@ -0,0 +1,52 @@
+//! Thermal state
+//!
+//! Reads `NSProcessInfo.thermalState`, which rises above `nominal` when
+//! macOS starts throttling to shed heat.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ThermalState {
+    #[default]
+    Nominal,
+    Fair,
+    Serious,
+    Critical,
+}
+
+/// Read the current thermal state
+#[cfg(target_os = "macos")]
+pub fn read_thermal_state() -> ThermalState {
+    use objc2::runtime::AnyObject;
+    use objc2::{class, msg_send};
+
+    // NSProcessInfoThermalState (NSInteger)
+    let state: isize = unsafe {
+        let process_info: *const AnyObject = msg_send![class!(NSProcessInfo), processInfo];
+        msg_send![process_info, thermalState]
+    };
+
+    match state {
+        1 => ThermalState::Fair,
+        2 => ThermalState::Serious,
+        3 => ThermalState::Critical,
+        _ => ThermalState::Nominal,
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_thermal_state() -> ThermalState {
+    ThermalState::default()
+}
+
+// ============================================
+// Thermal Commands
+// ============================================
+
+/// Get the current thermal pressure level
+#[command]
+pub fn get_thermal_state() -> Result<ThermalState, String> {
+    Ok(read_thermal_state())
+}
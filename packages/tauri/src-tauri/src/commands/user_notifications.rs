@@ -0,0 +1,177 @@
+//! Native notification banners
+//!
+//! Posts through `UNUserNotificationCenter`. Authorization is requested on
+//! every post; macOS only prompts the first time and afterwards answers with
+//! the stored decision. The API requires a bundled app (it aborts the process
+//! otherwise), so dev runs without a bundle identifier get an error instead.
+
+use tauri::command;
+
+#[cfg(target_os = "macos")]
+mod center {
+    use block2::RcBlock;
+    use objc2::runtime::{AnyClass, AnyObject, Bool};
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+    use std::sync::mpsc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[link(name = "UserNotifications", kind = "framework")]
+    extern "C" {}
+
+    /// UNAuthorizationOptionSound | UNAuthorizationOptionAlert
+    const AUTHORIZATION_OPTIONS: usize = (1 << 1) | (1 << 2);
+
+    /// How long to wait for the center's completion handlers
+    /// (the first call waits on the user answering the permission prompt)
+    const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(60);
+    const POST_TIMEOUT: Duration = Duration::from_secs(5);
+
+    unsafe fn error_description(error: *mut AnyObject) -> String {
+        let description: *const NSString = msg_send![error, localizedDescription];
+        if description.is_null() {
+            "Unknown error".to_string()
+        } else {
+            (*description).to_string()
+        }
+    }
+
+    fn has_bundle_identifier() -> bool {
+        unsafe {
+            let bundle: *const AnyObject = msg_send![class!(NSBundle), mainBundle];
+            let identifier: *const AnyObject = msg_send![bundle, bundleIdentifier];
+            !identifier.is_null()
+        }
+    }
+
+    fn current_center() -> Result<*const AnyObject, String> {
+        if !has_bundle_identifier() {
+            return Err("Notifications require running from the app bundle".to_string());
+        }
+
+        let class = AnyClass::get(c"UNUserNotificationCenter")
+            .ok_or("UserNotifications framework is unavailable")?;
+        let center: *const AnyObject = unsafe { msg_send![class, currentNotificationCenter] };
+        if center.is_null() {
+            return Err("Failed to get notification center".to_string());
+        }
+        Ok(center)
+    }
+
+    fn request_authorization(center: *const AnyObject) -> Result<(), String> {
+        let (tx, rx) = mpsc::channel();
+        let block = RcBlock::new(move |granted: Bool, error: *mut AnyObject| {
+            let result = if !error.is_null() {
+                Err(format!("Failed to request notification permission: {}", unsafe {
+                    error_description(error)
+                }))
+            } else if granted.as_bool() {
+                Ok(())
+            } else {
+                Err("Notification permission denied (enable it in System Settings > Notifications)"
+                    .to_string())
+            };
+            let _ = tx.send(result);
+        });
+
+        unsafe {
+            let _: () = msg_send![
+                center,
+                requestAuthorizationWithOptions: AUTHORIZATION_OPTIONS,
+                completionHandler: &*block
+            ];
+        }
+
+        rx.recv_timeout(AUTHORIZATION_TIMEOUT)
+            .map_err(|_| "Timed out waiting for notification permission".to_string())?
+    }
+
+    pub fn post(title: &str, body: &str, subtitle: Option<&str>, sound: bool) -> Result<(), String> {
+        let center = current_center()?;
+        request_authorization(center)?;
+
+        let content_class = AnyClass::get(c"UNMutableNotificationContent")
+            .ok_or("UserNotifications framework is unavailable")?;
+        let request_class = AnyClass::get(c"UNNotificationRequest")
+            .ok_or("UserNotifications framework is unavailable")?;
+
+        let identifier = format!(
+            "fluopanel-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default()
+        );
+
+        let (tx, rx) = mpsc::channel();
+        let block = RcBlock::new(move |error: *mut AnyObject| {
+            let result = if error.is_null() {
+                Ok(())
+            } else {
+                Err(format!("Failed to post notification: {}", unsafe {
+                    error_description(error)
+                }))
+            };
+            let _ = tx.send(result);
+        });
+
+        unsafe {
+            let content: *mut AnyObject = msg_send![content_class, new];
+            let _: () = msg_send![content, setTitle: &*NSString::from_str(title)];
+            let _: () = msg_send![content, setBody: &*NSString::from_str(body)];
+            if let Some(subtitle) = subtitle {
+                let _: () = msg_send![content, setSubtitle: &*NSString::from_str(subtitle)];
+            }
+            if let Some(sound_class) = AnyClass::get(c"UNNotificationSound").filter(|_| sound) {
+                let default_sound: *const AnyObject = msg_send![sound_class, defaultSound];
+                let _: () = msg_send![content, setSound: default_sound];
+            }
+
+            // nil trigger = deliver immediately
+            let request: *const AnyObject = msg_send![
+                request_class,
+                requestWithIdentifier: &*NSString::from_str(&identifier),
+                content: content,
+                trigger: std::ptr::null::<AnyObject>()
+            ];
+            let _: () = msg_send![content, release];
+
+            let _: () = msg_send![
+                center,
+                addNotificationRequest: request,
+                withCompletionHandler: &*block
+            ];
+        }
+
+        rx.recv_timeout(POST_TIMEOUT)
+            .map_err(|_| "Timed out posting notification".to_string())?
+    }
+}
+
+// ============================================
+// Notification Commands
+// ============================================
+
+/// Show a notification banner (asks for permission the first time)
+#[command]
+pub async fn post_notification(
+    title: String,
+    body: String,
+    subtitle: Option<String>,
+    sound: bool,
+) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        tauri::async_runtime::spawn_blocking(move || {
+            center::post(&title, &body, subtitle.as_deref(), sound)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (title, body, subtitle, sound);
+        Err("Notifications are only supported on macOS".to_string())
+    }
+}
@@ -1,10 +1,22 @@
-use serde::Serialize;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::AppHandle;
 
-use super::helpers::{constrain_to_screen, get_target_window};
+use super::constants::geometry::UNBOUNDED_WINDOW_SIZE;
+use super::constants::resize;
+#[cfg(target_os = "macos")]
+use super::geometry::cursor_position;
+use super::geometry::{constrain_to_screen, flip_y, logical_bounds};
+use super::helpers::get_target_window;
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MonitorInfo {
+    /// Stable display identifier (CGDirectDisplayID on macOS, name elsewhere)
+    pub id: String,
     pub name: String,
     pub width: u32,
     pub height: u32,
@@ -13,23 +25,95 @@ pub struct MonitorInfo {
     pub scale_factor: f64,
 }
 
-#[tauri::command]
-pub fn get_monitors(window: tauri::WebviewWindow) -> Result<Vec<MonitorInfo>, String> {
-    let monitors = window.available_monitors().map_err(|e| e.to_string())?;
+/// A point (or the top/bottom of a rect of `height`) in logical pixels
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScreenPoint {
+    pub x: f64,
+    pub y: f64,
+    /// Height of the rect whose origin this is (0 for a bare point)
+    #[serde(default)]
+    pub height: f64,
+}
+
+/// Cursor location and the monitor under it
+#[derive(Debug, Clone, Serialize)]
+pub struct CursorPosition {
+    /// Logical pixels, top-left origin (the space popover anchors use)
+    pub x: f64,
+    pub y: f64,
+    /// Monitor containing the cursor (None while monitors are unavailable)
+    pub monitor: Option<MonitorInfo>,
+}
+
+/// Limits on user resizing, in logical pixels (unset = unconstrained)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeConstraints {
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
+}
+
+impl SizeConstraints {
+    /// Minimum size (a missing dimension is 0)
+    pub fn min_size(&self) -> Option<tauri::LogicalSize<f64>> {
+        if self.min_width.is_none() && self.min_height.is_none() {
+            return None;
+        }
+        Some(tauri::LogicalSize {
+            width: self.min_width.unwrap_or(0.0),
+            height: self.min_height.unwrap_or(0.0),
+        })
+    }
+
+    /// Maximum size (a missing dimension is effectively unbounded)
+    pub fn max_size(&self) -> Option<tauri::LogicalSize<f64>> {
+        if self.max_width.is_none() && self.max_height.is_none() {
+            return None;
+        }
+        Some(tauri::LogicalSize {
+            width: self.max_width.unwrap_or(UNBOUNDED_WINDOW_SIZE),
+            height: self.max_height.unwrap_or(UNBOUNDED_WINDOW_SIZE),
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let pairs = [
+            ("width", self.min_width, self.max_width),
+            ("height", self.min_height, self.max_height),
+        ];
+        for (dimension, min, max) in pairs {
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    return Err(format!("Minimum {} {} exceeds maximum {}", dimension, min, max));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// List all monitors in logical pixels
+pub fn list_monitors(app: &AppHandle) -> Result<Vec<MonitorInfo>, String> {
+    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
 
     let monitor_infos: Vec<MonitorInfo> = monitors
         .into_iter()
         .map(|m| {
-            let size = m.size();
-            let position = m.position();
             let scale = m.scale_factor();
-            // Return logical pixels (divide physical by scale factor)
+            let name = m.name().cloned().unwrap_or_else(|| "Unknown".to_string());
+
+            let (x, y, width, height) = logical_bounds(&m);
+            let (x, y, width, height) = (x as i32, y as i32, width as u32, height as u32);
+
             MonitorInfo {
-                name: m.name().cloned().unwrap_or_else(|| "Unknown".to_string()),
-                width: (size.width as f64 / scale) as u32,
-                height: (size.height as f64 / scale) as u32,
-                x: (position.x as f64 / scale) as i32,
-                y: (position.y as f64 / scale) as i32,
+                id: monitor_id(&name, x, y, width, height),
+                name,
+                width,
+                height,
+                x,
+                y,
                 scale_factor: scale,
             }
         })
@@ -38,6 +122,99 @@ pub fn get_monitors(window: tauri::WebviewWindow) -> Result<Vec<MonitorInfo>, St
     Ok(monitor_infos)
 }
 
+/// Resolve the CGDirectDisplayID of the display at the monitor's center
+#[cfg(target_os = "macos")]
+fn monitor_id(name: &str, x: i32, y: i32, width: u32, height: u32) -> String {
+    #[repr(C)]
+    struct CGPoint {
+        x: f64,
+        y: f64,
+    }
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGGetDisplaysWithPoint(
+            point: CGPoint,
+            max_displays: u32,
+            displays: *mut u32,
+            matching_display_count: *mut u32,
+        ) -> i32;
+    }
+
+    // CoreGraphics global coordinates share the top-left origin of logical pixels
+    let center = CGPoint {
+        x: x as f64 + width as f64 / 2.0,
+        y: y as f64 + height as f64 / 2.0,
+    };
+    let mut display_id: u32 = 0;
+    let mut count: u32 = 0;
+
+    let result = unsafe { CGGetDisplaysWithPoint(center, 1, &mut display_id, &mut count) };
+
+    if result == 0 && count > 0 {
+        display_id.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn monitor_id(name: &str, _x: i32, _y: i32, _width: u32, _height: u32) -> String {
+    name.to_string()
+}
+
+#[tauri::command]
+pub fn get_monitors(app: AppHandle) -> Result<Vec<MonitorInfo>, String> {
+    list_monitors(&app)
+}
+
+/// Read the cursor location in logical pixels
+#[cfg(target_os = "macos")]
+fn read_cursor_position(_app: &AppHandle) -> Result<(f64, f64), String> {
+    cursor_position().ok_or_else(|| "Failed to read cursor position".to_string())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_cursor_position(app: &AppHandle) -> Result<(f64, f64), String> {
+    let position = app
+        .cursor_position()
+        .map_err(|e| format!("Failed to read cursor position: {}", e))?;
+
+    // Physical pixels: scale by the monitor they fall on
+    let scale = app
+        .available_monitors()
+        .ok()
+        .and_then(|monitors| {
+            monitors.into_iter().find(|m| {
+                let (pos, size) = (m.position(), m.size());
+                position.x >= pos.x as f64
+                    && position.x < pos.x as f64 + size.width as f64
+                    && position.y >= pos.y as f64
+                    && position.y < pos.y as f64 + size.height as f64
+            })
+        })
+        .map(|m| m.scale_factor())
+        .unwrap_or(1.0);
+
+    Ok((position.x / scale, position.y / scale))
+}
+
+/// Cursor location and the monitor under it, e.g. to open a menu at the pointer
+#[tauri::command]
+pub fn get_cursor_position(app: AppHandle) -> Result<CursorPosition, String> {
+    let (x, y) = read_cursor_position(&app)?;
+    let monitor = list_monitors(&app).ok().and_then(|monitors| {
+        monitors.into_iter().find(|m| {
+            x >= m.x as f64
+                && x < m.x as f64 + m.width as f64
+                && y >= m.y as f64
+                && y < m.y as f64 + m.height as f64
+        })
+    });
+
+    Ok(CursorPosition { x, y, monitor })
+}
+
 #[tauri::command]
 pub fn set_window_geometry(
     app: tauri::AppHandle,
@@ -99,7 +276,15 @@ pub fn set_window_size(
     height: u32,
 ) -> Result<(), String> {
     let target_window = get_target_window(&app, window, label.as_deref())?;
+    apply_window_size(&target_window, width, height)
+}
 
+/// Resize a window, clamping regular windows to their screen
+fn apply_window_size(
+    target_window: &tauri::WebviewWindow,
+    width: u32,
+    height: u32,
+) -> Result<(), String> {
     // Popover windows are already clamped by popover.rs (accurate maxHeight based on anchor position)
     // and useAutoSize (clamps content to maxHeight). Skip additional constraints here.
     let is_popover = target_window.label().starts_with("popover-");
@@ -109,9 +294,7 @@ pub fn set_window_size(
         (width as f64, height as f64)
     } else if let Ok(Some(monitor)) = target_window.current_monitor() {
         // Regular windows: apply screen bounds constraint
-        let scale = monitor.scale_factor();
-        let monitor_width = monitor.size().width as f64 / scale;
-        let monitor_height = monitor.size().height as f64 / scale;
+        let (_, _, monitor_width, monitor_height) = logical_bounds(&monitor);
         constrain_to_screen(width as f64, height as f64, monitor_width, monitor_height)
     } else {
         (width as f64, height as f64)
@@ -126,3 +309,93 @@ pub fn set_window_size(
 
     Ok(())
 }
+
+/// Latest debounced resize request per window label
+static RESIZE_GENERATIONS: Lazy<Mutex<HashMap<String, u64>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Like `set_window_size`, but coalesces rapid calls per window: only the
+/// last size requested within `debounce_ms` is applied
+#[tauri::command]
+pub async fn set_window_size_debounced(
+    app: tauri::AppHandle,
+    window: tauri::WebviewWindow,
+    label: Option<String>,
+    width: u32,
+    height: u32,
+    debounce_ms: Option<u64>,
+) -> Result<(), String> {
+    let target_window = get_target_window(&app, window, label.as_deref())?;
+    let target_label = target_window.label().to_string();
+
+    let generation = {
+        let mut generations = RESIZE_GENERATIONS.lock().map_err(|e| e.to_string())?;
+        let generation = generations.entry(target_label.clone()).or_insert(0);
+        *generation += 1;
+        *generation
+    };
+
+    let debounce = debounce_ms
+        .unwrap_or(resize::DEFAULT_DEBOUNCE_MS)
+        .min(resize::MAX_DEBOUNCE_MS);
+    tokio::time::sleep(Duration::from_millis(debounce)).await;
+
+    {
+        let mut generations = RESIZE_GENERATIONS.lock().map_err(|e| e.to_string())?;
+        if generations.get(&target_label) != Some(&generation) {
+            // Superseded by a later call
+            return Ok(());
+        }
+        generations.remove(&target_label);
+    }
+
+    apply_window_size(&target_window, width, height)
+}
+
+/// Limit how small/large the user can resize a window (NSWindow minSize/maxSize)
+#[tauri::command]
+pub fn set_window_size_constraints(
+    app: tauri::AppHandle,
+    window: tauri::WebviewWindow,
+    label: Option<String>,
+    constraints: SizeConstraints,
+) -> Result<(), String> {
+    constraints.validate()?;
+    let target_window = get_target_window(&app, window, label.as_deref())?;
+
+    target_window
+        .set_min_size(constraints.min_size().map(tauri::Size::Logical))
+        .map_err(|e: tauri::Error| e.to_string())?;
+
+    target_window
+        .set_max_size(constraints.max_size().map(tauri::Size::Logical))
+        .map_err(|e: tauri::Error| e.to_string())?;
+
+    Ok(())
+}
+
+fn flip_point(app: &AppHandle, point: ScreenPoint) -> Result<ScreenPoint, String> {
+    let primary = app
+        .primary_monitor()
+        .map_err(|e| e.to_string())?
+        .ok_or("No primary monitor")?;
+    let (_, _, _, primary_height) = logical_bounds(&primary);
+
+    Ok(ScreenPoint {
+        y: flip_y(point.y, point.height, primary_height),
+        ..point
+    })
+}
+
+/// Convert a global top-left (web/Tauri) point to macOS bottom-left coordinates
+/// Works on any monitor: both spaces are anchored to the primary screen
+#[tauri::command]
+pub fn screen_to_macos(app: AppHandle, point: ScreenPoint) -> Result<ScreenPoint, String> {
+    flip_point(&app, point)
+}
+
+/// Convert a macOS bottom-left point to global top-left (web/Tauri) coordinates
+#[tauri::command]
+pub fn macos_to_screen(app: AppHandle, point: ScreenPoint) -> Result<ScreenPoint, String> {
+    flip_point(&app, point)
+}
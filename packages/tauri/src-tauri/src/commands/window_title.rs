@@ -0,0 +1,128 @@
+//! Focused Window Title
+//!
+//! Reads the title of the frontmost app's focused window through the
+//! Accessibility API. This needs the Accessibility permission (System
+//! Settings > Privacy & Security > Accessibility, see `request_permission`);
+//! without it the title is reported as None rather than an error.
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveWindowTitle {
+    pub title: Option<String>,
+    pub bundle_id: Option<String>,
+    pub pid: Option<i32>,
+}
+
+#[cfg(target_os = "macos")]
+mod ax {
+    use core_foundation_sys::base::{CFGetTypeID, CFRelease, CFTypeRef};
+    use core_foundation_sys::string::{CFStringGetTypeID, CFStringRef};
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
+    /// `kAXErrorSuccess`
+    const AX_SUCCESS: i32 = 0;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXUIElementCreateApplication(pid: i32) -> CFTypeRef;
+        fn AXUIElementCopyAttributeValue(
+            element: CFTypeRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> i32;
+    }
+
+    /// Copy an attribute value (caller releases)
+    unsafe fn copy_attribute(element: CFTypeRef, attribute: &str) -> Option<CFTypeRef> {
+        let attribute = NSString::from_str(attribute);
+        let mut value: CFTypeRef = std::ptr::null();
+        let result = AXUIElementCopyAttributeValue(
+            element,
+            &*attribute as *const NSString as CFStringRef,
+            &mut value,
+        );
+        (result == AX_SUCCESS && !value.is_null()).then_some(value)
+    }
+
+    /// Title of an app's focused window (`kAXFocusedWindowAttribute`, `kAXTitleAttribute`)
+    pub fn focused_window_title(pid: i32) -> Option<String> {
+        unsafe {
+            let app = AXUIElementCreateApplication(pid);
+            if app.is_null() {
+                return None;
+            }
+
+            let window = copy_attribute(app, "AXFocusedWindow");
+            CFRelease(app);
+            let window = window?;
+
+            let title = copy_attribute(window, "AXTitle");
+            CFRelease(window);
+            let title = title?;
+
+            // CFString is toll-free bridged to NSString
+            let string = (CFGetTypeID(title) == CFStringGetTypeID())
+                .then(|| (*(title as *const NSString)).to_string());
+            CFRelease(title as *const c_void);
+
+            string.filter(|title| !title.is_empty())
+        }
+    }
+}
+
+/// Frontmost app and its focused window title
+/// Drains its own autorelease pool since it runs on blocking-pool threads
+#[cfg(target_os = "macos")]
+pub fn read_active_window_title() -> ActiveWindowTitle {
+    use super::permissions::accessibility_trusted;
+    use objc2::rc::autoreleasepool;
+    use objc2_app_kit::NSWorkspace;
+
+    autoreleasepool(|_| {
+        let Some(app) = NSWorkspace::sharedWorkspace().frontmostApplication() else {
+            return ActiveWindowTitle {
+                title: None,
+                bundle_id: None,
+                pid: None,
+            };
+        };
+
+        let pid = app.processIdentifier();
+        let title = accessibility_trusted()
+            .then(|| ax::focused_window_title(pid))
+            .flatten();
+
+        ActiveWindowTitle {
+            title,
+            bundle_id: app.bundleIdentifier().map(|s| s.to_string()),
+            pid: Some(pid),
+        }
+    })
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_active_window_title() -> ActiveWindowTitle {
+    ActiveWindowTitle {
+        title: None,
+        bundle_id: None,
+        pid: None,
+    }
+}
+
+// ============================================
+// Window Title Commands
+// ============================================
+
+/// Title of the frontmost app's focused window
+/// None without the Accessibility permission or when the app has no window
+/// Runs off the main thread since AX calls block while the app is unresponsive
+#[command]
+pub async fn get_active_window_title() -> Result<Option<String>, String> {
+    tauri::async_runtime::spawn_blocking(|| read_active_window_title().title)
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+}
@@ -1,14 +1,51 @@
-use std::io::{BufRead, BufReader, Write};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener};
+use std::os::unix::fs::OpenOptionsExt;
 use std::os::unix::net::{UnixListener, UnixStream};
 use std::path::Path;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tauri::{AppHandle, Emitter, Manager};
 
-use crate::commands::{aerospace_get_workspaces_sync, get_workspace_by_id};
+use crate::commands::config::{get_config, get_config_dir};
+use crate::commands::events;
+use crate::commands::{
+    aerospace_get_workspaces_sync, emit_focus_changed, get_focused_workspace_id_sync,
+};
 
 const SOCKET_PATH: &str = "/tmp/fluopanel.sock";
 
+/// File in the config dir holding the TCP listener's per-session token
+const TCP_TOKEN_FILE: &str = "ipc-token";
+
+/// Request line prefixes that mark a connection as HTTP rather than IPC
+const HTTP_PREFIXES: [&str; 8] = [
+    "GET ", "POST ", "PUT ", "HEAD ", "DELETE ", "OPTIONS ", "PATCH ", "HOST:",
+];
+
 /// Start the IPC server (called from main app)
+///
+/// Always listens on the Unix socket. If `ipc.tcpPort` is set in config,
+/// also listens on `127.0.0.1:{port}` with the same line-based protocol.
+/// TCP clients must first send `auth:{token}`, where the token is read
+/// from `~/.config/fluopanel/ipc-token` (owner-only, new every launch).
 pub fn start_server(app: AppHandle) {
+    let tcp_port = get_config()
+        .ok()
+        .and_then(|config| config.ipc)
+        .and_then(|ipc| ipc.tcp_port);
+
+    if let Some(port) = tcp_port {
+        start_tcp_server(app.clone(), port);
+    }
+
+    start_unix_server(app);
+}
+
+/// Listen on the Unix socket
+fn start_unix_server(app: AppHandle) {
     // Remove existing socket file if it exists
     let _ = std::fs::remove_file(SOCKET_PATH);
 
@@ -28,7 +65,7 @@ pub fn start_server(app: AppHandle) {
                 Ok(stream) => {
                     let app = app.clone();
                     std::thread::spawn(move || {
-                        handle_client(stream, &app);
+                        handle_client(stream, &app, None);
                     });
                 }
                 Err(e) => {
@@ -39,15 +76,154 @@ pub fn start_server(app: AppHandle) {
     });
 }
 
+/// Listen on a TCP port
+///
+/// Binds to loopback only. Browsers can still reach loopback ports (a page
+/// can POST to 127.0.0.1), so every connection must authenticate with the
+/// session token before any command runs.
+fn start_tcp_server(app: AppHandle, port: u16) {
+    let token = match write_tcp_token() {
+        Ok(token) => Arc::new(token),
+        Err(e) => {
+            eprintln!("[IPC] Failed to write TCP token, TCP disabled: {}", e);
+            return;
+        }
+    };
+
+    std::thread::spawn(move || {
+        let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+            Ok(l) => l,
+            Err(e) => {
+                eprintln!("[IPC] Failed to bind TCP port {}: {}", port, e);
+                return;
+            }
+        };
+
+        println!("[IPC] Server listening on 127.0.0.1:{}", port);
+
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    let app = app.clone();
+                    let token = token.clone();
+                    std::thread::spawn(move || {
+                        handle_client(stream, &app, Some(&token));
+                    });
+                }
+                Err(e) => {
+                    eprintln!("[IPC] Connection error: {}", e);
+                }
+            }
+        }
+    });
+}
+
+/// Generate a random token and store it where only this user can read it
+fn write_tcp_token() -> std::io::Result<String> {
+    let mut bytes = [0u8; 32];
+    std::fs::File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    let token: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    let dir = get_config_dir();
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(TCP_TOKEN_FILE);
+
+    // Recreate rather than overwrite, so a planted file or symlink isn't reused
+    let _ = std::fs::remove_file(&path);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)?;
+    file.write_all(token.as_bytes())?;
+
+    Ok(token)
+}
+
+/// Whether a line is part of an HTTP request (cross-protocol request from a browser)
+fn looks_like_http(line: &str) -> bool {
+    let upper = line.trim_start().to_ascii_uppercase();
+    HTTP_PREFIXES.iter().any(|prefix| upper.starts_with(prefix)) || upper.contains(" HTTP/")
+}
+
+/// Compare without exiting early on the first differing byte
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// One-line JSON response written back for every command
+#[derive(Debug, Serialize, Deserialize)]
+struct IpcResponse {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl IpcResponse {
+    fn from_result(result: Result<Option<Value>, String>) -> Self {
+        match result {
+            Ok(data) => Self {
+                ok: true,
+                data,
+                error: None,
+            },
+            Err(error) => Self {
+                ok: false,
+                data: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
 /// Handle incoming client connection
-fn handle_client(stream: UnixStream, app: &AppHandle) {
+///
+/// With a `token`, the first line must be `auth:{token}`; anything else
+/// closes the connection. HTTP request lines always close it.
+fn handle_client<S>(stream: S, app: &AppHandle, token: Option<&str>)
+where
+    for<'a> &'a S: Read + Write,
+{
     let reader = BufReader::new(&stream);
+    let mut writer = &stream;
+    let mut authenticated = token.is_none();
 
     for line in reader.lines() {
         match line {
+            Ok(command) if looks_like_http(&command) => {
+                eprintln!("[IPC] Rejected HTTP request, closing connection");
+                break;
+            }
+            Ok(command) if !authenticated => {
+                let given = command.strip_prefix("auth:").unwrap_or_default();
+                if !token.is_some_and(|token| token_matches(given.trim(), token)) {
+                    eprintln!("[IPC] Rejected unauthenticated TCP client");
+                    let _ = writeln!(writer, r#"{{"ok":false,"error":"Unauthorized"}}"#);
+                    break;
+                }
+                authenticated = true;
+                if let Err(e) = writeln!(writer, r#"{{"ok":true}}"#) {
+                    eprintln!("[IPC] Write error: {}", e);
+                    break;
+                }
+            }
             Ok(command) => {
                 println!("[IPC] Received command: {}", command);
-                execute_command(&command, app);
+                let response = IpcResponse::from_result(execute_command(&command, app));
+                let json = serde_json::to_string(&response).unwrap_or_else(|_| {
+                    r#"{"ok":false,"error":"Failed to serialize response"}"#.to_string()
+                });
+                if let Err(e) = writeln!(writer, "{}", json) {
+                    eprintln!("[IPC] Write error: {}", e);
+                    break;
+                }
             }
             Err(e) => {
                 eprintln!("[IPC] Read error: {}", e);
@@ -58,44 +234,85 @@ fn handle_client(stream: UnixStream, app: &AppHandle) {
 }
 
 /// Execute a command received via IPC
-fn execute_command(command: &str, app: &AppHandle) {
+///
+/// Returns optional data to include in the response.
+fn execute_command(command: &str, app: &AppHandle) -> Result<Option<Value>, String> {
     // Handle focus-changed:focused:prev format
     if let Some(rest) = command.strip_prefix("focus-changed:") {
         let parts: Vec<&str> = rest.split(':').collect();
         let focused_id = parts.first().map(|s| s.trim()).filter(|s| !s.is_empty());
         let prev_id = parts.get(1).map(|s| s.trim()).filter(|s| !s.is_empty());
 
-        if let Some(focused) = focused_id {
-            let focused_ws = get_workspace_by_id(focused, true);
-            let prev_ws = prev_id.and_then(|id| get_workspace_by_id(id, false));
+        let focused = focused_id.ok_or("Missing focused workspace ID")?;
+        emit_focus_changed(app, focused, prev_id);
+        return Ok(None);
+    }
 
-            if let Some(window) = app.get_webview_window("main") {
-                let _ = window.emit(
-                    "aerospace-focus-changed",
-                    serde_json::json!({
-                        "focused": focused_ws,
-                        "prev": prev_ws
-                    }),
-                );
-            }
-        }
-        return;
+    // Handle emit:{event}:{json} - forward a custom event to all windows
+    if let Some(rest) = command.strip_prefix("emit:") {
+        let (event, payload) = rest
+            .split_once(':')
+            .ok_or("Expected emit:{event}:{json}")?;
+        return emit_custom_event(app, event.trim(), payload).map(|_| None);
+    }
+
+    // Handle query:{name} - return data without emitting
+    if let Some(name) = command.strip_prefix("query:") {
+        return execute_query(name.trim()).map(Some);
     }
 
     // Legacy: full workspace refresh
     match command {
         "workspace-changed" => {
-            if let Ok(workspaces) = aerospace_get_workspaces_sync() {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.emit("aerospace-workspace-changed", &workspaces);
-                }
+            let workspaces = aerospace_get_workspaces_sync()?;
+            if let Some(window) = app.get_webview_window("main") {
+                window
+                    .emit(events::AEROSPACE_WORKSPACE_CHANGED, &workspaces)
+                    .map_err(|e| format!("Failed to emit event: {}", e))?;
             }
+            Ok(None)
+        }
+        _ => Err(format!("Unknown command: {}", command)),
+    }
+}
+
+/// Validate and broadcast a custom event from an external script
+fn emit_custom_event(app: &AppHandle, event: &str, payload: &str) -> Result<(), String> {
+    if event.is_empty()
+        || !event
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '/')
+    {
+        return Err(format!("Invalid event name: {}", event));
+    }
+
+    if events::is_reserved(event) {
+        return Err(format!("Event name is reserved: {}", event));
+    }
+
+    let payload: Value =
+        serde_json::from_str(payload).map_err(|e| format!("Invalid JSON payload: {}", e))?;
+
+    app.emit(event, payload)
+        .map_err(|e| format!("Failed to emit event: {}", e))
+}
+
+/// Execute a query:{name} command
+fn execute_query(name: &str) -> Result<Value, String> {
+    match name {
+        "workspaces" => {
+            let workspaces = aerospace_get_workspaces_sync()?;
+            serde_json::to_value(workspaces).map_err(|e| format!("Failed to serialize: {}", e))
         }
-        _ => {}
+        "focused-workspace" => get_focused_workspace_id_sync().map(Value::String),
+        _ => Err(format!("Unknown query: {}", name)),
     }
 }
 
 /// Send a command to the running instance (CLI mode)
+///
+/// Prints the response data (if any) to stdout and the error to stderr.
+/// Returns false if the command could not be delivered or failed.
 pub fn send_command(event: &str) -> bool {
     let socket_path = Path::new(SOCKET_PATH);
 
@@ -104,17 +321,43 @@ pub fn send_command(event: &str) -> bool {
         return false;
     }
 
-    match UnixStream::connect(socket_path) {
-        Ok(mut stream) => {
-            if let Err(e) = writeln!(stream, "{}", event) {
-                eprintln!("Failed to send command: {}", e);
-                return false;
-            }
-            true
-        }
+    let mut stream = match UnixStream::connect(socket_path) {
+        Ok(stream) => stream,
         Err(e) => {
             eprintln!("Failed to connect to fluopanel: {}", e);
-            false
+            return false;
+        }
+    };
+
+    if let Err(e) = writeln!(stream, "{}", event) {
+        eprintln!("Failed to send command: {}", e);
+        return false;
+    }
+
+    let mut line = String::new();
+    if let Err(e) = BufReader::new(&stream).read_line(&mut line) {
+        eprintln!("Failed to read response: {}", e);
+        return false;
+    }
+
+    let response: IpcResponse = match serde_json::from_str(line.trim()) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("Invalid response from fluopanel: {}", e);
+            return false;
+        }
+    };
+
+    if let Some(data) = &response.data {
+        match serde_json::to_string_pretty(data) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Failed to format response data: {}", e),
         }
     }
+
+    if let Some(error) = &response.error {
+        eprintln!("Error: {}", error);
+    }
+
+    response.ok
 }
@@ -1,27 +1,46 @@
 mod cli;
 mod commands;
 mod ipc;
+mod protocol;
 mod watchers;
 mod windows;
 
 use clap::{Parser, Subcommand};
 use commands::{
-    aerospace_focus_workspace, aerospace_get_focused_workspace, aerospace_get_workspaces,
-    clear_icon_cache, close_all_popovers, close_popover, execute_shell, get_active_app_info,
-    get_app_icon, get_app_icons, get_battery_info, get_bluetooth_info, get_brightness_info,
-    get_config, get_cpu_info, get_disk_info, get_media_info, get_memory_info, get_monitors,
-    get_network_info, get_open_popovers, get_volume_info, media_next, media_pause, media_play,
-    media_previous, open_popover, save_config, set_brightness, set_mute, set_volume,
-    set_window_geometry, set_window_position, set_window_size, store_delete, store_get,
-    store_keys, store_set, toggle_bluetooth, toggle_mute,
+    activate_app, aerospace_focus_window, aerospace_focus_workspace, aerospace_get_all_app_icons,
+    aerospace_get_focused_workspace, aerospace_get_layout, aerospace_get_workspaces,
+    aerospace_move_window_to_workspace, aerospace_set_layout, cancel_tick, check_permissions,
+    clear_icon_cache, clear_theme_override, clipboard_read_image, clipboard_read_text,
+    clipboard_write_text, close_all_popovers, close_popover, execute_shell, execute_shell_stream,
+    get_accessibility_prefs, get_active_app_info, get_active_window_title, get_all_batteries,
+    get_all_interfaces, get_app_icon, get_app_icon_by_bundle_id, get_app_icons, get_battery_info,
+    get_bluetooth_info, get_brightness_info, get_calendars, get_config, get_cpu_info,
+    get_cursor_position, get_disk_info, get_effective_theme, get_fan_info, get_file_icon,
+    get_focus_mode, get_gpu_info, get_keyboard_brightness, get_launch_at_login, get_media_info,
+    get_memory_detail, get_memory_info, get_monitors, get_network_info, get_notification_count,
+    get_open_popovers, get_power_metrics, get_running_apps, get_system_stats, get_thermal_state,
+    get_top_processes, get_upcoming_events, get_volume_info, get_widget_resource_usage,
+    is_sleep_prevented, kill_shell, list_displays, list_events, list_login_items, macos_to_screen,
+    media_next, media_pause, media_play, media_previous, media_seek, media_set_volume,
+    open_popover, post_notification, prevent_sleep, request_permission, restore_overlays,
+    run_shell, save_config, schedule_at, schedule_tick, screen_to_macos, set_brightness,
+    set_keyboard_brightness, set_launch_at_login, set_login_item, set_mute, set_theme_override,
+    set_volume, set_window_geometry, set_window_position, set_window_size,
+    set_window_size_constraints, set_window_size_debounced, snapshot_overlays, store_delete,
+    store_get, store_keys, store_set, toggle_bluetooth, toggle_mute,
 };
 use windows::{
-    close_window, create_inline_window, hide_window, show_window, update_window_position,
+    ack_widget_reload, apply_layout_preset, close_window, create_inline_window, fade_window,
+    get_focused_window, hide_window, list_layout_presets, reapply_all_window_geometry,
+    reload_all_widgets, reload_widget, save_layout_preset, set_window_collection_behavior,
+    set_window_level, set_window_magnetic, show_window, update_window_position,
 };
+use watchers::history::get_history;
+use watchers::sources::{activate_sources, deactivate_sources, request_fast_updates};
 use once_cell::sync::OnceCell;
 use std::path::PathBuf;
 use tauri::http::Response;
-use tauri::{Emitter, Manager};
+use tauri::Manager;
 
 #[derive(Parser)]
 #[command(name = "fluopanel")]
@@ -35,9 +54,16 @@ pub struct Cli {
 pub enum Commands {
     /// Emit an event to the running instance
     Emit {
-        /// Event name (e.g., workspace-changed)
+        /// Event name (e.g., workspace-changed, query:workspaces)
         event: String,
     },
+    /// Emit a custom event with a JSON payload to all widgets
+    EmitData {
+        /// Event name (e.g., build-finished)
+        event: String,
+        /// JSON payload (e.g., '{"status":"ok"}')
+        payload: String,
+    },
     /// Notify workspace focus change (optimized, only fetches 2 workspaces)
     FocusChanged {
         /// Focused workspace ID
@@ -58,6 +84,16 @@ pub fn run() {
     if let Some(command) = cli.command {
         let success = match command {
             Commands::Emit { event } => ipc::send_command(&event),
+            Commands::EmitData { event, payload } => {
+                // Re-serialize so multi-line JSON fits the line-based protocol
+                match serde_json::from_str::<serde_json::Value>(&payload) {
+                    Ok(value) => ipc::send_command(&format!("emit:{}:{}", event, value)),
+                    Err(e) => {
+                        eprintln!("Invalid JSON payload: {}", e);
+                        false
+                    }
+                }
+            }
             Commands::FocusChanged { focused, prev } => {
                 let cmd = match prev {
                     Some(p) => format!("focus-changed:{}:{}", focused, p),
@@ -83,21 +119,46 @@ pub fn run() {
     builder
         .invoke_handler(tauri::generate_handler![
             aerospace_get_workspaces,
+            aerospace_get_all_app_icons,
             aerospace_get_focused_workspace,
             aerospace_focus_workspace,
+            aerospace_focus_window,
+            aerospace_move_window_to_workspace,
+            aerospace_get_layout,
+            aerospace_set_layout,
             get_battery_info,
+            get_all_batteries,
             get_cpu_info,
             get_memory_info,
+            get_memory_detail,
+            get_system_stats,
+            get_top_processes,
+            get_gpu_info,
+            get_fan_info,
             get_network_info,
+            get_all_interfaces,
             get_config,
             save_config,
+            get_effective_theme,
+            list_events,
+            set_theme_override,
+            clear_theme_override,
             get_app_icon,
+            get_app_icon_by_bundle_id,
             get_app_icons,
+            get_file_icon,
             clear_icon_cache,
             get_monitors,
+            get_cursor_position,
+            screen_to_macos,
+            macos_to_screen,
             set_window_geometry,
             set_window_position,
             set_window_size,
+            set_window_size_debounced,
+            set_window_size_constraints,
+            // Accessibility commands
+            get_accessibility_prefs,
             // Volume commands
             get_volume_info,
             set_volume,
@@ -105,6 +166,9 @@ pub fn run() {
             toggle_mute,
             // Active app commands
             get_active_app_info,
+            get_running_apps,
+            activate_app,
+            get_active_window_title,
             // Disk commands
             get_disk_info,
             // Media commands
@@ -113,15 +177,56 @@ pub fn run() {
             media_pause,
             media_next,
             media_previous,
+            media_seek,
+            media_set_volume,
             // Brightness commands
             get_brightness_info,
             set_brightness,
+            list_displays,
+            get_keyboard_brightness,
+            set_keyboard_brightness,
+            // Sleep prevention commands
+            prevent_sleep,
+            is_sleep_prevented,
+            // Power metrics commands
+            get_power_metrics,
+            // Resource usage commands
+            get_widget_resource_usage,
+            // Thermal commands
+            get_thermal_state,
+            // Focus mode commands
+            get_focus_mode,
+            // User notification commands
+            post_notification,
+            // Clipboard commands
+            clipboard_read_text,
+            clipboard_write_text,
+            clipboard_read_image,
+            // Notification Center commands
+            get_notification_count,
             // Bluetooth commands
             get_bluetooth_info,
             toggle_bluetooth,
+            // Login item commands
+            list_login_items,
+            set_login_item,
+            get_launch_at_login,
+            set_launch_at_login,
             // Inline window commands
             create_inline_window,
             update_window_position,
+            reapply_all_window_geometry,
+            set_window_magnetic,
+            set_window_collection_behavior,
+            set_window_level,
+            fade_window,
+            reload_widget,
+            reload_all_widgets,
+            ack_widget_reload,
+            save_layout_preset,
+            list_layout_presets,
+            apply_layout_preset,
+            get_focused_window,
             hide_window,
             close_window,
             show_window,
@@ -130,6 +235,8 @@ pub fn run() {
             close_popover,
             close_all_popovers,
             get_open_popovers,
+            snapshot_overlays,
+            restore_overlays,
             // Store commands
             store_set,
             store_get,
@@ -137,7 +244,39 @@ pub fn run() {
             store_keys,
             // Shell commands
             execute_shell,
+            run_shell,
+            execute_shell_stream,
+            kill_shell,
+            // Scheduler commands
+            schedule_tick,
+            schedule_at,
+            cancel_tick,
+            // Watcher activation commands
+            activate_sources,
+            deactivate_sources,
+            request_fast_updates,
+            // Metric history commands
+            get_history,
+            // Calendar commands
+            get_upcoming_events,
+            get_calendars,
+            // Permission commands
+            check_permissions,
+            request_permission,
         ])
+        .on_window_event(|window, event| match event {
+            // Release data sources, shell commands and ticks held by closed windows
+            tauri::WindowEvent::Destroyed => {
+                watchers::sources::release_window(window.label());
+                commands::shell::release_window(window.label());
+                commands::scheduler::release_window(window.label());
+                windows::key_window::handle_focus_event(window.app_handle());
+            }
+            tauri::WindowEvent::Focused(_) => {
+                windows::key_window::handle_focus_event(window.app_handle());
+            }
+            _ => {}
+        })
         .register_uri_scheme_protocol("fluopanel", |ctx, request| {
             // Combine host and path for routing
             // fluopanel://localhost/index.html -> host="localhost", path="/index.html"
@@ -176,21 +315,69 @@ pub fn run() {
                 }
             };
 
+            // Range header, honored so <video>/<audio> can seek
+            let range_header = request
+                .headers()
+                .get("range")
+                .and_then(|value| value.to_str().ok());
+            let if_none_match = request
+                .headers()
+                .get("if-none-match")
+                .and_then(|value| value.to_str().ok());
+
             // Helper: serve file with MIME type
-            let serve_file = |file_path: &PathBuf| -> Response<Vec<u8>> {
-                if file_path.exists() {
-                    match std::fs::read(file_path) {
-                        Ok(content) => {
-                            Response::builder()
-                                .header("Content-Type", get_mime(file_path))
-                                .header("Access-Control-Allow-Origin", "*")
-                                .body(content)
-                                .unwrap()
-                        }
-                        Err(_) => Response::builder().status(404).body(Vec::new()).unwrap(),
-                    }
+            // `hashed_output` marks directories whose hashed names are safe to cache forever
+            let serve_file = |file_path: &PathBuf, hashed_output: bool| -> Response<Vec<u8>> {
+                let metadata = if protocol::file_exists(file_path) {
+                    std::fs::metadata(file_path).ok()
                 } else {
-                    Response::builder().status(404).body(Vec::new()).unwrap()
+                    None
+                };
+                let Some(metadata) = metadata else {
+                    protocol::log_not_found(path);
+                    return Response::builder().status(404).body(Vec::new()).unwrap();
+                };
+
+                // Unchanged since the webview's cached copy
+                let etag = protocol::etag(&metadata);
+                let cache_control = protocol::cache_control(file_path, hashed_output);
+                if if_none_match.is_some_and(|tags| protocol::etag_matches(tags, &etag)) {
+                    return Response::builder()
+                        .status(304)
+                        .header("ETag", etag)
+                        .header("Cache-Control", cache_control)
+                        .header("Access-Control-Allow-Origin", "*")
+                        .body(Vec::new())
+                        .unwrap();
+                }
+
+                let builder = Response::builder()
+                    .header("Content-Type", get_mime(file_path))
+                    .header("Access-Control-Allow-Origin", "*")
+                    .header("Accept-Ranges", "bytes")
+                    .header("ETag", etag)
+                    .header("Cache-Control", cache_control);
+                match protocol::read_file(file_path, range_header) {
+                    Ok(protocol::FileBody::Full(content)) => builder.body(content).unwrap(),
+                    Ok(protocol::FileBody::Partial {
+                        content,
+                        start,
+                        end,
+                        len,
+                    }) => builder
+                        .status(206)
+                        .header("Content-Range", format!("bytes {}-{}/{}", start, end, len))
+                        .body(content)
+                        .unwrap(),
+                    Ok(protocol::FileBody::Unsatisfiable { len }) => builder
+                        .status(416)
+                        .header("Content-Range", format!("bytes */{}", len))
+                        .body(Vec::new())
+                        .unwrap(),
+                    Err(_) => {
+                        protocol::log_not_found(path);
+                        Response::builder().status(404).body(Vec::new()).unwrap()
+                    }
                 }
             };
 
@@ -198,6 +385,32 @@ pub fn run() {
             let ui_not_found_response = || -> Response<Vec<u8>> {
                 let config_dir = commands::config::get_config_dir();
                 let default_dist = config_dir.join("dist");
+
+                // Explain what is actually missing at the expected location
+                let info = commands::config::get_ui_serve_info();
+                let source = if info.from_config {
+                    "ui.distPath in fluopanel.json"
+                } else {
+                    "the default location"
+                };
+                let diagnosis = if info.needs_build() {
+                    format!(
+                        "The folder from {} exists but has no <code>index.html</code>. Run your build (e.g. <code>npm run build</code>).",
+                        source
+                    )
+                } else if !info.dir_exists {
+                    format!("The folder from {} does not exist.", source)
+                } else {
+                    format!("The UI from {} could not be loaded.", source)
+                };
+                let expected_path = info
+                    .expected_path
+                    .display()
+                    .to_string()
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+
                 let html = format!(
                     r#"<!DOCTYPE html>
 <html>
@@ -228,12 +441,23 @@ pub fn run() {
         }}
         ol {{ padding-left: 24px; }}
         li {{ margin: 8px 0; }}
+        .diagnosis {{
+            border-left: 4px solid #ff6b6b;
+            padding: 4px 16px;
+            background: #242424;
+        }}
     </style>
 </head>
 <body>
     <h1>Fluopanel UI Not Found</h1>
     <p>No user interface distribution was found. Fluopanel requires a built UI to display.</p>
 
+    <div class="diagnosis">
+        <p>Expected <code>index.html</code> in:</p>
+        <pre><code>{}</code></pre>
+        <p>{}</p>
+    </div>
+
     <h2>Setup Options</h2>
 
     <h3>Option 1: Default Location</h3>
@@ -259,6 +483,8 @@ pub fn run() {
     </ol>
 </body>
 </html>"#,
+                    expected_path,
+                    diagnosis,
                     default_dist.display(),
                     default_dist.display()
                 );
@@ -269,27 +495,36 @@ pub fn run() {
                     .unwrap()
             };
 
-            // Route: /lib/{file} - Serve shared libraries for widget runtime
-            if path.starts_with("/lib/") {
-                let file = &path[5..];
+            // Helper: refuse paths that would escape their root directory
+            let forbidden = || -> Response<Vec<u8>> {
+                protocol::log_forbidden(path);
+                Response::builder().status(403).body(Vec::new()).unwrap()
+            };
 
+            // Route: /lib/{file} - Serve shared libraries for widget runtime
+            if let Some(file) = path.strip_prefix("/lib/") {
                 // Try resource directory first (bundled with app in production)
                 if let Ok(resource_dir) = ctx.app_handle().path().resource_dir() {
-                    let lib_path: PathBuf = resource_dir.join("libs").join(file);
-                    if lib_path.exists() {
-                        return serve_file(&lib_path);
+                    let Some(lib_path) = protocol::resolve_within(&resource_dir.join("libs"), file)
+                    else {
+                        return forbidden();
+                    };
+                    if protocol::file_exists(&lib_path) {
+                        return serve_file(&lib_path, true);
                     }
                 }
 
                 // Fallback: development mode - look in src-tauri/libs/
                 // This works when running `cargo tauri dev`
-                let dev_lib_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                    .join("libs")
-                    .join(file);
-                if dev_lib_path.exists() {
-                    return serve_file(&dev_lib_path);
+                let dev_libs_dir: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("libs");
+                let Some(dev_lib_path) = protocol::resolve_within(&dev_libs_dir, file) else {
+                    return forbidden();
+                };
+                if protocol::file_exists(&dev_lib_path) {
+                    return serve_file(&dev_lib_path, true);
                 }
 
+                protocol::log_not_found(path);
                 return Response::builder().status(404).body(Vec::new()).unwrap();
             }
 
@@ -300,26 +535,33 @@ pub fn run() {
                 None => return ui_not_found_response(),
             };
 
-            // Determine file to serve
+            // Determine file to serve (never outside the dist folder)
             let file_path = if path == "/index.html" {
                 ui_dist.join("index.html")
             } else {
-                // Remove leading slash and serve from dist
-                let relative = path.trim_start_matches('/');
-                ui_dist.join(relative)
+                match protocol::resolve_within(&ui_dist, path) {
+                    Some(file_path) => file_path,
+                    None => return forbidden(),
+                }
             };
 
+            // Entry point vanished (e.g. mid-rebuild): explain instead of a blank page
+            if path == "/index.html" && !protocol::file_exists(&file_path) {
+                return ui_not_found_response();
+            }
+
             // Try to serve the file
-            if file_path.exists() {
-                return serve_file(&file_path);
+            if protocol::file_exists(&file_path) {
+                return serve_file(&file_path, false);
             }
 
             // SPA fallback: serve index.html for non-existent paths (Vue Router support)
             let index_path = ui_dist.join("index.html");
-            if index_path.exists() {
-                return serve_file(&index_path);
+            if protocol::file_exists(&index_path) {
+                return serve_file(&index_path, false);
             }
 
+            protocol::log_not_found(path);
             Response::builder().status(404).body(Vec::new()).unwrap()
         })
         .setup(|app| {
@@ -332,6 +574,12 @@ pub fn run() {
             // Initialize system watchers (active app, battery, volume, media, network)
             watchers::init_all(app.handle().clone());
 
+            // Apply settings.launchAtLogin (registers/unregisters the login item)
+            commands::login_items::sync_launch_at_login();
+
+            // Record the monitor set so display changes can be diffed
+            windows::monitors::init(app.handle());
+
             // Initialize hover focus (autoraise) feature
             #[cfg(target_os = "macos")]
             windows::hover_focus::init(app.handle().clone());
@@ -367,7 +615,7 @@ pub fn run() {
                         #[unsafe(method(screenDidChange:))]
                         fn screen_did_change(&self, _notification: &NSNotification) {
                             if let Some(handle) = GLOBAL_APP_HANDLE.get() {
-                                let _ = handle.emit("monitor-changed", ());
+                                windows::monitors::handle_screen_change(handle);
                             }
                         }
                     }
@@ -436,6 +684,11 @@ pub fn run() {
 
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                commands::sleep::release_sleep_assertion();
+            }
+        });
 }
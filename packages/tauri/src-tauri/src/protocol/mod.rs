@@ -0,0 +1,348 @@
+//! fluopanel:// Protocol Helpers
+//!
+//! Shared state and file reading for the custom URI scheme handler
+//! registered in lib.rs.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a missing path is remembered before hitting the disk again
+const NOT_FOUND_TTL: Duration = Duration::from_secs(2);
+
+/// Upper bound on remembered missing paths
+const NOT_FOUND_CACHE_MAX: usize = 512;
+
+/// Negative-result cache: path -> time it was found missing
+static NOT_FOUND_CACHE: Lazy<Mutex<HashMap<PathBuf, Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Check whether a file exists, remembering misses for a short time
+///
+/// Widgets that reference missing assets tend to request them repeatedly
+/// (e.g. on every hot reload), so misses skip the filesystem until the TTL expires.
+pub fn file_exists(path: &Path) -> bool {
+    if let Ok(cache) = NOT_FOUND_CACHE.lock() {
+        if let Some(missed_at) = cache.get(path) {
+            if missed_at.elapsed() < NOT_FOUND_TTL {
+                return false;
+            }
+        }
+    }
+
+    let exists = path.exists();
+
+    if let Ok(mut cache) = NOT_FOUND_CACHE.lock() {
+        if exists {
+            cache.remove(path);
+        } else {
+            if cache.len() >= NOT_FOUND_CACHE_MAX {
+                cache.retain(|_, missed_at| missed_at.elapsed() < NOT_FOUND_TTL);
+            }
+            if cache.len() < NOT_FOUND_CACHE_MAX {
+                cache.insert(path.to_path_buf(), Instant::now());
+            }
+        }
+    }
+
+    exists
+}
+
+/// Log a 404 (debug builds only)
+pub fn log_not_found(request_path: &str) {
+    if cfg!(debug_assertions) {
+        eprintln!("[protocol] 404 Not Found: {}", request_path);
+    }
+}
+
+/// File contents to serve for a request, honoring a single-range `Range` header
+pub enum FileBody {
+    /// Whole file (no or unusable Range header)
+    Full(Vec<u8>),
+    /// `206 Partial Content` for bytes `start..=end` of a `len`-byte file
+    Partial {
+        content: Vec<u8>,
+        start: u64,
+        end: u64,
+        len: u64,
+    },
+    /// `416 Range Not Satisfiable`
+    Unsatisfiable { len: u64 },
+}
+
+/// Resolve a `Range` header against a file length
+///
+/// Supports `bytes=a-b`, `bytes=a-` and `bytes=-n`. Multi-range and
+/// malformed headers return None, which serves the whole file (allowed
+/// by RFC 9110). Some(Err(())) means the range lies past the end.
+fn parse_range(header: &str, len: u64) -> Option<Result<(u64, u64), ()>> {
+    let spec = header.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    let range = if start.is_empty() {
+        // Suffix range: last n bytes
+        let suffix: u64 = end.parse().ok()?;
+        if suffix == 0 || len == 0 {
+            return Some(Err(()));
+        }
+        (len.saturating_sub(suffix), len - 1)
+    } else {
+        let start: u64 = start.parse().ok()?;
+        let end = if end.is_empty() {
+            len.saturating_sub(1)
+        } else {
+            let end: u64 = end.parse().ok()?;
+            if end < start {
+                return None;
+            }
+            end.min(len.saturating_sub(1))
+        };
+        if start >= len {
+            return Some(Err(()));
+        }
+        (start, end)
+    };
+
+    Some(Ok(range))
+}
+
+/// Read a file, or only the byte range requested by `range_header`
+///
+/// Without a Range header the file is read whole, as before; with one only
+/// the requested bytes are read, so media elements can seek in large files.
+pub fn read_file(path: &Path, range_header: Option<&str>) -> std::io::Result<FileBody> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let Some(header) = range_header else {
+        return std::fs::read(path).map(FileBody::Full);
+    };
+
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+
+    match parse_range(header, len) {
+        None => {
+            let mut content = Vec::with_capacity(len as usize);
+            file.read_to_end(&mut content)?;
+            Ok(FileBody::Full(content))
+        }
+        Some(Err(())) => Ok(FileBody::Unsatisfiable { len }),
+        Some(Ok((start, end))) => {
+            let mut content = vec![0; (end - start + 1) as usize];
+            file.seek(SeekFrom::Start(start))?;
+            file.read_exact(&mut content)?;
+            Ok(FileBody::Partial {
+                content,
+                start,
+                end,
+                len,
+            })
+        }
+    }
+}
+
+/// Weak ETag from file size and modification time
+pub fn etag(metadata: &std::fs::Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|since| since.as_nanos())
+        .unwrap_or(0);
+    format!("W/\"{:x}-{:x}\"", metadata.len(), modified)
+}
+
+/// Whether an `If-None-Match` header matches an ETag (weak comparison)
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    if_none_match
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Whether a file name carries a bundler content hash (e.g. `index-B4x9kQ2a.js`)
+///
+/// The hash is the shortest `-`/`.`-separated suffix of the stem with at
+/// least 8 characters: hex with a digit, or base64url with a digit and an
+/// uppercase letter, so names like `settings-panel` don't qualify.
+fn is_content_hashed(path: &Path) -> bool {
+    let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+        return false;
+    };
+    let Some(hash) = stem
+        .rmatch_indices(['-', '.'])
+        .map(|(i, _)| &stem[i + 1..])
+        .find(|suffix| suffix.len() >= 8)
+    else {
+        return false;
+    };
+
+    let has_digit = hash.chars().any(|c| c.is_ascii_digit());
+    let is_hex = hash.chars().all(|c| c.is_ascii_hexdigit());
+    let is_base64url = hash
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && hash.chars().any(|c| c.is_ascii_uppercase());
+    has_digit && (is_hex || is_base64url)
+}
+
+/// `Cache-Control` for a served file
+///
+/// Content-hashed files under a hashed-output directory (the bundled libs)
+/// never change under the same name. Everything else, including the user's
+/// dist folder, is revalidated against its ETag on each load (a cheap 304),
+/// so a rebuilt widget is never served stale during hot reload.
+pub fn cache_control(path: &Path, hashed_output: bool) -> &'static str {
+    if hashed_output && is_content_hashed(path) {
+        "public, max-age=31536000, immutable"
+    } else {
+        "no-cache"
+    }
+}
+
+/// Decode `%XX` escapes in a URI path (None if the result isn't UTF-8)
+fn percent_decode(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+/// Join a request path onto `root`, refusing anything that would escape it
+///
+/// Rejects `..` segments (including percent-encoded ones) and, for paths
+/// that exist, anything whose canonical location is outside `root` (e.g. a
+/// symlink pointing elsewhere). Returns None when the request must be
+/// refused; a path that doesn't exist is returned as-is so it 404s.
+pub fn resolve_within(root: &Path, request_path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode(request_path)?;
+
+    let mut path = root.to_path_buf();
+    for segment in decoded.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => return None,
+            _ if segment.contains('\0') => return None,
+            _ => path.push(segment),
+        }
+    }
+
+    if let Ok(canonical) = path.canonicalize() {
+        let canonical_root = root.canonicalize().ok()?;
+        if !canonical.starts_with(&canonical_root) {
+            return None;
+        }
+    }
+
+    Some(path)
+}
+
+/// Log a refused path (debug builds only)
+pub fn log_forbidden(request_path: &str) {
+    if cfg!(debug_assertions) {
+        eprintln!("[protocol] 403 Forbidden: {}", request_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_within;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Fresh `root/` (with `index.html`) and sibling `outside/secret.txt`
+    fn fixture(name: &str) -> (PathBuf, PathBuf) {
+        let base =
+            std::env::temp_dir().join(format!("fluopanel-resolve-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&base);
+        let root = base.join("root");
+        let outside = base.join("outside");
+        fs::create_dir_all(&root).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(root.join("index.html"), "<html></html>").unwrap();
+        fs::write(outside.join("secret.txt"), "secret").unwrap();
+        (root, outside)
+    }
+
+    #[test]
+    fn resolves_paths_inside_the_root() {
+        let (root, _) = fixture("inside");
+        assert_eq!(
+            resolve_within(&root, "/index.html"),
+            Some(root.join("index.html"))
+        );
+        assert_eq!(
+            resolve_within(&root, "assets/missing.js"),
+            Some(root.join("assets").join("missing.js"))
+        );
+    }
+
+    #[test]
+    fn rejects_parent_segments() {
+        let (root, _) = fixture("parent");
+        assert_eq!(resolve_within(&root, "../outside/secret.txt"), None);
+        assert_eq!(
+            resolve_within(&root, "/assets/../../outside/secret.txt"),
+            None
+        );
+        assert_eq!(resolve_within(&root, "..\\outside\\secret.txt"), None);
+    }
+
+    #[test]
+    fn rejects_percent_encoded_parent_segments() {
+        let (root, _) = fixture("encoded");
+        assert_eq!(resolve_within(&root, "%2e%2e/outside/secret.txt"), None);
+        assert_eq!(resolve_within(&root, "%2E%2E/outside/secret.txt"), None);
+        assert_eq!(resolve_within(&root, "..%2foutside%2fsecret.txt"), None);
+        assert_eq!(resolve_within(&root, "..%5coutside%5csecret.txt"), None);
+    }
+
+    #[test]
+    fn keeps_absolute_paths_under_the_root() {
+        let (root, outside) = fixture("absolute");
+        let absolute = outside.join("secret.txt").display().to_string();
+        let resolved = resolve_within(&root, &absolute).unwrap();
+        assert!(resolved.starts_with(&root));
+        assert_ne!(resolved, outside.join("secret.txt"));
+
+        let encoded = resolve_within(&root, "%2Fetc%2Fpasswd").unwrap();
+        assert_eq!(encoded, root.join("etc").join("passwd"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlinks_that_escape_the_root() {
+        let (root, outside) = fixture("symlink");
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+        std::os::unix::fs::symlink(outside.join("secret.txt"), root.join("secret.txt")).unwrap();
+
+        assert_eq!(resolve_within(&root, "escape/secret.txt"), None);
+        assert_eq!(resolve_within(&root, "secret.txt"), None);
+    }
+}
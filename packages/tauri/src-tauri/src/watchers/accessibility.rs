@@ -0,0 +1,72 @@
+//! Accessibility Watcher
+//!
+//! Monitors accessibility display options using NSWorkspace notifications.
+//! Emits `accessibility-prefs-changed` event when reduce motion, reduce
+//! transparency, increase contrast or differentiate without color changes.
+
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, sel, ClassType};
+use objc2_app_kit::NSWorkspace;
+use objc2_foundation::{NSNotification, NSNotificationName, NSObject, NSObjectProtocol};
+use std::sync::{Once, OnceLock};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::accessibility::read_accessibility_prefs;
+use crate::commands::events;
+
+static INIT: Once = Once::new();
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Register the accessibility watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    INIT.call_once(|| {
+        // Store app handle for callback
+        let _ = APP_HANDLE.set(app_handle);
+
+        // Define observer class
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "AccessibilityObserver"]
+            #[ivars = ()]
+            struct AccessibilityObserver;
+
+            unsafe impl NSObjectProtocol for AccessibilityObserver {}
+
+            impl AccessibilityObserver {
+                #[unsafe(method(displayOptionsDidChange:))]
+                fn display_options_did_change(&self, _notification: &NSNotification) {
+                    if let Some(handle) = APP_HANDLE.get() {
+                        let prefs = read_accessibility_prefs();
+                        let _ = handle.emit(events::ACCESSIBILITY_PREFS_CHANGED, prefs);
+                    }
+                }
+            }
+        );
+
+        // Create observer instance
+        let observer: Retained<AccessibilityObserver> =
+            unsafe { msg_send![AccessibilityObserver::class(), new] };
+
+        // Posted to the workspace notification center (not default center)
+        let workspace = NSWorkspace::sharedWorkspace();
+        let notification_center = workspace.notificationCenter();
+
+        let notification_name = NSNotificationName::from_str(
+            "NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification",
+        );
+
+        unsafe {
+            notification_center.addObserver_selector_name_object(
+                &*observer,
+                sel!(displayOptionsDidChange:),
+                Some(&*notification_name),
+                None,
+            );
+        }
+
+        // Prevent observer from being deallocated
+        std::mem::forget(observer);
+    });
+
+    Ok(())
+}
@@ -11,6 +11,8 @@ use serde::Serialize;
 use std::sync::{Once, OnceLock};
 use tauri::{AppHandle, Emitter};
 
+use crate::commands::events;
+
 static INIT: Once = Once::new();
 static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
 
@@ -43,7 +45,7 @@ pub fn register(app_handle: AppHandle) -> Result<(), String> {
                     if let Some(handle) = APP_HANDLE.get() {
                         // Get the activated app info from notification userInfo
                         let event = get_frontmost_app_info();
-                        let _ = handle.emit("active-app-changed", event);
+                        let _ = handle.emit(events::ACTIVE_APP_CHANGED, event);
                     }
                 }
             }
@@ -0,0 +1,81 @@
+//! Aerospace Watcher
+//!
+//! Polls aerospace for the focused workspace and emits `aerospace-focus-changed`
+//! when it changes, so users don't have to wire up aerospace's
+//! `exec-on-workspace-change` callback to `fluopanel focus-changed`.
+//!
+//! Configured via `aerospace.watch` / `aerospace.pollIntervalMs` in fluopanel.json.
+
+use super::sources::{self, Source};
+use crate::commands::aerospace::{
+    emit_focus_changed, get_focused_workspace_id_sync, is_aerospace_available,
+};
+use crate::commands::config::{get_config, AerospaceConfig};
+use std::sync::Once;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle};
+
+static INIT: Once = Once::new();
+
+// Lower bound for the poll interval to keep subprocess spawns reasonable
+const MIN_POLL_INTERVAL_MS: u64 = 100;
+
+/// Register the aerospace focus watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    let config = get_config()?.aerospace.unwrap_or_default();
+
+    if !config.watch {
+        return Ok(());
+    }
+
+    if !is_aerospace_available() {
+        return Err("aerospace is not installed".to_string());
+    }
+
+    INIT.call_once(|| {
+        let handle = app_handle.clone();
+        let interval = poll_interval(&config);
+
+        async_runtime::spawn(async move {
+            let mut ticker = sources::Ticker::new(Source::Aerospace, interval);
+            let mut last_focused: Option<String> = None;
+
+            loop {
+                ticker.tick().await;
+
+                if !sources::is_active(Source::Aerospace) {
+                    sources::wait_active(Source::Aerospace).await;
+                    ticker.reset();
+                }
+
+                let focused = async_runtime::spawn_blocking(get_focused_workspace_id_sync)
+                    .await
+                    .ok()
+                    .and_then(|result| result.ok())
+                    .filter(|id| !id.is_empty());
+
+                let Some(focused) = focused else {
+                    continue;
+                };
+
+                // Only emit if focus changed
+                if last_focused.as_deref() == Some(focused.as_str()) {
+                    continue;
+                }
+
+                let prev = last_focused.replace(focused.clone());
+                let handle = handle.clone();
+                let _ = async_runtime::spawn_blocking(move || {
+                    emit_focus_changed(&handle, &focused, prev.as_deref());
+                })
+                .await;
+            }
+        });
+    });
+
+    Ok(())
+}
+
+fn poll_interval(config: &AerospaceConfig) -> Duration {
+    Duration::from_millis(config.poll_interval_ms.max(MIN_POLL_INTERVAL_MS))
+}
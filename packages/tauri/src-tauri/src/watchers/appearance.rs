@@ -0,0 +1,83 @@
+//! Appearance Watcher
+//!
+//! Monitors system appearance (light/dark) and accent color changes using
+//! distributed notifications.
+//! Emits `theme-resolved` with the effective theme when either changes.
+
+use crate::commands::theme::emit_theme_resolved;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{class, define_class, msg_send, sel, ClassType};
+use objc2_foundation::{NSNotification, NSObject, NSObjectProtocol, NSString};
+use std::sync::{Once, OnceLock};
+use std::time::Duration;
+use tauri::AppHandle;
+
+static INIT: Once = Once::new();
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+// Delay before resolving so NSApp.effectiveAppearance has caught up
+const APPEARANCE_SETTLE_MS: u64 = 100;
+
+/// Register the appearance watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    INIT.call_once(|| {
+        // Store app handle for callback
+        let _ = APP_HANDLE.set(app_handle);
+
+        // Define observer class
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "AppearanceObserver"]
+            #[ivars = ()]
+            struct AppearanceObserver;
+
+            unsafe impl NSObjectProtocol for AppearanceObserver {}
+
+            impl AppearanceObserver {
+                #[unsafe(method(appearanceDidChange:))]
+                fn appearance_did_change(&self, _notification: &NSNotification) {
+                    std::thread::spawn(|| {
+                        std::thread::sleep(Duration::from_millis(APPEARANCE_SETTLE_MS));
+                        if let Some(handle) = APP_HANDLE.get() {
+                            emit_theme_resolved(handle);
+                        }
+                    });
+                }
+            }
+        );
+
+        // Create observer instance
+        let observer: Retained<AppearanceObserver> =
+            unsafe { msg_send![AppearanceObserver::class(), new] };
+
+        // Theme and accent changes are posted to the distributed notification center
+        let center: *const AnyObject =
+            unsafe { msg_send![class!(NSDistributedNotificationCenter), defaultCenter] };
+        if center.is_null() {
+            return;
+        }
+
+        for name in [
+            "AppleInterfaceThemeChangedNotification",
+            "AppleColorPreferencesChangedNotification",
+        ] {
+            let notification_name = NSString::from_str(name);
+            let object: *const AnyObject = std::ptr::null();
+            unsafe {
+                let _: () = msg_send![
+                    center,
+                    addObserver: &*observer,
+                    selector: sel!(appearanceDidChange:),
+                    name: &*notification_name,
+                    object: object
+                ];
+            }
+        }
+
+        // Prevent observer from being deallocated
+        std::mem::forget(observer);
+    });
+
+    Ok(())
+}
@@ -2,14 +2,56 @@
 //!
 //! Monitors battery state changes using IOKit Power Source notifications.
 //! Emits `battery-changed` event when battery level or charging state changes.
+//!
+//! Also emits semantic events, each once per crossing:
+//! `battery-low` / `battery-critical` when the level drops below the
+//! configured thresholds (`battery.lowThreshold` / `battery.criticalThreshold`),
+//! and `battery-charging-changed` when charging starts or stops.
 
 use serde::Serialize;
-use std::sync::{Once, OnceLock};
+use std::sync::{Mutex, Once, OnceLock};
 use std::thread;
 use tauri::{AppHandle, Emitter};
 
+use crate::commands::config::{get_config, BatteryConfig};
+use crate::commands::events;
+
 static INIT: Once = Once::new();
 static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+static THRESHOLDS: OnceLock<BatteryConfig> = OnceLock::new();
+
+/// Last state the threshold events were based on
+static LAST_STATE: Mutex<Option<ThresholdState>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Level {
+    Critical,
+    Low,
+    Normal,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ThresholdState {
+    level: Level,
+    charging: bool,
+}
+
+impl ThresholdState {
+    fn of(event: &BatteryEvent, thresholds: &BatteryConfig) -> Self {
+        let level = if event.percent < thresholds.critical_threshold {
+            Level::Critical
+        } else if event.percent < thresholds.low_threshold {
+            Level::Low
+        } else {
+            Level::Normal
+        };
+
+        ThresholdState {
+            level,
+            charging: event.charging,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -49,16 +91,59 @@ extern "C" {
 extern "C" fn power_source_callback(_context: *mut std::ffi::c_void) {
     if let Some(handle) = APP_HANDLE.get() {
         if let Some(event) = get_battery_info() {
-            let _ = handle.emit("battery-changed", event);
+            emit_threshold_events(handle, &event);
+            let _ = handle.emit(events::BATTERY_CHANGED, event);
         }
     }
 }
 
+/// Emit threshold and charging events for a state transition
+fn emit_threshold_events(handle: &AppHandle, event: &BatteryEvent) {
+    let thresholds = THRESHOLDS.get_or_init(BatteryConfig::default);
+    let state = ThresholdState::of(event, thresholds);
+
+    let Ok(mut last) = LAST_STATE.lock() else {
+        return;
+    };
+    let previous = last.replace(state);
+
+    // First reading only establishes the baseline
+    let Some(previous) = previous else {
+        return;
+    };
+
+    if state.charging != previous.charging {
+        let _ = handle.emit(events::BATTERY_CHARGING_CHANGED, event);
+    }
+
+    // Fire on downward crossings only; going back up re-arms the threshold
+    if state.level < previous.level {
+        let name = match state.level {
+            Level::Critical => events::BATTERY_CRITICAL,
+            _ => events::BATTERY_LOW,
+        };
+        let _ = handle.emit(name, event);
+    }
+}
+
 /// Register the battery watcher
 pub fn register(app_handle: AppHandle) -> Result<(), String> {
     INIT.call_once(|| {
         let _ = APP_HANDLE.set(app_handle);
 
+        let thresholds = get_config()
+            .ok()
+            .and_then(|config| config.battery)
+            .unwrap_or_default();
+        let thresholds = THRESHOLDS.get_or_init(|| thresholds);
+
+        // Baseline so a battery that starts low doesn't fire immediately
+        if let Some(event) = get_battery_info() {
+            if let Ok(mut last) = LAST_STATE.lock() {
+                *last = Some(ThresholdState::of(&event, thresholds));
+            }
+        }
+
         // Spawn a thread to run the CFRunLoop
         thread::spawn(|| {
             unsafe {
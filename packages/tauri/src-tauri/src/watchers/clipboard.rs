@@ -0,0 +1,77 @@
+//! Clipboard Watcher
+//!
+//! Polls the pasteboard `changeCount` (a cheap integer read) and emits
+//! `clipboard-changed` with the new text when it changes.
+//! Changes made by `clipboard_write_text` are not emitted.
+//!
+//! Configured via `clipboard.pollIntervalMs` in fluopanel.json.
+//!
+//! The loop runs on a tokio worker thread, which has no autorelease pool, so
+//! each pasteboard read drains its own.
+
+use objc2::rc::autoreleasepool;
+use serde::Serialize;
+use std::sync::Once;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle, Emitter};
+
+use super::sources::{self, Source};
+use crate::commands::clipboard::{change_count, has_image, is_own_change, read_text};
+use crate::commands::config::get_config;
+use crate::commands::events;
+
+static INIT: Once = Once::new();
+
+// Lower bound for the poll interval
+const MIN_POLL_INTERVAL_MS: u64 = 100;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardEvent {
+    pub text: Option<String>,
+    pub has_image: bool,
+}
+
+/// Register the clipboard watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    let config = get_config()?.clipboard.unwrap_or_default();
+
+    INIT.call_once(|| {
+        let handle = app_handle.clone();
+        let interval = Duration::from_millis(config.poll_interval_ms.max(MIN_POLL_INTERVAL_MS));
+
+        async_runtime::spawn(async move {
+            let mut ticker = sources::Ticker::new(Source::Clipboard, interval);
+            let mut last_count = autoreleasepool(|_| change_count());
+
+            loop {
+                ticker.tick().await;
+
+                if !sources::is_active(Source::Clipboard) {
+                    sources::wait_active(Source::Clipboard).await;
+                    ticker.reset();
+                    // Don't replay changes that happened while parked
+                    last_count = autoreleasepool(|_| change_count());
+                }
+
+                let count = autoreleasepool(|_| change_count());
+                if count == last_count {
+                    continue;
+                }
+                last_count = count;
+
+                if is_own_change(count) {
+                    continue;
+                }
+
+                let event = autoreleasepool(|_| ClipboardEvent {
+                    text: read_text(),
+                    has_image: has_image(),
+                });
+                let _ = handle.emit(events::CLIPBOARD_CHANGED, event);
+            }
+        });
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Focus Watcher
+//!
+//! Watches the Focus database directory for writes (macOS rewrites the
+//! JSON files whenever a Focus mode is toggled).
+//! Emits `focus-mode-changed` event when the Focus state changes.
+
+use notify::{RecursiveMode, Watcher};
+use std::sync::{Mutex, Once};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::events;
+use crate::commands::focus::{focus_db_dir, read_focus_info};
+
+static INIT: Once = Once::new();
+
+/// Register the focus watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    let dir = focus_db_dir().ok_or("Home directory not found")?;
+    let mut result = Ok(());
+
+    INIT.call_once(|| {
+        let last_state = Mutex::new(read_focus_info());
+
+        let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_err() {
+                return;
+            }
+
+            let state = read_focus_info();
+            let Ok(mut last) = last_state.lock() else {
+                return;
+            };
+
+            // Only emit if state changed (one toggle writes several files)
+            if *last != state {
+                *last = state.clone();
+                let _ = app_handle.emit(events::FOCUS_MODE_CHANGED, state);
+            }
+        });
+
+        result = watcher
+            .and_then(|mut watcher| {
+                watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+                // Keep the watcher alive for the app's lifetime
+                std::mem::forget(watcher);
+                Ok(())
+            })
+            .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e));
+    });
+
+    result
+}
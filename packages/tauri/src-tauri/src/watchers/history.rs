@@ -0,0 +1,87 @@
+//! Metric History
+//!
+//! Keeps the most recent samples of the polled system metrics in ring
+//! buffers so a freshly mounted sparkline can render real history instead
+//! of starting empty. The system monitor records a sample on every tick;
+//! the buffer length comes from `history.length` in fluopanel.json.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::command;
+
+use crate::commands::config::{get_config, HistoryConfig};
+
+/// Upper bound so a misconfigured length can't grow without limit
+const MAX_HISTORY_LENGTH: usize = 3600;
+
+static CAPACITY: OnceLock<usize> = OnceLock::new();
+static HISTORY: Lazy<Mutex<HashMap<Metric, VecDeque<HistorySample>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A metric with recorded history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Metric {
+    /// Global CPU usage, 0-100
+    Cpu,
+    /// Memory usage, 0-100
+    Memory,
+    /// Bytes received per second across all interfaces
+    NetworkIn,
+    /// Bytes sent per second across all interfaces
+    NetworkOut,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct HistorySample {
+    /// Unix time in milliseconds
+    pub timestamp: u64,
+    pub value: f64,
+}
+
+fn capacity() -> usize {
+    *CAPACITY.get_or_init(|| {
+        let config = get_config()
+            .ok()
+            .and_then(|config| config.history)
+            .unwrap_or_default();
+        config.length.clamp(1, MAX_HISTORY_LENGTH)
+    })
+}
+
+/// Append a sample, dropping the oldest once the buffer is full
+pub fn record(metric: Metric, value: f64) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let capacity = capacity();
+
+    let Ok(mut history) = HISTORY.lock() else {
+        return;
+    };
+    let samples = history
+        .entry(metric)
+        .or_insert_with(|| VecDeque::with_capacity(capacity));
+    while samples.len() >= capacity {
+        samples.pop_front();
+    }
+    samples.push_back(HistorySample { timestamp, value });
+}
+
+// ============================================
+// History Commands
+// ============================================
+
+/// Recorded samples of a metric, oldest first
+#[command]
+pub fn get_history(metric: Metric) -> Result<Vec<HistorySample>, String> {
+    let history = HISTORY.lock().map_err(|e| e.to_string())?;
+    Ok(history
+        .get(&metric)
+        .map(|samples| samples.iter().copied().collect())
+        .unwrap_or_default())
+}
@@ -0,0 +1,94 @@
+//! Libs Watcher (debug builds only)
+//!
+//! Watches the dev `libs/` directory served at `fluopanel://lib/` (vue,
+//! providers, tauri-api) so widgets can reload when a shared runtime library
+//! is rebuilt. Emits `libs-changed` with the file name once the file has
+//! been quiet for `DEBOUNCE`, so a reload sees the finished write.
+
+use notify::event::EventKind;
+use notify::{RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, Once};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::events;
+
+/// A rebuild writes a file several times; emit once it has been quiet this long
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+static INIT: Once = Once::new();
+
+/// Change counter per file; a pending emit is dropped if the file changed again
+static GENERATIONS: Lazy<Mutex<HashMap<String, u64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+struct LibsChangedPayload {
+    file: String,
+}
+
+/// Register the libs watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("libs");
+    let mut result = Ok(());
+
+    INIT.call_once(|| {
+        let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else {
+                return;
+            };
+            if !matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                return;
+            }
+            for path in &event.paths {
+                let Some(file) = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                else {
+                    continue;
+                };
+                // Editor swap and temp files
+                if file.starts_with('.') || file.ends_with('~') {
+                    continue;
+                }
+
+                let Ok(mut generations) = GENERATIONS.lock() else {
+                    return;
+                };
+                let generation = generations.entry(file.clone()).or_default();
+                *generation += 1;
+                let generation = *generation;
+                drop(generations);
+
+                let app_handle = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(DEBOUNCE).await;
+                    let latest = GENERATIONS
+                        .lock()
+                        .ok()
+                        .and_then(|generations| generations.get(&file).copied());
+                    if latest == Some(generation) {
+                        let _ = app_handle.emit(events::LIBS_CHANGED, LibsChangedPayload { file });
+                    }
+                });
+            }
+        });
+
+        result = watcher
+            .and_then(|mut watcher| {
+                watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+                // Keep the watcher alive for the app's lifetime
+                std::mem::forget(watcher);
+                Ok(())
+            })
+            .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e));
+    });
+
+    result
+}
@@ -1,23 +1,35 @@
 //! Media Watcher
 //!
 //! Monitors Now Playing media state changes.
-//! Uses AppleScript to query Spotify/Music apps.
 //! Emits `media-changed` event when media state changes.
 //!
-//! Note: Uses polling with change detection due to private MediaRemote API.
-//! Future improvement: Use MRMediaRemoteRegisterForNowPlayingNotifications.
+//! Primary source is the private MediaRemote framework
+//! (`MRMediaRemoteRegisterForNowPlayingNotifications`), which covers every
+//! Now Playing client (including browsers) and reports changes instantly.
+//! If the framework can't be loaded, falls back to polling Spotify/Music
+//! via AppleScript. On macOS 15.4+ MediaRemote loads but returns no info to
+//! unentitled processes, so an empty answer is also checked against
+//! AppleScript, and polling starts once AppleScript sees media MediaRemote
+//! missed.
 
+use once_cell::sync::Lazy;
 use serde::Serialize;
-use std::process::Command;
-use std::sync::Once;
+use std::sync::{Mutex, Once};
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, async_runtime};
 
+use super::sources::{self, Source};
+use crate::commands::{events, system};
+
 static INIT: Once = Once::new();
+static POLLING: Once = Once::new();
 
 // Check interval (5 seconds - balanced between responsiveness and CPU usage)
 const MEDIA_CHECK_INTERVAL_SECS: u64 = 5;
 
+/// Last emitted state, shared by the MediaRemote and polling paths
+static LAST_STATE: Lazy<Mutex<Option<MediaEvent>>> = Lazy::new(|| Mutex::new(None));
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaEvent {
@@ -31,34 +43,85 @@ pub struct MediaEvent {
     pub artwork_url: Option<String>,
 }
 
+impl MediaEvent {
+    fn stopped() -> Self {
+        Self {
+            playing: false,
+            title: None,
+            artist: None,
+            album: None,
+            duration: None,
+            position: None,
+            app: None,
+            artwork_url: None,
+        }
+    }
+}
+
 /// Register the media watcher
 pub fn register(app_handle: AppHandle) -> Result<(), String> {
     INIT.call_once(|| {
+        if media_remote::register(app_handle.clone()) {
+            return;
+        }
+
+        eprintln!("[media] MediaRemote unavailable, falling back to AppleScript polling");
+        start_polling(app_handle);
+    });
+
+    Ok(())
+}
+
+/// Poll Spotify/Music via AppleScript (started at most once)
+fn start_polling(app_handle: AppHandle) {
+    POLLING.call_once(|| {
         let handle = app_handle.clone();
         async_runtime::spawn(async move {
-            let mut ticker = tokio::time::interval(Duration::from_secs(MEDIA_CHECK_INTERVAL_SECS));
-            let mut last_state: Option<MediaEvent> = None;
+            let mut ticker = sources::Ticker::new(
+                Source::Media,
+                Duration::from_secs(MEDIA_CHECK_INTERVAL_SECS),
+            );
 
             loop {
                 ticker.tick().await;
 
+                if !sources::is_active(Source::Media) {
+                    sources::wait_active(Source::Media).await;
+                    ticker.reset();
+                    reset_last_state();
+                }
+
                 if let Ok(event) = get_media_info() {
-                    // Only emit if state changed (ignoring position for comparison)
-                    let should_emit = match &last_state {
-                        Some(last) => !media_states_equal(last, &event),
-                        None => true,
-                    };
-
-                    if should_emit {
-                        last_state = Some(event.clone());
-                        let _ = handle.emit("media-changed", event);
-                    }
+                    emit_if_changed(&handle, event);
                 }
             }
         });
     });
+}
 
-    Ok(())
+/// Forget the last emitted state so the next check always emits
+fn reset_last_state() {
+    if let Ok(mut last_state) = LAST_STATE.lock() {
+        *last_state = None;
+    }
+}
+
+/// Emit `media-changed` if the state differs from the last emitted one
+fn emit_if_changed(handle: &AppHandle, event: MediaEvent) {
+    let Ok(mut last_state) = LAST_STATE.lock() else {
+        return;
+    };
+
+    // Only emit if state changed (ignoring position for comparison)
+    let should_emit = match &*last_state {
+        Some(last) => !media_states_equal(last, &event),
+        None => true,
+    };
+
+    if should_emit {
+        *last_state = Some(event.clone());
+        let _ = handle.emit(events::MEDIA_CHANGED, event);
+    }
 }
 
 /// Compare media states, ignoring position (which always changes)
@@ -70,72 +133,333 @@ fn media_states_equal(a: &MediaEvent, b: &MediaEvent) -> bool {
         && a.app == b.app
 }
 
-/// Get current media info
+/// Get current media info via AppleScript (respects `media.preferredApp`)
 fn get_media_info() -> Result<MediaEvent, String> {
-    // Try to get Now Playing info using osascript
-    // This works with Music.app, Spotify, and other media apps
-    let script = r#"
-        set mediaInfo to ""
-
-        -- Try Spotify first
-        if application "Spotify" is running then
-            tell application "Spotify"
-                if player state is playing then
-                    set mediaInfo to "true|" & name of current track & "|" & artist of current track & "|" & album of current track & "|" & (duration of current track / 1000) & "|" & (player position) & "|Spotify|"
-                else if player state is paused then
-                    set mediaInfo to "false|" & name of current track & "|" & artist of current track & "|" & album of current track & "|" & (duration of current track / 1000) & "|" & (player position) & "|Spotify|"
-                end if
-            end tell
-        end if
-
-        -- Try Music.app if no Spotify info
-        if mediaInfo is "" and application "Music" is running then
-            tell application "Music"
-                if player state is playing then
-                    set currentTrack to current track
-                    set mediaInfo to "true|" & name of currentTrack & "|" & artist of currentTrack & "|" & album of currentTrack & "|" & (duration of currentTrack) & "|" & player position & "|Music|"
-                else if player state is paused then
-                    set currentTrack to current track
-                    set mediaInfo to "false|" & name of currentTrack & "|" & artist of currentTrack & "|" & album of currentTrack & "|" & (duration of currentTrack) & "|" & player position & "|Music|"
-                end if
-            end tell
-        end if
-
-        return mediaInfo
-    "#;
-
-    let output = Command::new("osascript")
-        .args(["-e", script])
-        .output()
-        .map_err(|e| format!("Failed to get media info: {}", e))?;
-
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let parts: Vec<&str> = stdout.trim().split('|').collect();
-
-        if parts.len() >= 7 && !parts[0].is_empty() {
-            return Ok(MediaEvent {
-                playing: parts[0] == "true",
-                title: Some(parts[1].to_string()).filter(|s| !s.is_empty()),
-                artist: Some(parts[2].to_string()).filter(|s| !s.is_empty()),
-                album: Some(parts[3].to_string()).filter(|s| !s.is_empty()),
-                duration: parts[4].parse().ok(),
-                position: parts[5].parse().ok(),
-                app: Some(parts[6].to_string()).filter(|s| !s.is_empty()),
-                artwork_url: None,
-            });
-        }
-    }
+    let info = system::get_media_info()?;
 
-    // No media playing
     Ok(MediaEvent {
-        playing: false,
-        title: None,
-        artist: None,
-        album: None,
-        duration: None,
-        position: None,
-        app: None,
-        artwork_url: None,
+        playing: info.playing,
+        title: info.title,
+        artist: info.artist,
+        album: info.album,
+        duration: info.duration,
+        position: info.position,
+        app: info.app,
+        artwork_url: info.artwork_url,
     })
 }
+
+/// Apply `media.preferredApp` to a MediaRemote result
+///
+/// MediaRemote reports whichever app owns Now Playing. When a specific app
+/// is preferred and something else is playing, query that app directly.
+fn prefer_configured_app(event: MediaEvent) -> MediaEvent {
+    let order = system::media_app_order();
+    if order.len() > 1 || event.app.as_deref() == order.first().copied() {
+        return event;
+    }
+
+    get_media_info().unwrap_or_else(|_| MediaEvent::stopped())
+}
+
+// ============================================
+// MediaRemote (private framework)
+// ============================================
+
+mod media_remote {
+    use super::{
+        emit_if_changed, get_media_info, prefer_configured_app, reset_last_state, start_polling,
+        MediaEvent,
+    };
+    use crate::commands::artwork::{cached_artwork, to_data_url};
+    use crate::watchers::sources::{self, Source};
+    use block2::{Block, RcBlock};
+    use objc2::encode::EncodeArgument;
+    use objc2::runtime::AnyObject;
+    use objc2::{class, msg_send};
+    use objc2_foundation::NSString;
+    use std::ffi::{c_char, c_int, c_void, CStr};
+    use std::sync::{mpsc, OnceLock};
+    use std::time::Duration;
+    use tauri::AppHandle;
+
+    const FRAMEWORK_PATH: &CStr =
+        c"/System/Library/PrivateFrameworks/MediaRemote.framework/MediaRemote";
+    const RTLD_LAZY: c_int = 0x1;
+
+    /// How long to wait for MediaRemote to answer a query
+    const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+    /// Notifications posted to the default center after registering
+    const NOTIFICATIONS: &[&str] = &[
+        "kMRMediaRemoteNowPlayingInfoDidChangeNotification",
+        "kMRMediaRemoteNowPlayingApplicationIsPlayingDidChangeNotification",
+        "kMRMediaRemoteNowPlayingApplicationDidChangeNotification",
+    ];
+
+    extern "C" {
+        fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+        fn dispatch_get_global_queue(identifier: isize, flags: usize) -> *mut c_void;
+    }
+
+    type RegisterFn = unsafe extern "C" fn(queue: *mut c_void);
+    type GetInfoFn = unsafe extern "C" fn(queue: *mut c_void, block: &Block<dyn Fn(*const AnyObject)>);
+    type GetIsPlayingFn = unsafe extern "C" fn(queue: *mut c_void, block: &Block<dyn Fn(u8)>);
+    type GetPidFn = unsafe extern "C" fn(queue: *mut c_void, block: &Block<dyn Fn(c_int)>);
+
+    /// Resolved MediaRemote entry points
+    struct MediaRemote {
+        register_for_notifications: RegisterFn,
+        get_now_playing_info: GetInfoFn,
+        get_is_playing: GetIsPlayingFn,
+        get_pid: GetPidFn,
+    }
+
+    static MEDIA_REMOTE: OnceLock<Option<MediaRemote>> = OnceLock::new();
+
+    fn load() -> Option<&'static MediaRemote> {
+        MEDIA_REMOTE
+            .get_or_init(|| unsafe {
+                let handle = dlopen(FRAMEWORK_PATH.as_ptr(), RTLD_LAZY);
+                if handle.is_null() {
+                    return None;
+                }
+
+                let symbol = |name: &CStr| {
+                    let ptr = dlsym(handle, name.as_ptr());
+                    (!ptr.is_null()).then_some(ptr)
+                };
+
+                Some(MediaRemote {
+                    register_for_notifications: std::mem::transmute::<*mut c_void, RegisterFn>(
+                        symbol(c"MRMediaRemoteRegisterForNowPlayingNotifications")?,
+                    ),
+                    get_now_playing_info: std::mem::transmute::<*mut c_void, GetInfoFn>(symbol(
+                        c"MRMediaRemoteGetNowPlayingInfo",
+                    )?),
+                    get_is_playing: std::mem::transmute::<*mut c_void, GetIsPlayingFn>(symbol(
+                        c"MRMediaRemoteGetNowPlayingApplicationIsPlaying",
+                    )?),
+                    get_pid: std::mem::transmute::<*mut c_void, GetPidFn>(symbol(
+                        c"MRMediaRemoteGetNowPlayingApplicationPID",
+                    )?),
+                })
+            })
+            .as_ref()
+    }
+
+    /// Start listening for Now Playing notifications
+    ///
+    /// Returns false if the framework couldn't be loaded.
+    pub fn register(app_handle: AppHandle) -> bool {
+        let Some(remote) = load() else {
+            return false;
+        };
+
+        unsafe {
+            let queue = dispatch_get_global_queue(0, 0);
+            (remote.register_for_notifications)(queue);
+
+            let center: *const AnyObject = msg_send![class!(NSNotificationCenter), defaultCenter];
+            for name in NOTIFICATIONS {
+                let handle = app_handle.clone();
+                let block = RcBlock::new(move |_notification: *mut AnyObject| {
+                    if !sources::is_active(Source::Media) {
+                        return;
+                    }
+
+                    // Queries block on MediaRemote callbacks; don't stall the posting thread
+                    let handle = handle.clone();
+                    std::thread::spawn(move || {
+                        emit_if_changed(&handle, get_now_playing(&handle, remote));
+                    });
+                });
+
+                let name = NSString::from_str(name);
+                let nil: *const AnyObject = std::ptr::null();
+                // The center retains the observer for the app's lifetime
+                let _observer: *const AnyObject = msg_send![
+                    center,
+                    addObserverForName: &*name,
+                    object: nil,
+                    queue: nil,
+                    usingBlock: &*block
+                ];
+            }
+        }
+
+        // Emit the initial state, and again whenever media is re-activated
+        // (notifications that arrived while inactive were dropped)
+        tauri::async_runtime::spawn(async move {
+            loop {
+                sources::wait_active(Source::Media).await;
+                reset_last_state();
+                let handle = app_handle.clone();
+                let _ = tauri::async_runtime::spawn_blocking(move || {
+                    emit_if_changed(&handle, get_now_playing(&handle, remote));
+                })
+                .await;
+
+                sources::wait_inactive(Source::Media).await;
+            }
+        });
+        true
+    }
+
+    /// Query a MediaRemote getter and wait for its callback
+    fn query<T: Send + 'static, A: 'static>(
+        call: impl FnOnce(&Block<dyn Fn(A)>),
+        map: impl Fn(A) -> T + 'static,
+    ) -> Option<T>
+    where
+        A: EncodeArgument,
+    {
+        let (tx, rx) = mpsc::channel();
+        let block = RcBlock::new(move |value: A| {
+            let _ = tx.send(map(value));
+        });
+        call(&block);
+        rx.recv_timeout(QUERY_TIMEOUT).ok()
+    }
+
+    /// Read the current Now Playing state, honoring `media.preferredApp`
+    fn get_now_playing(handle: &AppHandle, remote: &MediaRemote) -> MediaEvent {
+        if let Some(event) = read_now_playing(remote) {
+            return prefer_configured_app(event);
+        }
+
+        // No info means nothing is playing, or MediaRemote refuses this
+        // process (macOS 15.4+). If the players themselves report media,
+        // MediaRemote is blind and its notifications can't be relied on.
+        let event = get_media_info().unwrap_or_else(|_| MediaEvent::stopped());
+        if event.title.is_some() {
+            start_polling(handle.clone());
+        }
+        event
+    }
+
+    /// None when MediaRemote returns nil or empty info
+    fn read_now_playing(remote: &MediaRemote) -> Option<MediaEvent> {
+        let queue = unsafe { dispatch_get_global_queue(0, 0) };
+
+        let mut event = query(
+            |block| unsafe { (remote.get_now_playing_info)(queue, block) },
+            |info: *const AnyObject| unsafe { parse_info(info) },
+        )
+        .flatten()?;
+
+        if let Some(playing) = query(
+            |block| unsafe { (remote.get_is_playing)(queue, block) },
+            |playing: u8| playing != 0,
+        ) {
+            event.playing = playing;
+        }
+
+        event.app = query(
+            |block| unsafe { (remote.get_pid)(queue, block) },
+            |pid: c_int| pid,
+        )
+        .filter(|pid| *pid > 0)
+        .and_then(app_name_for_pid);
+
+        Some(event)
+    }
+
+    /// Convert a Now Playing info dictionary into a MediaEvent
+    ///
+    /// Called inside the MediaRemote callback while the dictionary is alive.
+    unsafe fn parse_info(info: *const AnyObject) -> Option<MediaEvent> {
+        if info.is_null() {
+            return None;
+        }
+
+        let string = |key: &str| -> Option<String> {
+            let value: *const AnyObject = msg_send![info, objectForKey: &*NSString::from_str(key)];
+            if value.is_null() {
+                return None;
+            }
+            let description: *const NSString = msg_send![value, description];
+            if description.is_null() {
+                return None;
+            }
+            Some((*description).to_string()).filter(|s| !s.is_empty())
+        };
+        let number = |key: &str| -> Option<f64> {
+            let value: *const AnyObject = msg_send![info, objectForKey: &*NSString::from_str(key)];
+            if value.is_null() {
+                return None;
+            }
+            let number: f64 = msg_send![value, doubleValue];
+            Some(number)
+        };
+
+        let title = string("kMRMediaRemoteNowPlayingInfoTitle");
+        if title.is_none() {
+            return None;
+        }
+
+        let rate = number("kMRMediaRemoteNowPlayingInfoPlaybackRate").unwrap_or(0.0);
+
+        // Elapsed time is reported as of the info timestamp; extrapolate while playing
+        let position = number("kMRMediaRemoteNowPlayingInfoElapsedTime").map(|elapsed| {
+            let timestamp: *const AnyObject = msg_send![
+                info,
+                objectForKey: &*NSString::from_str("kMRMediaRemoteNowPlayingInfoTimestamp")
+            ];
+            if timestamp.is_null() || rate == 0.0 {
+                return elapsed;
+            }
+            let since: f64 = msg_send![timestamp, timeIntervalSinceNow];
+            elapsed + (-since) * rate
+        });
+
+        let artist = string("kMRMediaRemoteNowPlayingInfoArtist");
+        let artwork_url = cached_artwork(title.as_deref(), artist.as_deref(), || {
+            let data: *const AnyObject = msg_send![
+                info,
+                objectForKey: &*NSString::from_str("kMRMediaRemoteNowPlayingInfoArtworkData")
+            ];
+            if data.is_null() {
+                return None;
+            }
+            let len: usize = msg_send![data, length];
+            let bytes_ptr: *const u8 = msg_send![data, bytes];
+            if bytes_ptr.is_null() {
+                return None;
+            }
+            let bytes = std::slice::from_raw_parts(bytes_ptr, len);
+            let mime_type = string("kMRMediaRemoteNowPlayingInfoArtworkMIMEType");
+            to_data_url(bytes, mime_type.as_deref())
+        });
+
+        Some(MediaEvent {
+            playing: rate > 0.0,
+            title,
+            artist,
+            album: string("kMRMediaRemoteNowPlayingInfoAlbum"),
+            duration: number("kMRMediaRemoteNowPlayingInfoDuration"),
+            position,
+            app: None,
+            artwork_url,
+        })
+    }
+
+    /// Localized name of the app with the given pid
+    fn app_name_for_pid(pid: c_int) -> Option<String> {
+        unsafe {
+            let app: *const AnyObject = msg_send![
+                class!(NSRunningApplication),
+                runningApplicationWithProcessIdentifier: pid
+            ];
+            if app.is_null() {
+                return None;
+            }
+            let name: *const NSString = msg_send![app, localizedName];
+            if name.is_null() {
+                return None;
+            }
+            Some((*name).to_string())
+        }
+    }
+}
@@ -3,23 +3,75 @@
 //! Monitors system events and emits Tauri events to the frontend.
 //! Replaces frontend polling with native event-driven architecture.
 
+#[cfg(target_os = "macos")]
+pub mod accessibility;
 #[cfg(target_os = "macos")]
 pub mod active_app;
 #[cfg(target_os = "macos")]
+pub mod aerospace;
+#[cfg(target_os = "macos")]
+pub mod appearance;
+#[cfg(target_os = "macos")]
 pub mod battery;
 #[cfg(target_os = "macos")]
+pub mod clipboard;
+#[cfg(target_os = "macos")]
+pub mod focus;
+pub mod history;
+#[cfg(debug_assertions)]
+pub mod libs;
+#[cfg(target_os = "macos")]
 pub mod media;
 #[cfg(target_os = "macos")]
 pub mod network;
+pub mod sources;
+#[cfg(target_os = "macos")]
+pub mod notifications;
 #[cfg(target_os = "macos")]
 pub mod system_monitor;
 #[cfg(target_os = "macos")]
+pub mod thermal;
+#[cfg(target_os = "macos")]
 pub mod volume;
+#[cfg(target_os = "macos")]
+pub mod window_title;
 
+use std::time::Duration;
 use tauri::AppHandle;
 
+use crate::commands::config::{get_config, WatchersConfig};
+use sources::Source;
+
+/// Shortest configurable poll interval, so a typo can't spin a watcher
+const MIN_WATCHER_INTERVAL_MS: u64 = 1000;
+
+/// Apply the `watchers` intervals from fluopanel.json
+/// Called at startup and whenever the config is saved
+pub fn apply_intervals(config: &WatchersConfig) {
+    let intervals = [
+        (Source::Media, config.media_interval_ms),
+        (Source::Network, config.network_interval_ms),
+        (Source::System, config.system_interval_ms),
+    ];
+
+    for (source, interval_ms) in intervals {
+        let interval = interval_ms.map(|ms| Duration::from_millis(ms.max(MIN_WATCHER_INTERVAL_MS)));
+        sources::set_interval(source, interval);
+    }
+}
+
 /// Initialize all system watchers
 pub fn init_all(app_handle: AppHandle) {
+    match get_config() {
+        Ok(config) => apply_intervals(&config.watchers.unwrap_or_default()),
+        Err(e) => eprintln!("Failed to read watcher intervals: {}", e),
+    }
+
+    #[cfg(debug_assertions)]
+    if let Err(e) = libs::register(app_handle.clone()) {
+        eprintln!("Failed to register libs watcher: {}", e);
+    }
+
     #[cfg(target_os = "macos")]
     {
         if let Err(e) = active_app::register(app_handle.clone()) {
@@ -42,8 +94,40 @@ pub fn init_all(app_handle: AppHandle) {
             eprintln!("Failed to register network watcher: {}", e);
         }
 
-        if let Err(e) = media::register(app_handle) {
+        if let Err(e) = media::register(app_handle.clone()) {
             eprintln!("Failed to register media watcher: {}", e);
         }
+
+        if let Err(e) = notifications::register(app_handle.clone()) {
+            eprintln!("Failed to register notification watcher: {}", e);
+        }
+
+        if let Err(e) = aerospace::register(app_handle.clone()) {
+            eprintln!("Failed to register aerospace watcher: {}", e);
+        }
+
+        if let Err(e) = accessibility::register(app_handle.clone()) {
+            eprintln!("Failed to register accessibility watcher: {}", e);
+        }
+
+        if let Err(e) = clipboard::register(app_handle.clone()) {
+            eprintln!("Failed to register clipboard watcher: {}", e);
+        }
+
+        if let Err(e) = focus::register(app_handle.clone()) {
+            eprintln!("Failed to register focus watcher: {}", e);
+        }
+
+        if let Err(e) = thermal::register(app_handle.clone()) {
+            eprintln!("Failed to register thermal watcher: {}", e);
+        }
+
+        if let Err(e) = window_title::register(app_handle.clone()) {
+            eprintln!("Failed to register window title watcher: {}", e);
+        }
+
+        if let Err(e) = appearance::register(app_handle) {
+            eprintln!("Failed to register appearance watcher: {}", e);
+        }
     }
 }
@@ -1,22 +1,44 @@
 //! Network Watcher
 //!
-//! Monitors network state changes.
-//! Currently uses a timer-based approach for reliability.
+//! Monitors network state changes through SCDynamicStore notifications on
+//! the global IPv4/IPv6 state and per-interface link, address and Wi-Fi keys,
+//! delivered on a dedicated CFRunLoop thread. A slow poll backs it up in case
+//! a change slips past the watched keys, and a short refresh keeps Wi-Fi
+//! signal strength current since RSSI changes raise no notification.
 //! Emits `network-changed` event when network state changes.
-//!
-//! Future improvement: Use SCDynamicStore for true event-driven monitoring.
 
-use crate::commands::system::get_wifi_info;
+use super::sources::{self, Source};
+use crate::commands::events;
+use crate::commands::system;
+use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::sync::Once;
+use std::thread;
 use std::time::Duration;
-use sysinfo::Networks;
 use tauri::{AppHandle, Emitter, async_runtime};
+use tokio::sync::Notify;
 
 static INIT: Once = Once::new();
 
-// Check interval (5 seconds - more responsive than UI polling)
-const NETWORK_CHECK_INTERVAL_SECS: u64 = 5;
+/// Signalled by the SCDynamicStore callback
+static STORE_CHANGED: Lazy<Notify> = Lazy::new(Notify::new);
+
+// Safety-net poll interval; changes normally arrive via SCDynamicStore
+const NETWORK_FALLBACK_INTERVAL_SECS: u64 = 30;
+
+// RSSI refresh while the primary interface is Wi-Fi
+const RSSI_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A Wi-Fi switch or VPN connect touches several keys in quick succession
+const SETTLE_DELAY: Duration = Duration::from_millis(300);
+
+/// Keys whose changes mean the primary network may have changed
+const WATCHED_KEYS: &[&str] = &["State:/Network/Global/IPv4", "State:/Network/Global/IPv6"];
+const WATCHED_PATTERNS: &[&str] = &[
+    "State:/Network/Interface/[^/]+/Link",
+    "State:/Network/Interface/[^/]+/IPv4",
+    "State:/Network/Interface/[^/]+/AirPort",
+];
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -26,31 +48,159 @@ pub struct NetworkEvent {
     pub network_type: String,
     pub ssid: Option<String>,
     pub signal_strength: Option<i32>,
+    pub band: Option<String>,
+    pub channel: Option<u32>,
+    pub tx_rate_mbps: Option<u32>,
     pub connected: bool,
 }
 
+mod dynamic_store {
+    use core_foundation_sys::array::{kCFTypeArrayCallBacks, CFArrayCreate, CFArrayRef};
+    use core_foundation_sys::base::{CFAllocatorRef, CFIndex, CFRelease};
+    use core_foundation_sys::runloop::{
+        kCFRunLoopDefaultMode, CFRunLoopAddSource, CFRunLoopGetCurrent, CFRunLoopRun,
+        CFRunLoopSourceRef,
+    };
+    use core_foundation_sys::string::CFStringRef;
+    use objc2::rc::Retained;
+    use objc2_foundation::NSString;
+    use std::ffi::c_void;
+
+    type StoreCallback = extern "C" fn(*const c_void, CFArrayRef, *mut c_void);
+
+    #[link(name = "SystemConfiguration", kind = "framework")]
+    extern "C" {
+        fn SCDynamicStoreCreate(
+            allocator: CFAllocatorRef,
+            name: CFStringRef,
+            callout: StoreCallback,
+            context: *mut c_void,
+        ) -> *const c_void;
+        fn SCDynamicStoreSetNotificationKeys(
+            store: *const c_void,
+            keys: CFArrayRef,
+            patterns: CFArrayRef,
+        ) -> u8;
+        fn SCDynamicStoreCreateRunLoopSource(
+            allocator: CFAllocatorRef,
+            store: *const c_void,
+            order: CFIndex,
+        ) -> CFRunLoopSourceRef;
+    }
+
+    /// CFArray of CFStrings (NSString is toll-free bridged)
+    unsafe fn string_array(strings: &[&str]) -> CFArrayRef {
+        let strings: Vec<Retained<NSString>> =
+            strings.iter().map(|s| NSString::from_str(s)).collect();
+        let values: Vec<*const c_void> = strings
+            .iter()
+            .map(|s| &**s as *const NSString as *const c_void)
+            .collect();
+        CFArrayCreate(
+            std::ptr::null(),
+            values.as_ptr(),
+            values.len() as CFIndex,
+            &kCFTypeArrayCallBacks,
+        )
+    }
+
+    /// Subscribe to the keys and run the current thread's run loop forever
+    pub fn run(keys: &[&str], patterns: &[&str], callback: StoreCallback) -> Result<(), String> {
+        unsafe {
+            let name = NSString::from_str("fluopanel-network");
+            let store = SCDynamicStoreCreate(
+                std::ptr::null(),
+                &*name as *const NSString as CFStringRef,
+                callback,
+                std::ptr::null_mut(),
+            );
+            if store.is_null() {
+                return Err("Failed to create SCDynamicStore".to_string());
+            }
+
+            let keys = string_array(keys);
+            let patterns = string_array(patterns);
+            let subscribed = SCDynamicStoreSetNotificationKeys(store, keys, patterns) != 0;
+            CFRelease(keys as *const c_void);
+            CFRelease(patterns as *const c_void);
+            if !subscribed {
+                CFRelease(store);
+                return Err("Failed to set SCDynamicStore notification keys".to_string());
+            }
+
+            let source = SCDynamicStoreCreateRunLoopSource(std::ptr::null(), store, 0);
+            if source.is_null() {
+                CFRelease(store);
+                return Err("Failed to create SCDynamicStore run loop source".to_string());
+            }
+
+            CFRunLoopAddSource(CFRunLoopGetCurrent(), source, kCFRunLoopDefaultMode);
+            CFRunLoopRun();
+        }
+
+        Ok(())
+    }
+}
+
+/// Callback for SCDynamicStore key changes
+extern "C" fn store_callback(
+    _store: *const std::ffi::c_void,
+    _changed_keys: core_foundation_sys::array::CFArrayRef,
+    _info: *mut std::ffi::c_void,
+) {
+    STORE_CHANGED.notify_one();
+}
+
 /// Register the network watcher
 pub fn register(app_handle: AppHandle) -> Result<(), String> {
     INIT.call_once(|| {
+        // Spawn a thread to run the CFRunLoop
+        thread::spawn(|| {
+            if let Err(e) = dynamic_store::run(WATCHED_KEYS, WATCHED_PATTERNS, store_callback) {
+                eprintln!("[network] {}, falling back to polling", e);
+            }
+        });
+
         let handle = app_handle.clone();
         async_runtime::spawn(async move {
-            let mut ticker = tokio::time::interval(Duration::from_secs(NETWORK_CHECK_INTERVAL_SECS));
+            let mut ticker = sources::Ticker::new(
+                Source::Network,
+                Duration::from_secs(NETWORK_FALLBACK_INTERVAL_SECS),
+            );
+            let mut rssi_refresh = tokio::time::interval(RSSI_REFRESH_INTERVAL);
+            rssi_refresh.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
             let mut last_state: Option<NetworkEvent> = None;
 
             loop {
-                ticker.tick().await;
-
-                if let Ok(event) = get_network_info() {
-                    // Only emit if state changed
-                    let should_emit = match &last_state {
-                        Some(last) => last != &event,
-                        None => true,
-                    };
-
-                    if should_emit {
-                        last_state = Some(event.clone());
-                        let _ = handle.emit("network-changed", event);
-                    }
+                let on_wifi = last_state
+                    .as_ref()
+                    .is_some_and(|state| state.network_type == "wifi");
+                let store_changed = tokio::select! {
+                    _ = ticker.tick() => false,
+                    _ = STORE_CHANGED.notified() => true,
+                    _ = rssi_refresh.tick(), if on_wifi => false,
+                };
+                if store_changed {
+                    tokio::time::sleep(SETTLE_DELAY).await;
+                    ticker.reset();
+                }
+
+                // Park while no widget needs network data, then re-emit for the new consumer
+                if !sources::is_active(Source::Network) {
+                    sources::wait_active(Source::Network).await;
+                    ticker.reset();
+                    last_state = None;
+                }
+
+                let event = match async_runtime::spawn_blocking(get_network_info).await {
+                    Ok(Ok(event)) => event,
+                    _ => continue,
+                };
+
+                // Only emit if state changed
+                if last_state.as_ref() != Some(&event) {
+                    last_state = Some(event.clone());
+                    let _ = handle.emit(events::NETWORK_CHANGED, event);
                 }
             }
         });
@@ -59,37 +209,18 @@ pub fn register(app_handle: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-/// Get current network info
+/// Get current network info (primary interface)
 fn get_network_info() -> Result<NetworkEvent, String> {
-    let networks = Networks::new_with_refreshed_list();
-
-    // Find the primary network interface (usually en0 for WiFi on macOS)
-    for (interface_name, _network) in &networks {
-        if interface_name.starts_with("en") {
-            // Try to get WiFi info
-            let wifi_info = get_wifi_info();
-
-            return Ok(NetworkEvent {
-                interface: interface_name.clone(),
-                network_type: if interface_name == "en0" {
-                    "wifi".to_string()
-                } else {
-                    "ethernet".to_string()
-                },
-                ssid: wifi_info.as_ref().map(|(ssid, _)| ssid.clone()),
-                signal_strength: wifi_info.as_ref().and_then(|(_, strength)| *strength),
-                connected: true,
-            });
-        }
-    }
+    let info = system::get_network_info()?;
 
     Ok(NetworkEvent {
-        interface: "unknown".to_string(),
-        network_type: "unknown".to_string(),
-        ssid: None,
-        signal_strength: None,
-        connected: false,
+        interface: info.interface,
+        network_type: info.network_type,
+        ssid: info.ssid,
+        signal_strength: info.signal_strength,
+        band: info.band,
+        channel: info.channel,
+        tx_rate_mbps: info.tx_rate_mbps,
+        connected: info.connected,
     })
 }
-
-// WiFi info functions are now in crate::commands::system
@@ -0,0 +1,56 @@
+//! Notification Watcher
+//!
+//! Polls the Notification Center database for the number of notifications.
+//! Emits `notification-count-changed` event when the count changes.
+//! Emits nothing if the database is inaccessible (e.g. no Full Disk Access).
+
+use super::sources::{self, Source};
+use crate::commands::events;
+use crate::commands::system::get_notification_count_sync;
+use std::sync::Once;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, async_runtime};
+
+static INIT: Once = Once::new();
+
+// Check interval (notification counts rarely need second-level precision)
+const NOTIFICATION_CHECK_INTERVAL_SECS: u64 = 15;
+
+/// Register the notification watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    INIT.call_once(|| {
+        let handle = app_handle.clone();
+        async_runtime::spawn(async move {
+            let mut ticker = sources::Ticker::new(
+                Source::Notifications,
+                Duration::from_secs(NOTIFICATION_CHECK_INTERVAL_SECS),
+            );
+            let mut last_count: Option<u32> = None;
+
+            loop {
+                ticker.tick().await;
+
+                if !sources::is_active(Source::Notifications) {
+                    sources::wait_active(Source::Notifications).await;
+                    ticker.reset();
+                    last_count = None;
+                }
+
+                let count = async_runtime::spawn_blocking(get_notification_count_sync)
+                    .await
+                    .ok()
+                    .flatten();
+
+                // Only emit if count changed
+                if let Some(count) = count {
+                    if last_count != Some(count) {
+                        last_count = Some(count);
+                        let _ = handle.emit(events::NOTIFICATION_COUNT_CHANGED, count);
+                    }
+                }
+            }
+        });
+    });
+
+    Ok(())
+}
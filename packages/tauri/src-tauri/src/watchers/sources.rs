@@ -0,0 +1,321 @@
+//! Watcher Activation
+//!
+//! Reference-counts the data sources widgets need, per window.
+//! Polling watchers park while their source has no consumers and resume
+//! (emitting fresh state immediately) when a window activates it again.
+//!
+//! Until some window calls `activate_sources`, every source counts as
+//! active so widgets that never declare their sources keep working.
+//! Event-driven watchers (battery, volume, active app) cost nothing while
+//! idle and are not gated.
+//!
+//! A source can also be boosted for a while (`request_fast_updates`), which
+//! drops its polling watcher to `FAST_UPDATE_INTERVAL` until the boost ends.
+//! The normal interval can be overridden from config (`set_interval`).
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, WebviewWindow};
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+/// Poll interval while a source is boosted
+const FAST_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Upper bound for a single boost so a forgotten request can't poll forever
+const MAX_BOOST_MS: u64 = 60_000;
+
+/// A data source backed by a watcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Source {
+    /// CPU, memory, GPU and system stats
+    /// (`cpu-changed`, `memory-changed`, `gpu-changed`, `system-stats-changed`)
+    System,
+    Network,
+    Media,
+    Battery,
+    Volume,
+    ActiveApp,
+    Aerospace,
+    Notifications,
+    Clipboard,
+    /// Focused window title (`active-window-title-changed`)
+    WindowTitle,
+}
+
+impl Source {
+    const ALL: [Source; 10] = [
+        Source::System,
+        Source::Network,
+        Source::Media,
+        Source::Battery,
+        Source::Volume,
+        Source::ActiveApp,
+        Source::Aerospace,
+        Source::Notifications,
+        Source::Clipboard,
+        Source::WindowTitle,
+    ];
+
+    /// Whether a polling watcher backs this source (only those can be boosted)
+    fn is_polled(self) -> bool {
+        !matches!(self, Source::Battery | Source::Volume | Source::ActiveApp)
+    }
+}
+
+#[derive(Default)]
+struct Activation {
+    /// Set once any window declares its sources
+    demand_driven: bool,
+    /// Window label -> sources that window needs
+    windows: HashMap<String, HashSet<Source>>,
+}
+
+impl Activation {
+    fn count(&self, source: Source) -> usize {
+        self.windows
+            .values()
+            .filter(|sources| sources.contains(&source))
+            .count()
+    }
+
+    fn is_active(&self, source: Source) -> bool {
+        !self.demand_driven || self.count(source) > 0
+    }
+}
+
+static ACTIVATION: Lazy<Mutex<Activation>> = Lazy::new(|| Mutex::new(Activation::default()));
+
+/// Source -> when its current boost ends
+static BOOSTS: Lazy<Mutex<HashMap<Source, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Source -> configured poll interval, replacing the watcher's default
+static INTERVALS: Lazy<Mutex<HashMap<Source, Duration>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Wakes waiting watchers when their source is activated, deactivated or boosted
+static WAKERS: Lazy<HashMap<Source, Notify>> =
+    Lazy::new(|| Source::ALL.iter().map(|s| (*s, Notify::new())).collect());
+
+/// Whether at least one consumer needs this source
+pub fn is_active(source: Source) -> bool {
+    ACTIVATION
+        .lock()
+        .map(|activation| activation.is_active(source))
+        .unwrap_or(true)
+}
+
+/// Wait until the source is active (returns immediately if it already is)
+pub async fn wait_active(source: Source) {
+    wait_until(source, true).await;
+}
+
+/// Wait until the source has no consumers left
+pub async fn wait_inactive(source: Source) {
+    wait_until(source, false).await;
+}
+
+async fn wait_until(source: Source, active: bool) {
+    let waker = &WAKERS[&source];
+    loop {
+        let notified = waker.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+
+        if is_active(source) == active {
+            return;
+        }
+        notified.await;
+    }
+}
+
+fn wake(sources: impl IntoIterator<Item = Source>) {
+    for source in sources {
+        WAKERS[&source].notify_waiters();
+    }
+}
+
+/// Add sources for a window
+pub fn activate(label: &str, sources: &[Source]) {
+    let Ok(mut activation) = ACTIVATION.lock() else {
+        return;
+    };
+
+    activation.demand_driven = true;
+    activation
+        .windows
+        .entry(label.to_string())
+        .or_default()
+        .extend(sources.iter().copied());
+
+    drop(activation);
+    wake(Source::ALL);
+}
+
+/// Remove sources for a window
+pub fn deactivate(label: &str, sources: &[Source]) {
+    let Ok(mut activation) = ACTIVATION.lock() else {
+        return;
+    };
+
+    if let Some(window_sources) = activation.windows.get_mut(label) {
+        for source in sources {
+            window_sources.remove(source);
+        }
+        if window_sources.is_empty() {
+            activation.windows.remove(label);
+        }
+    }
+
+    drop(activation);
+    wake(sources.iter().copied());
+}
+
+/// Release everything a window activated (called when it is destroyed)
+pub fn release_window(label: &str) {
+    let released = ACTIVATION
+        .lock()
+        .ok()
+        .and_then(|mut activation| activation.windows.remove(label));
+
+    if let Some(sources) = released {
+        wake(sources);
+    }
+}
+
+/// Poll faster for `duration`; overlapping boosts extend rather than shorten
+pub fn boost(source: Source, duration: Duration) {
+    let until = Instant::now() + duration;
+    let Ok(mut boosts) = BOOSTS.lock() else {
+        return;
+    };
+
+    let deadline = boosts.entry(source).or_insert(until);
+    *deadline = (*deadline).max(until);
+
+    drop(boosts);
+    wake([source]);
+}
+
+/// Override a polling watcher's interval (None restores its default)
+/// A watcher waiting for its next tick picks the change up immediately
+pub fn set_interval(source: Source, interval: Option<Duration>) {
+    let Ok(mut intervals) = INTERVALS.lock() else {
+        return;
+    };
+
+    match interval {
+        Some(interval) => intervals.insert(source, interval),
+        None => intervals.remove(&source),
+    };
+
+    drop(intervals);
+    wake([source]);
+}
+
+/// Interval a watcher should use right now
+fn current_interval(source: Source, normal: Duration) -> Duration {
+    let normal = INTERVALS
+        .lock()
+        .ok()
+        .and_then(|intervals| intervals.get(&source).copied())
+        .unwrap_or(normal);
+
+    let boosted = BOOSTS.lock().is_ok_and(|mut boosts| match boosts.get(&source) {
+        Some(until) if *until > Instant::now() => true,
+        Some(_) => {
+            boosts.remove(&source);
+            false
+        }
+        None => false,
+    });
+
+    if boosted {
+        normal.min(FAST_UPDATE_INTERVAL)
+    } else {
+        normal
+    }
+}
+
+/// Drop-in for `tokio::time::interval` that honours boosts
+///
+/// The first tick completes immediately; `reset` delays the next tick
+/// by a full period, matching `tokio::time::Interval`.
+pub struct Ticker {
+    source: Source,
+    normal: Duration,
+    last: Option<Instant>,
+}
+
+impl Ticker {
+    pub fn new(source: Source, normal: Duration) -> Self {
+        Ticker {
+            source,
+            normal,
+            last: None,
+        }
+    }
+
+    pub async fn tick(&mut self) {
+        if let Some(last) = self.last {
+            let waker = &WAKERS[&self.source];
+            loop {
+                let notified = waker.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                let deadline = last + current_interval(self.source, self.normal);
+                if deadline <= Instant::now() {
+                    break;
+                }
+
+                // A boost starting mid-wait shortens the deadline, so re-check
+                tokio::select! {
+                    _ = tokio::time::sleep_until(deadline) => break,
+                    _ = notified => {}
+                }
+            }
+        }
+
+        self.last = Some(Instant::now());
+    }
+
+    pub fn reset(&mut self) {
+        self.last = Some(Instant::now());
+    }
+}
+
+// ============================================
+// Source Commands
+// ============================================
+
+/// Declare the data sources the calling window needs
+#[command]
+pub fn activate_sources(window: WebviewWindow, sources: Vec<Source>) -> Result<(), String> {
+    activate(window.label(), &sources);
+    Ok(())
+}
+
+/// Stop needing data sources for the calling window
+#[command]
+pub fn deactivate_sources(window: WebviewWindow, sources: Vec<Source>) -> Result<(), String> {
+    deactivate(window.label(), &sources);
+    Ok(())
+}
+
+/// Temporarily poll a source every second (e.g. while a detail popover is open)
+/// Event-driven sources (battery, volume, active app) already update instantly
+#[command]
+pub fn request_fast_updates(source: Source, duration_ms: u64) -> Result<(), String> {
+    if !source.is_polled() {
+        return Err(format!(
+            "{:?} is event-driven and can't be polled faster",
+            source
+        ));
+    }
+    boost(source, Duration::from_millis(duration_ms.min(MAX_BOOST_MS)));
+    Ok(())
+}
@@ -1,17 +1,28 @@
 //! System Monitor Watcher
 //!
 //! Monitors CPU and Memory usage using a timer-based approach.
-//! Emits `cpu-changed` and `memory-changed` events at regular intervals.
+//! Emits `cpu-changed`, `memory-changed`, `system-stats-changed` and
+//! `gpu-changed` events at regular intervals, and records CPU, memory and
+//! network throughput samples in the metric history on each tick.
 
 use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::sync::{Mutex, Once};
-use std::time::Duration;
-use sysinfo::System;
+use std::time::{Duration, Instant};
+use sysinfo::{Networks, System};
 use tauri::{AppHandle, Emitter, async_runtime};
 
+use super::history::{self, Metric};
+use super::sources::{self, Source};
+use crate::commands::events;
+use crate::commands::gpu::read_gpu_info;
+use crate::commands::system::{memory_pressure, read_system_stats, MemoryPressure};
+
 static INIT: Once = Once::new();
 static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new()));
+/// Interface counters and when they were last refreshed
+static NETWORKS: Lazy<Mutex<(Networks, Instant)>> =
+    Lazy::new(|| Mutex::new((Networks::new_with_refreshed_list(), Instant::now())));
 
 // Interval for CPU/Memory monitoring (5 seconds)
 const MONITOR_INTERVAL_SECS: u64 = 5;
@@ -23,10 +34,14 @@ pub struct CpuEvent {
 }
 
 #[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MemoryEvent {
     pub total: u64,
     pub used: u64,
     pub usage: f32,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub pressure: MemoryPressure,
 }
 
 /// Register the system monitor watcher
@@ -35,19 +50,43 @@ pub fn register(app_handle: AppHandle) -> Result<(), String> {
         // Spawn a tokio task for periodic monitoring
         let handle = app_handle.clone();
         async_runtime::spawn(async move {
-            let mut ticker = tokio::time::interval(Duration::from_secs(MONITOR_INTERVAL_SECS));
+            let mut ticker =
+                sources::Ticker::new(Source::System, Duration::from_secs(MONITOR_INTERVAL_SECS));
 
             loop {
                 ticker.tick().await;
 
+                // Park while no widget needs CPU/memory data
+                if !sources::is_active(Source::System) {
+                    sources::wait_active(Source::System).await;
+                    ticker.reset();
+                }
+
                 // Get CPU info
                 if let Ok(cpu_event) = get_cpu_info() {
-                    let _ = handle.emit("cpu-changed", cpu_event);
+                    history::record(Metric::Cpu, cpu_event.usage as f64);
+                    let _ = handle.emit(events::CPU_CHANGED, cpu_event);
                 }
 
                 // Get Memory info
                 if let Ok(memory_event) = get_memory_info() {
-                    let _ = handle.emit("memory-changed", memory_event);
+                    history::record(Metric::Memory, memory_event.usage as f64);
+                    let _ = handle.emit(events::MEMORY_CHANGED, memory_event);
+                }
+
+                // Uptime, load average and process count
+                if let Ok(stats) = read_system_stats() {
+                    let _ = handle.emit(events::SYSTEM_STATS_CHANGED, stats);
+                }
+
+                let gpus = read_gpu_info();
+                if !gpus.is_empty() {
+                    let _ = handle.emit(events::GPU_CHANGED, gpus);
+                }
+
+                if let Ok((received, transmitted)) = get_network_throughput() {
+                    history::record(Metric::NetworkIn, received);
+                    history::record(Metric::NetworkOut, transmitted);
                 }
             }
         });
@@ -82,5 +121,35 @@ fn get_memory_info() -> Result<MemoryEvent, String> {
         0.0
     };
 
-    Ok(MemoryEvent { total, used, usage })
+    Ok(MemoryEvent {
+        total,
+        used,
+        usage,
+        swap_used: sys.used_swap(),
+        swap_total: sys.total_swap(),
+        pressure: memory_pressure(),
+    })
+}
+
+/// Bytes per second received and sent since the previous call,
+/// summed over every interface except loopback
+fn get_network_throughput() -> Result<(f64, f64), String> {
+    let mut guard = NETWORKS.lock().map_err(|e| format!("Lock error: {}", e))?;
+    let (networks, last_refresh) = &mut *guard;
+    networks.refresh_list();
+
+    let elapsed = last_refresh.elapsed().as_secs_f64();
+    *last_refresh = Instant::now();
+    if elapsed <= 0.0 {
+        return Err("No time elapsed since last sample".to_string());
+    }
+
+    let (received, transmitted) = networks
+        .iter()
+        .filter(|(name, _)| !name.starts_with("lo"))
+        .fold((0u64, 0u64), |(rx, tx), (_, data)| {
+            (rx + data.received(), tx + data.transmitted())
+        });
+
+    Ok((received as f64 / elapsed, transmitted as f64 / elapsed))
 }
@@ -0,0 +1,69 @@
+//! Thermal Watcher
+//!
+//! Monitors thermal pressure using NSProcessInfo notifications.
+//! Emits `thermal-changed` event with `nominal` / `fair` / `serious` / `critical`
+//! whenever the thermal state changes.
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{class, define_class, msg_send, sel, ClassType};
+use objc2_foundation::{NSNotification, NSNotificationCenter, NSNotificationName, NSObject, NSObjectProtocol};
+use std::sync::{Once, OnceLock};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::events;
+use crate::commands::thermal::read_thermal_state;
+
+static INIT: Once = Once::new();
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Register the thermal watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    INIT.call_once(|| {
+        // Store app handle for callback
+        let _ = APP_HANDLE.set(app_handle);
+
+        // Define observer class
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "ThermalStateObserver"]
+            #[ivars = ()]
+            struct ThermalStateObserver;
+
+            unsafe impl NSObjectProtocol for ThermalStateObserver {}
+
+            impl ThermalStateObserver {
+                #[unsafe(method(thermalStateDidChange:))]
+                fn thermal_state_did_change(&self, _notification: &NSNotification) {
+                    if let Some(handle) = APP_HANDLE.get() {
+                        let _ = handle.emit(events::THERMAL_CHANGED, read_thermal_state());
+                    }
+                }
+            }
+        );
+
+        // Create observer instance
+        let observer: Retained<ThermalStateObserver> =
+            unsafe { msg_send![ThermalStateObserver::class(), new] };
+
+        // Posted to the default center by the process info object
+        let notification_center = NSNotificationCenter::defaultCenter();
+        let notification_name =
+            NSNotificationName::from_str("NSProcessInfoThermalStateDidChangeNotification");
+
+        unsafe {
+            let process_info: *const AnyObject = msg_send![class!(NSProcessInfo), processInfo];
+            notification_center.addObserver_selector_name_object(
+                &*observer,
+                sel!(thermalStateDidChange:),
+                Some(&*notification_name),
+                process_info.as_ref(),
+            );
+        }
+
+        // Prevent observer from being deallocated
+        std::mem::forget(observer);
+    });
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 //! Emits `volume-changed` event when volume or mute state changes.
 
 use crate::commands::audio;
+use crate::commands::events;
 use coreaudio_sys::*;
 use serde::Serialize;
 use std::os::raw::c_void;
@@ -30,7 +31,7 @@ extern "C" fn volume_listener_callback(
 ) -> OSStatus {
     if let Some(handle) = APP_HANDLE.get() {
         let event = get_current_volume_info();
-        let _ = handle.emit("volume-changed", event);
+        let _ = handle.emit(events::VOLUME_CHANGED, event);
     }
     0 // noErr
 }
@@ -138,7 +139,7 @@ extern "C" fn device_changed_callback(
     // Emit volume changed event for the new device
     if let Some(handle) = APP_HANDLE.get() {
         let event = get_current_volume_info();
-        let _ = handle.emit("volume-changed", event);
+        let _ = handle.emit(events::VOLUME_CHANGED, event);
     }
 
     0 // noErr
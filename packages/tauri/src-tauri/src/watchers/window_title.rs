@@ -0,0 +1,56 @@
+//! Active Window Title Watcher
+//!
+//! Polls the frontmost app's focused window title and emits
+//! `active-window-title-changed` when the app, the focused window or its
+//! title changes. Titles need the Accessibility permission; without it the
+//! event still fires on app switches, with a null title.
+
+use super::sources::{self, Source};
+use crate::commands::events;
+use crate::commands::window_title::read_active_window_title;
+use std::sync::Once;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle, Emitter};
+
+static INIT: Once = Once::new();
+
+// Check interval (an AX attribute read is cheap, and titles should feel live)
+const WINDOW_TITLE_CHECK_INTERVAL_MS: u64 = 500;
+
+/// Register the window title watcher
+pub fn register(app_handle: AppHandle) -> Result<(), String> {
+    INIT.call_once(|| {
+        let handle = app_handle.clone();
+        async_runtime::spawn(async move {
+            let mut ticker = sources::Ticker::new(
+                Source::WindowTitle,
+                Duration::from_millis(WINDOW_TITLE_CHECK_INTERVAL_MS),
+            );
+            let mut last = None;
+
+            loop {
+                ticker.tick().await;
+
+                if !sources::is_active(Source::WindowTitle) {
+                    sources::wait_active(Source::WindowTitle).await;
+                    ticker.reset();
+                    last = None;
+                }
+
+                // AX calls block until the target app answers
+                let Ok(current) = async_runtime::spawn_blocking(read_active_window_title).await
+                else {
+                    continue;
+                };
+
+                // Only emit if app or title changed
+                if last.as_ref() != Some(&current) {
+                    let _ = handle.emit(events::ACTIVE_WINDOW_TITLE_CHANGED, &current);
+                    last = Some(current);
+                }
+            }
+        });
+    });
+
+    Ok(())
+}
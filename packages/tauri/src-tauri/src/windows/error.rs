@@ -0,0 +1,60 @@
+//! Window Creation Errors
+//!
+//! Serialized as `{ kind, ... }` so the frontend can tell failure modes
+//! apart (e.g. prompt to pick another monitor vs. rebuild the UI).
+
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum WindowCreateError {
+    /// A window with this label is already open
+    AlreadyExists { label: String },
+    /// Position config is incomplete or contradictory
+    InvalidPosition { reason: String },
+    /// `minSize` exceeds `maxSize`
+    InvalidSizeConstraints { reason: String },
+    /// Window URL could not be parsed
+    InvalidUrl { url: String, reason: String },
+    /// `position.monitor` names a monitor that isn't connected
+    MonitorNotFound { name: String },
+    /// Tauri failed to build or show the window
+    Build { reason: String },
+}
+
+impl WindowCreateError {
+    pub fn build(reason: impl fmt::Display) -> Self {
+        WindowCreateError::Build {
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WindowCreateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowCreateError::AlreadyExists { label } => {
+                write!(f, "Inline window '{}' already exists", label)
+            }
+            WindowCreateError::InvalidPosition { reason } => write!(f, "{}", reason),
+            WindowCreateError::InvalidSizeConstraints { reason } => write!(f, "{}", reason),
+            WindowCreateError::InvalidUrl { url, reason } => {
+                write!(f, "Invalid URL '{}': {}", url, reason)
+            }
+            WindowCreateError::MonitorNotFound { name } => {
+                write!(f, "Monitor '{}' not found", name)
+            }
+            WindowCreateError::Build { reason } => write!(f, "Failed to create window: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for WindowCreateError {}
+
+/// Commands outside the creation path still return string errors
+impl From<WindowCreateError> for String {
+    fn from(error: WindowCreateError) -> Self {
+        error.to_string()
+    }
+}
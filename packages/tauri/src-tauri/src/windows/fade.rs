@@ -0,0 +1,150 @@
+//! Fade Transitions
+//!
+//! Shows or hides a window by animating `NSWindow.alphaValue` through
+//! `NSAnimationContext`. A hidden window's alpha is restored to 1 after it
+//! is ordered out, so a later plain `show_window` isn't invisible.
+
+use std::time::Duration;
+use tauri::{command, AppHandle, Manager, WebviewWindow};
+
+use super::manager::{apply_ignore_cursor_events, resolve_labels};
+
+/// Default fade duration
+const DEFAULT_FADE_MS: u64 = 200;
+
+/// Upper bound so a typo can't leave a window half-faded for minutes
+const MAX_FADE_MS: u64 = 5000;
+
+/// Extra wait for the completion handler beyond the animation itself
+const COMPLETION_GRACE: Duration = Duration::from_millis(500);
+
+/// Animate the window's alpha to `target` and wait for it to finish
+#[cfg(target_os = "macos")]
+async fn animate_alpha(
+    window: &WebviewWindow,
+    target: f64,
+    duration: Duration,
+) -> Result<(), String> {
+    use std::sync::Arc;
+    use tokio::sync::Notify;
+
+    let finished = Arc::new(Notify::new());
+    let on_finish = finished.clone();
+    let seconds = duration.as_secs_f64();
+
+    window
+        .with_webview(move |webview| unsafe {
+            use block2::RcBlock;
+            use objc2::runtime::AnyObject;
+            use objc2::{class, msg_send};
+
+            let ns_window = webview.ns_window() as *const AnyObject;
+            if ns_window.is_null() {
+                on_finish.notify_one();
+                return;
+            }
+
+            let changes = RcBlock::new(move |context: *mut AnyObject| {
+                let _: () = msg_send![context, setDuration: seconds];
+                let animator: *const AnyObject = msg_send![ns_window, animator];
+                let _: () = msg_send![animator, setAlphaValue: target];
+            });
+            let on_finish = on_finish.clone();
+            let completion = RcBlock::new(move || on_finish.notify_one());
+
+            let _: () = msg_send![
+                class!(NSAnimationContext),
+                runAnimationGroup: &*changes,
+                completionHandler: &*completion
+            ];
+        })
+        .map_err(|e| format!("Failed to animate window: {}", e))?;
+
+    let _ = tokio::time::timeout(duration + COMPLETION_GRACE, finished.notified()).await;
+    Ok(())
+}
+
+/// Set the window's alpha immediately
+#[cfg(target_os = "macos")]
+fn set_alpha(window: &WebviewWindow, alpha: f64) -> Result<(), String> {
+    window
+        .with_webview(move |webview| unsafe {
+            use objc2::msg_send;
+            use objc2::runtime::AnyObject;
+
+            let ns_window = webview.ns_window() as *const AnyObject;
+            if !ns_window.is_null() {
+                let _: () = msg_send![ns_window, setAlphaValue: alpha];
+            }
+        })
+        .map_err(|e| format!("Failed to set window alpha: {}", e))
+}
+
+async fn fade(window: &WebviewWindow, visible: bool, duration: Duration) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        if visible {
+            set_alpha(window, 0.0)?;
+            window.show().map_err(|e| e.to_string())?;
+            animate_alpha(window, 1.0, duration).await
+        } else {
+            animate_alpha(window, 0.0, duration).await?;
+            window.hide().map_err(|e| e.to_string())?;
+            set_alpha(window, 1.0)
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = duration;
+        if visible {
+            window.show().map_err(|e| e.to_string())
+        } else {
+            window.hide().map_err(|e| e.to_string())
+        }
+    }
+}
+
+// ============================================
+// Fade Commands
+// ============================================
+
+/// Show or hide a window with a fade
+/// `ignore_cursor_events` optionally sets click-through (independent of visibility)
+#[command]
+pub async fn fade_window(
+    app: AppHandle,
+    label: String,
+    visible: bool,
+    duration_ms: Option<u64>,
+    ignore_cursor_events: Option<bool>,
+) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    let duration = Duration::from_millis(duration_ms.unwrap_or(DEFAULT_FADE_MS).min(MAX_FADE_MS));
+    let windows: Vec<WebviewWindow> = labels
+        .iter()
+        .filter_map(|target| app.get_webview_window(target))
+        .collect();
+
+    for window in &windows {
+        apply_ignore_cursor_events(window, ignore_cursor_events)?;
+    }
+
+    // Fade per-monitor instances together
+    let fades: Vec<_> = windows
+        .into_iter()
+        .map(|window| {
+            tauri::async_runtime::spawn(async move { fade(&window, visible, duration).await })
+        })
+        .collect();
+
+    for handle in fades {
+        handle.await.map_err(|e| format!("Task join error: {}", e))??;
+    }
+
+    Ok(())
+}
@@ -2,24 +2,40 @@
 //!
 //! Automatically focuses windows when the cursor enters their bounds.
 //! Uses NSEvent global monitoring for mouse movement detection.
+//! Focus is applied only after the cursor dwells on a window for
+//! `settings.hoverFocusDelayMs`, so quick passes don't grab focus.
 
 use once_cell::sync::OnceCell;
 use std::sync::Mutex;
 use tauri::{AppHandle, Manager, WebviewWindow};
 
+use crate::commands::config::get_config;
+
 static STATE: OnceCell<Mutex<HoverFocusState>> = OnceCell::new();
 
 struct HoverFocusState {
     app_handle: Option<AppHandle>,
     last_focused_label: Option<String>,
+    /// Window the cursor is dwelling on, waiting for the delay to elapse
+    pending_label: Option<String>,
+    /// Incremented on every new pending window to invalidate stale timers
+    pending_generation: u64,
+    delay_ms: u64,
 }
 
 /// Initialize the hover focus system
 pub fn init(app_handle: AppHandle) {
+    let delay_ms = get_config()
+        .map(|config| config.settings.hover_focus_delay_ms)
+        .unwrap_or(120);
+
     STATE.get_or_init(|| {
         Mutex::new(HoverFocusState {
             app_handle: Some(app_handle),
             last_focused_label: None,
+            pending_label: None,
+            pending_generation: 0,
+            delay_ms,
         })
     });
 
@@ -90,6 +106,57 @@ fn focus_window(label: &str) {
     }
 }
 
+/// Schedule focus for a window once the cursor has dwelled on it
+#[cfg(target_os = "macos")]
+fn schedule_focus(label: String) {
+    let (generation, delay_ms) = {
+        let Some(state_lock) = STATE.get() else {
+            return;
+        };
+        let Ok(mut state) = state_lock.lock() else {
+            return;
+        };
+
+        // Back on the focused window: cancel any pending focus elsewhere
+        if state.last_focused_label.as_ref() == Some(&label) {
+            state.pending_label = None;
+            return;
+        }
+
+        // Already waiting on this window
+        if state.pending_label.as_ref() == Some(&label) {
+            return;
+        }
+
+        state.pending_generation += 1;
+        state.pending_label = Some(label.clone());
+        (state.pending_generation, state.delay_ms)
+    };
+
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+
+        // Only focus if the cursor is still over the same window
+        {
+            let Some(state_lock) = STATE.get() else {
+                return;
+            };
+            let Ok(mut state) = state_lock.lock() else {
+                return;
+            };
+            if state.pending_generation != generation
+                || state.pending_label.as_ref() != Some(&label)
+            {
+                return;
+            }
+            state.pending_label = None;
+            state.last_focused_label = Some(label.clone());
+        }
+
+        focus_window(&label);
+    });
+}
+
 /// Start the global mouse monitor (macOS only)
 #[cfg(target_os = "macos")]
 fn start_mouse_monitor() {
@@ -136,28 +203,14 @@ fn start_mouse_monitor() {
 
                     // Check which window is under cursor
                     if let Some(label) = get_window_at_point(x, y) {
-                        // Get last focused label
-                        let last_label = STATE
-                            .get()
-                            .and_then(|s| s.lock().ok())
-                            .and_then(|s| s.last_focused_label.clone());
-
-                        // Only focus if it's a different window
-                        if last_label.as_ref() != Some(&label) {
-                            focus_window(&label);
-
-                            // Update last focused
-                            if let Some(state_lock) = STATE.get() {
-                                if let Ok(mut state) = state_lock.lock() {
-                                    state.last_focused_label = Some(label);
-                                }
-                            }
-                        }
+                        // Focus after the dwell delay (no-op if already focused)
+                        schedule_focus(label);
                     } else {
-                        // Cursor not over any window, clear last focused
+                        // Cursor not over any window, clear last focused and cancel pending focus
                         if let Some(state_lock) = STATE.get() {
                             if let Ok(mut state) = state_lock.lock() {
                                 state.last_focused_label = None;
+                                state.pending_label = None;
                             }
                         }
                     }
@@ -0,0 +1,68 @@
+//! Key Window Tracking
+//!
+//! Reports which Fluopanel window currently has keyboard focus (is key).
+//! `handle_focus_event` is called from the app-level window event handler
+//! and emits `arcana-focus-changed` with the new label (or null) on change.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, Manager};
+
+use crate::commands::events;
+
+/// Label of the last reported key window
+static LAST_KEY_WINDOW: Mutex<Option<String>> = Mutex::new(None);
+
+/// How long to wait before reporting that none of our windows is key.
+/// Switching between two Fluopanel windows blurs one before focusing the
+/// other, and the gap shouldn't surface as a null event.
+const BLUR_SETTLE_DELAY: Duration = Duration::from_millis(50);
+
+/// Label of the window that is currently key, if it belongs to us
+pub fn key_window_label(app: &AppHandle) -> Option<String> {
+    app.webview_windows()
+        .into_iter()
+        .find(|(_, window)| window.is_focused().unwrap_or(false))
+        .map(|(label, _)| label)
+}
+
+/// Re-check the key window after a focus event and emit if it changed
+pub fn handle_focus_event(app: &AppHandle) {
+    if key_window_label(app).is_some() {
+        report_key_window(app);
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(BLUR_SETTLE_DELAY).await;
+        report_key_window(&app);
+    });
+}
+
+fn report_key_window(app: &AppHandle) {
+    let current = key_window_label(app);
+
+    let Ok(mut last) = LAST_KEY_WINDOW.lock() else {
+        return;
+    };
+    if *last == current {
+        return;
+    }
+    *last = current.clone();
+    drop(last);
+
+    if let Err(e) = app.emit(events::ARCANA_FOCUS_CHANGED, current) {
+        eprintln!("[window] Failed to emit arcana-focus-changed event: {}", e);
+    }
+}
+
+// ============================================
+// Key Window Commands
+// ============================================
+
+/// Label of the Fluopanel window with keyboard focus (None if another app is focused)
+#[command]
+pub fn get_focused_window(app: AppHandle) -> Option<String> {
+    key_window_label(&app)
+}
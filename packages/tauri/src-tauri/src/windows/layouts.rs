@@ -0,0 +1,244 @@
+//! Layout Presets
+//!
+//! A preset is a snapshot of every inline window (its creation options and
+//! current geometry) stored at `~/.config/fluopanel/layouts/{name}.json`.
+//! Applying one closes the current inline windows and recreates the saved
+//! set. Single windows are saved at their current on-screen geometry;
+//! per-monitor windows keep their relative position config.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::{command, AppHandle, Manager, WebviewWindow};
+
+use super::length::Length;
+use super::manager::{
+    close_window, create_from_definition, get_monitor_info, resolve_labels,
+    InlineWindowDefinition, InlineWindowOptions, WindowPosition, INLINE_WINDOWS,
+};
+use crate::commands::config::get_config_dir;
+use crate::commands::helpers::wait_for_screens;
+
+/// How long to wait for a closed window's label to be released
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(2);
+const CLOSE_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutPreset {
+    pub name: String,
+    pub windows: Vec<PresetWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetWindow {
+    pub window_id: String,
+    #[serde(flatten)]
+    options: InlineWindowOptions,
+    pub position: WindowPosition,
+    pub per_monitor: bool,
+    pub visible: bool,
+}
+
+fn layouts_dir() -> PathBuf {
+    get_config_dir().join("layouts")
+}
+
+/// Path of a preset file, rejecting names that would escape the layouts dir
+fn preset_path(name: &str) -> Result<PathBuf, String> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ' ' | '.'))
+        && !name.starts_with('.');
+    if !valid {
+        return Err(format!("Invalid layout preset name '{}'", name));
+    }
+    Ok(layouts_dir().join(format!("{}.json", name)))
+}
+
+/// Position config that reproduces a window's current frame
+fn current_position(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    saved: &WindowPosition,
+) -> Option<WindowPosition> {
+    let monitor_name = window.current_monitor().ok()??.name().cloned();
+    let (monitor_x, monitor_y, _, _) = get_monitor_info(app, monitor_name.as_deref()).ok()?;
+
+    let scale = window.scale_factor().ok()?;
+    let position = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.inner_size().ok()?.to_logical::<f64>(scale);
+
+    Some(WindowPosition {
+        monitor: monitor_name,
+        top: Some(Length::from(position.y.round() as i32 - monitor_y)),
+        bottom: None,
+        left: Some(Length::from(position.x.round() as i32 - monitor_x)),
+        right: None,
+        width: Some(Length::from(size.width.round() as i32)),
+        height: Some(Length::from(size.height.round() as i32)),
+        min_visible: saved.min_visible,
+    })
+}
+
+/// Snapshot every open inline window
+fn capture_windows(app: &AppHandle) -> Result<Vec<PresetWindow>, String> {
+    let definitions = INLINE_WINDOWS.lock().map_err(|e| e.to_string())?.clone();
+    let mut windows = Vec::new();
+
+    for (label, definition) in definitions {
+        let instances: Vec<WebviewWindow> = resolve_labels(app, &label)
+            .iter()
+            .filter_map(|instance| app.get_webview_window(instance))
+            .collect();
+        if instances.is_empty() {
+            continue;
+        }
+
+        let position = if definition.per_monitor {
+            definition.position.clone()
+        } else {
+            current_position(app, &instances[0], &definition.position)
+                .unwrap_or_else(|| definition.position.clone())
+        };
+
+        windows.push(PresetWindow {
+            window_id: definition.title.clone(),
+            options: definition.options.clone(),
+            position,
+            per_monitor: definition.per_monitor,
+            visible: instances.iter().any(|w| w.is_visible().unwrap_or(false)),
+        });
+    }
+
+    windows.sort_by(|a, b| a.window_id.cmp(&b.window_id));
+    Ok(windows)
+}
+
+/// Close every inline window and wait until their labels are free
+async fn close_inline_windows(app: &AppHandle) -> Result<(), String> {
+    let mut labels: Vec<String> = INLINE_WINDOWS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .keys()
+        .cloned()
+        .collect();
+    labels.extend(
+        app.webview_windows()
+            .keys()
+            .filter(|label| label.starts_with("inline-window-"))
+            .cloned(),
+    );
+    labels.sort();
+    labels.dedup();
+
+    for label in labels {
+        if let Err(e) = close_window(app.clone(), label.clone()) {
+            eprintln!("[layouts] Failed to close '{}': {}", label, e);
+        }
+    }
+
+    let started = std::time::Instant::now();
+    while app
+        .webview_windows()
+        .keys()
+        .any(|label| label.starts_with("inline-window-"))
+    {
+        if started.elapsed() >= CLOSE_TIMEOUT {
+            return Err("Timed out closing current windows".to_string());
+        }
+        tokio::time::sleep(CLOSE_POLL_INTERVAL).await;
+    }
+
+    Ok(())
+}
+
+// ============================================
+// Layout Preset Commands
+// ============================================
+
+/// Save the open inline windows as a named preset
+#[command]
+pub fn save_layout_preset(app: AppHandle, name: String) -> Result<LayoutPreset, String> {
+    let path = preset_path(&name)?;
+    let preset = LayoutPreset {
+        name,
+        windows: capture_windows(&app)?,
+    };
+
+    std::fs::create_dir_all(layouts_dir())
+        .map_err(|e| format!("Failed to create layouts directory: {}", e))?;
+    let json = serde_json::to_string_pretty(&preset)
+        .map_err(|e| format!("Failed to serialize layout preset: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write layout preset: {}", e))?;
+
+    Ok(preset)
+}
+
+/// Names of the saved layout presets
+#[command]
+pub fn list_layout_presets() -> Result<Vec<String>, String> {
+    let entries = match std::fs::read_dir(layouts_dir()) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read layouts directory: {}", e)),
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+
+    Ok(names)
+}
+
+/// Replace the open inline windows with the ones saved in a preset
+#[command]
+pub async fn apply_layout_preset(app: AppHandle, name: String) -> Result<(), String> {
+    let path = preset_path(&name)?;
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read layout preset '{}': {}", name, e))?;
+    let preset: LayoutPreset = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse layout preset '{}': {}", name, e))?;
+
+    close_inline_windows(&app).await?;
+    wait_for_screens(&app).await;
+
+    let mut failed = Vec::new();
+    for window in preset.windows {
+        let label = format!("inline-window-{}", window.window_id);
+        let definition = InlineWindowDefinition {
+            title: window.window_id.clone(),
+            options: window.options,
+            position: window.position,
+            per_monitor: window.per_monitor,
+        };
+
+        let created = create_from_definition(&app, &label, definition).map_err(String::from);
+        let shown = created.and_then(|_| {
+            if !window.visible {
+                return Ok(());
+            }
+            resolve_labels(&app, &label)
+                .iter()
+                .filter_map(|instance| app.get_webview_window(instance))
+                .try_for_each(|w| w.show().map_err(|e| e.to_string()))
+        });
+
+        if let Err(e) = shown {
+            eprintln!("[layouts] Failed to restore '{}': {}", label, e);
+            failed.push(window.window_id);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Failed to restore windows: {}", failed.join(", ")))
+    }
+}
@@ -0,0 +1,145 @@
+//! Position Lengths
+//!
+//! A `WindowPosition` edge or size is either a number of logical pixels or
+//! a string resolved against the monitor dimension on that axis:
+//! `"120"` (pixels), `"50%"`, or `"100%-20"` / `"25%+8"` (percent plus a
+//! pixel offset). Strings are parsed when the position is validated so a
+//! malformed value is reported as an invalid position.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Length {
+    Pixels(i32),
+    Expression(String),
+}
+
+/// Parsed form: `percent` of the monitor dimension plus `offset` pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Parsed {
+    percent: f64,
+    offset: i32,
+}
+
+fn parse_pixels(value: &str) -> Option<i32> {
+    value.parse::<f64>().ok().filter(|v| v.is_finite()).map(|v| v.round() as i32)
+}
+
+fn parse_expression(expression: &str) -> Result<Parsed, String> {
+    let compact: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
+    let invalid = || {
+        format!(
+            "invalid value '{}' (expected pixels like \"120\", a percentage like \"50%\", or \"100%-20\")",
+            expression
+        )
+    };
+
+    let Some((percent, rest)) = compact.split_once('%') else {
+        let offset = parse_pixels(&compact).ok_or_else(invalid)?;
+        return Ok(Parsed { percent: 0.0, offset });
+    };
+
+    let percent = percent
+        .parse::<f64>()
+        .ok()
+        .filter(|p| p.is_finite())
+        .ok_or_else(invalid)?;
+    // The offset after the operator must be unsigned ("100%--20" is malformed)
+    let unsigned_pixels = |value: &str| {
+        value
+            .starts_with(|c: char| c.is_ascii_digit() || c == '.')
+            .then(|| parse_pixels(value))
+            .flatten()
+    };
+    let offset = match rest.chars().next() {
+        None => 0,
+        Some('+') => unsigned_pixels(&rest[1..]).ok_or_else(invalid)?,
+        Some('-') => -unsigned_pixels(&rest[1..]).ok_or_else(invalid)?,
+        Some(_) => return Err(invalid()),
+    };
+
+    Ok(Parsed { percent, offset })
+}
+
+impl Length {
+    fn parse(&self) -> Result<Parsed, String> {
+        match self {
+            Length::Pixels(px) => Ok(Parsed {
+                percent: 0.0,
+                offset: *px,
+            }),
+            Length::Expression(expression) => parse_expression(expression),
+        }
+    }
+
+    /// Check the value parses, for `validate_position`
+    pub fn validate(&self) -> Result<(), String> {
+        self.parse().map(|_| ())
+    }
+
+    /// Resolve to pixels against the monitor dimension on this axis
+    /// (malformed values resolve to 0; positions are validated first)
+    pub fn resolve(&self, extent: u32) -> i32 {
+        self.parse()
+            .map(|parsed| (extent as f64 * parsed.percent / 100.0).round() as i32 + parsed.offset)
+            .unwrap_or(0)
+    }
+}
+
+impl From<i32> for Length {
+    fn from(px: i32) -> Self {
+        Length::Pixels(px)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Length;
+
+    fn expr(value: &str) -> Length {
+        Length::Expression(value.to_string())
+    }
+
+    #[test]
+    fn resolves_pixels() {
+        assert_eq!(Length::Pixels(120).resolve(1440), 120);
+        assert_eq!(Length::Pixels(-8).resolve(1440), -8);
+        assert_eq!(expr("120").resolve(1440), 120);
+        assert_eq!(expr(" 12.6 ").resolve(1440), 13);
+        assert_eq!(expr("-20").resolve(1440), -20);
+    }
+
+    #[test]
+    fn resolves_percentages() {
+        assert_eq!(expr("50%").resolve(1440), 720);
+        assert_eq!(expr("100%").resolve(900), 900);
+        assert_eq!(expr("33.3%").resolve(900), 300);
+    }
+
+    #[test]
+    fn resolves_percent_with_offset() {
+        assert_eq!(expr("100%-20").resolve(1440), 1420);
+        assert_eq!(expr("25%+8").resolve(1440), 368);
+        assert_eq!(expr("50% - 10").resolve(1000), 490);
+    }
+
+    #[test]
+    fn rejects_malformed_values() {
+        for value in [
+            "", "abc", "50%%", "%", "50%20", "100%--20", "100%+-20", "100%-+20", "100%-", "100%+",
+            "inf", "NaN%", "50px",
+        ] {
+            assert!(
+                expr(value).validate().is_err(),
+                "'{}' should be invalid",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_values_resolve_to_zero() {
+        assert_eq!(expr("100%--20").resolve(1440), 0);
+    }
+}
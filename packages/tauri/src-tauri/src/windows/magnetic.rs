@@ -0,0 +1,272 @@
+//! Magnetic Window Edges
+//!
+//! While a magnetic window is moved, its edges snap to the edges of other
+//! Fluopanel windows and of its monitor when they come within the threshold.
+//! Snapping happens in the `Moved` handler; the resulting `set_position`
+//! fires another `Moved` with a zero offset, which ends the loop.
+
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Manager, WebviewWindow, WindowEvent};
+
+/// Default snap distance in logical pixels
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Magnetic window label -> snap threshold
+static MAGNETIC: Lazy<Mutex<HashMap<String, f64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Windows whose Moved handler is installed (handlers can't be removed)
+static HOOKED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Rectangle in logical pixels
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Rect {
+    fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Whether two spans overlap (or nearly touch) along one axis
+    fn spans_overlap(start: f64, end: f64, other_start: f64, other_end: f64, slack: f64) -> bool {
+        start <= other_end + slack && other_start <= end + slack
+    }
+}
+
+fn window_rect(window: &WebviewWindow) -> Option<Rect> {
+    let scale = window.scale_factor().ok()?;
+    let position = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.outer_size().ok()?.to_logical::<f64>(scale);
+    Some(Rect {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    })
+}
+
+fn monitor_rect(window: &WebviewWindow) -> Option<Rect> {
+    let monitor = window.current_monitor().ok()??;
+    let scale = monitor.scale_factor();
+    let position = monitor.position().to_logical::<f64>(scale);
+    let size = monitor.size().to_logical::<f64>(scale);
+    Some(Rect {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    })
+}
+
+/// Smallest adjustment (within threshold) that aligns one of `edges` with one of `targets`
+fn nearest_offset(edges: [f64; 2], targets: &[f64], threshold: f64) -> f64 {
+    let mut best = 0.0;
+    let mut best_distance = f64::INFINITY;
+
+    for edge in edges {
+        for target in targets {
+            let offset = target - edge;
+            if offset.abs() <= threshold && offset.abs() < best_distance {
+                best = offset;
+                best_distance = offset.abs();
+            }
+        }
+    }
+
+    best
+}
+
+/// Offset to apply to `rect` so its edges snap to `others` and the screen
+fn snap_offset(rect: Rect, others: &[Rect], screen: Option<Rect>, threshold: f64) -> (f64, f64) {
+    let mut x_targets = Vec::new();
+    let mut y_targets = Vec::new();
+
+    if let Some(screen) = screen {
+        x_targets.extend([screen.x, screen.right()]);
+        y_targets.extend([screen.y, screen.bottom()]);
+    }
+
+    for other in others {
+        // Only snap sideways to windows that are beside us vertically, and vice versa
+        if Rect::spans_overlap(rect.y, rect.bottom(), other.y, other.bottom(), threshold) {
+            x_targets.extend([other.x, other.right()]);
+        }
+        if Rect::spans_overlap(rect.x, rect.right(), other.x, other.right(), threshold) {
+            y_targets.extend([other.y, other.bottom()]);
+        }
+    }
+
+    (
+        nearest_offset([rect.x, rect.right()], &x_targets, threshold),
+        nearest_offset([rect.y, rect.bottom()], &y_targets, threshold),
+    )
+}
+
+/// Snap a magnetic window after it moved
+fn snap_window(app: &AppHandle, label: &str) {
+    let Some(threshold) = MAGNETIC.lock().ok().and_then(|m| m.get(label).copied()) else {
+        return;
+    };
+    let Some(window) = app.get_webview_window(label) else {
+        return;
+    };
+    let Some(rect) = window_rect(&window) else {
+        return;
+    };
+
+    let others: Vec<Rect> = app
+        .webview_windows()
+        .iter()
+        .filter(|(other_label, _)| *other_label != label && !other_label.starts_with("popover-"))
+        .filter(|(_, other)| other.is_visible().unwrap_or(false))
+        .filter_map(|(_, other)| window_rect(other))
+        .collect();
+
+    let (dx, dy) = snap_offset(rect, &others, monitor_rect(&window), threshold);
+    if dx == 0.0 && dy == 0.0 {
+        return;
+    }
+
+    if let Err(e) = window.set_position(tauri::Position::Logical(tauri::LogicalPosition {
+        x: rect.x + dx,
+        y: rect.y + dy,
+    })) {
+        eprintln!("[magnetic] Failed to snap '{}': {}", label, e);
+    }
+}
+
+/// Install the Moved handler for a window once
+fn hook_window(app: &AppHandle, window: &WebviewWindow) {
+    let label = window.label().to_string();
+    let newly_hooked = HOOKED
+        .lock()
+        .map(|mut hooked| hooked.insert(label.clone()))
+        .unwrap_or(false);
+    if !newly_hooked {
+        return;
+    }
+
+    let app = app.clone();
+    window.on_window_event(move |event| match event {
+        WindowEvent::Moved(_) => snap_window(&app, &label),
+        WindowEvent::Destroyed => {
+            if let Ok(mut magnetic) = MAGNETIC.lock() {
+                magnetic.remove(&label);
+            }
+            if let Ok(mut hooked) = HOOKED.lock() {
+                hooked.remove(&label);
+            }
+        }
+        _ => {}
+    });
+}
+
+// ============================================
+// Magnetic Commands
+// ============================================
+
+/// Make a window's edges snap to other windows and screen edges while it is moved
+#[command]
+pub fn set_window_magnetic(
+    app: AppHandle,
+    label: String,
+    enabled: bool,
+    threshold: Option<f64>,
+) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("Window '{}' not found", label))?;
+
+    let mut magnetic = MAGNETIC.lock().map_err(|e| e.to_string())?;
+    if enabled {
+        magnetic.insert(label, threshold.unwrap_or(DEFAULT_THRESHOLD).max(0.0));
+        drop(magnetic);
+        hook_window(&app, &window);
+    } else {
+        magnetic.remove(&label);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nearest_offset, snap_offset, Rect};
+
+    const THRESHOLD: f64 = 10.0;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn snaps_within_the_threshold() {
+        assert_eq!(nearest_offset([95.0, 195.0], &[100.0], THRESHOLD), 5.0);
+        assert_eq!(nearest_offset([0.0, 108.0], &[100.0], THRESHOLD), -8.0);
+        assert_eq!(nearest_offset([90.0, 190.0], &[100.0], THRESHOLD), 10.0);
+    }
+
+    #[test]
+    fn ignores_edges_outside_the_threshold() {
+        assert_eq!(nearest_offset([80.0, 180.0], &[100.0], THRESHOLD), 0.0);
+        assert_eq!(nearest_offset([0.0, 50.0], &[], THRESHOLD), 0.0);
+    }
+
+    #[test]
+    fn picks_the_closest_target() {
+        assert_eq!(
+            nearest_offset([97.0, 197.0], &[105.0, 100.0], THRESHOLD),
+            3.0
+        );
+    }
+
+    #[test]
+    fn ties_keep_the_first_candidate() {
+        // Leading edge is 5 past one target and the trailing edge 5 short of another
+        assert_eq!(
+            nearest_offset([105.0, 195.0], &[100.0, 200.0], THRESHOLD),
+            -5.0
+        );
+        // Same edge, targets on either side
+        assert_eq!(
+            nearest_offset([100.0, 300.0], &[105.0, 95.0], THRESHOLD),
+            5.0
+        );
+    }
+
+    #[test]
+    fn snaps_to_screen_edges() {
+        let screen = rect(0.0, 0.0, 1440.0, 900.0);
+        let window = rect(6.0, 893.0 - 100.0, 200.0, 100.0);
+        assert_eq!(
+            snap_offset(window, &[], Some(screen), THRESHOLD),
+            (-6.0, 7.0)
+        );
+    }
+
+    #[test]
+    fn only_snaps_sideways_to_windows_beside_it() {
+        let other = rect(300.0, 0.0, 200.0, 100.0);
+        // Beside the other window: the right edge snaps to its left edge
+        let beside = rect(95.0, 20.0, 200.0, 50.0);
+        assert_eq!(snap_offset(beside, &[other], None, THRESHOLD), (5.0, 0.0));
+        // Far below it: no horizontal snap
+        let below = rect(95.0, 400.0, 200.0, 50.0);
+        assert_eq!(snap_offset(below, &[other], None, THRESHOLD), (0.0, 0.0));
+    }
+}
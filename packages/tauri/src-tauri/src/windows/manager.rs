@@ -1,22 +1,38 @@
-use serde::Deserialize;
-use tauri::{command, AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+
+use super::error::WindowCreateError;
+use super::length::Length;
+use super::monitors::MonitorChange;
+use crate::commands::constants::geometry::DEFAULT_MIN_VISIBLE;
+use crate::commands::constants::screen::FALLBACK_SCREEN_FRAME;
+use crate::commands::helpers::wait_for_screens;
+use crate::commands::window::{list_monitors, MonitorInfo, SizeConstraints};
 
 #[cfg(target_os = "macos")]
 
 /// Window position configuration (bounding box)
-#[derive(Debug, Clone, Deserialize)]
+/// Edges and sizes are pixels or monitor-relative expressions (see `Length`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WindowPosition {
     pub monitor: Option<String>,
-    pub top: Option<i32>,
-    pub bottom: Option<i32>,
-    pub left: Option<i32>,
-    pub right: Option<i32>,
-    pub width: Option<u32>,
-    pub height: Option<u32>,
+    pub top: Option<Length>,
+    pub bottom: Option<Length>,
+    pub left: Option<Length>,
+    pub right: Option<Length>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    /// Share (0-1) of the window that must stay inside the monitor's
+    /// visible frame; 0 disables the clamp
+    pub min_visible: Option<f64>,
 }
 
 /// Calculated window geometry
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct WindowGeometry {
     x: i32,
     y: i32,
@@ -25,9 +41,25 @@ struct WindowGeometry {
 }
 
 /// Validate position configuration
-fn validate_position(position: &WindowPosition) -> Result<(), String> {
+fn validate_position(position: &WindowPosition) -> Result<(), WindowCreateError> {
+    let fields = [
+        ("top", &position.top),
+        ("bottom", &position.bottom),
+        ("left", &position.left),
+        ("right", &position.right),
+        ("width", &position.width),
+        ("height", &position.height),
+    ];
+    for (name, value) in fields {
+        if let Some(value) = value {
+            value.validate().map_err(|reason| WindowCreateError::InvalidPosition {
+                reason: format!("{}: {}", name, reason),
+            })?;
+        }
+    }
+
     // Horizontal: need (left + right) OR (left + width) OR (right + width)
-    let has_horizontal = match (position.left, position.right, position.width) {
+    let has_horizontal = match (&position.left, &position.right, &position.width) {
         (Some(_), Some(_), _) => true,      // left + right
         (Some(_), None, Some(_)) => true,   // left + width
         (None, Some(_), Some(_)) => true,   // right + width
@@ -35,11 +67,13 @@ fn validate_position(position: &WindowPosition) -> Result<(), String> {
     };
 
     if !has_horizontal {
-        return Err("Position must specify (left + right) or (left + width) or (right + width)".to_string());
+        return Err(WindowCreateError::InvalidPosition {
+            reason: "Position must specify (left + right) or (left + width) or (right + width)".to_string(),
+        });
     }
 
     // Vertical: need (top + bottom) OR (top + height) OR (bottom + height)
-    let has_vertical = match (position.top, position.bottom, position.height) {
+    let has_vertical = match (&position.top, &position.bottom, &position.height) {
         (Some(_), Some(_), _) => true,      // top + bottom
         (Some(_), None, Some(_)) => true,   // top + height
         (None, Some(_), Some(_)) => true,   // bottom + height
@@ -47,7 +81,9 @@ fn validate_position(position: &WindowPosition) -> Result<(), String> {
     };
 
     if !has_vertical {
-        return Err("Position must specify (top + bottom) or (top + height) or (bottom + height)".to_string());
+        return Err(WindowCreateError::InvalidPosition {
+            reason: "Position must specify (top + bottom) or (top + height) or (bottom + height)".to_string(),
+        });
     }
 
     Ok(())
@@ -61,44 +97,107 @@ fn calculate_geometry(
     monitor_width: u32,
     monitor_height: u32,
 ) -> WindowGeometry {
+    let horizontal = |length: &Option<Length>| length.as_ref().map(|l| l.resolve(monitor_width));
+    let vertical = |length: &Option<Length>| length.as_ref().map(|l| l.resolve(monitor_height));
+    let (left, right) = (horizontal(&position.left), horizontal(&position.right));
+    let (top, bottom) = (vertical(&position.top), vertical(&position.bottom));
+
     // Calculate width
-    let width = if let (Some(left), Some(right)) = (position.left, position.right) {
-        (monitor_width as i32 - left - right).max(1) as u32
+    let width = if let (Some(left), Some(right)) = (left, right) {
+        monitor_width as i32 - left - right
     } else {
-        position.width.unwrap() // Safe: validated
-    };
+        horizontal(&position.width).unwrap() // Safe: validated
+    }
+    .max(1) as u32;
 
     // Calculate height
-    let height = if let (Some(top), Some(bottom)) = (position.top, position.bottom) {
-        (monitor_height as i32 - top - bottom).max(1) as u32
+    let height = if let (Some(top), Some(bottom)) = (top, bottom) {
+        monitor_height as i32 - top - bottom
     } else {
-        position.height.unwrap() // Safe: validated
-    };
+        vertical(&position.height).unwrap() // Safe: validated
+    }
+    .max(1) as u32;
 
     // Calculate x position
-    let x = if let Some(left) = position.left {
+    let x = if let Some(left) = left {
         monitor_x + left
     } else {
         // right + width case
-        monitor_x + monitor_width as i32 - position.right.unwrap() - width as i32
+        monitor_x + monitor_width as i32 - right.unwrap() - width as i32
     };
 
     // Calculate y position
-    let y = if let Some(top) = position.top {
+    let y = if let Some(top) = top {
         monitor_y + top
     } else {
         // bottom + height case
-        monitor_y + monitor_height as i32 - position.bottom.unwrap() - height as i32
+        monitor_y + monitor_height as i32 - bottom.unwrap() - height as i32
     };
 
     WindowGeometry { x, y, width, height }
 }
 
+/// Clamp one axis so at least `visible` pixels of `[start, start + size)`
+/// stay inside `[frame_start, frame_start + frame_size)`
+fn clamp_axis(start: i32, size: u32, frame_start: i32, frame_size: u32, visible: u32) -> i32 {
+    let visible = visible.min(size).min(frame_size) as i32;
+    let min = frame_start - (size as i32 - visible);
+    let max = frame_start + frame_size as i32 - visible;
+    start.clamp(min, max.max(min))
+}
+
+/// Move a window back on screen if less than `position.minVisible` of it
+/// would land inside the monitor's visible frame
+fn clamp_to_visible_frame(
+    label: &str,
+    position: &WindowPosition,
+    geometry: WindowGeometry,
+    monitor: (i32, i32, u32, u32),
+) -> WindowGeometry {
+    let min_visible = position.min_visible.unwrap_or(DEFAULT_MIN_VISIBLE).clamp(0.0, 1.0);
+    if min_visible == 0.0 {
+        return geometry;
+    }
+
+    let (monitor_x, monitor_y, monitor_width, monitor_height) = monitor;
+    let visible_width = (geometry.width as f64 * min_visible).ceil() as u32;
+    let visible_height = (geometry.height as f64 * min_visible).ceil() as u32;
+
+    let clamped = WindowGeometry {
+        x: clamp_axis(geometry.x, geometry.width, monitor_x, monitor_width, visible_width),
+        y: clamp_axis(geometry.y, geometry.height, monitor_y, monitor_height, visible_height),
+        ..geometry
+    };
+
+    if clamped != geometry {
+        eprintln!(
+            "[window] '{}' would be off-screen at ({}, {}), moved to ({}, {})",
+            label, geometry.x, geometry.y, clamped.x, clamped.y
+        );
+    }
+
+    clamped
+}
+
 /// Get monitor info by name or primary
 /// Returns (x, y, width, height) in logical pixels for the visible frame
+/// Falls back to a default frame while screens are transiently unavailable
+/// (sleep/wake, KVM switch) so window creation doesn't fail outright
+pub(super) fn get_monitor_info(app: &AppHandle, monitor_name: Option<&str>) -> Result<(i32, i32, u32, u32), String> {
+    match query_monitor_info(app, monitor_name) {
+        Ok(info) => Ok(info),
+        Err(e) => {
+            eprintln!("[window] {}, using fallback screen frame", e);
+            let (x, y, width, height) = FALLBACK_SCREEN_FRAME;
+            Ok((x as i32, y as i32, width as u32, height as u32))
+        }
+    }
+}
+
 /// On macOS, uses NSScreen.visibleFrame to exclude menu bar and dock
+/// `monitor_name` matches the screen's display ID or localized name (falls back to primary)
 #[cfg(target_os = "macos")]
-fn get_monitor_info(_app: &AppHandle, _monitor_name: Option<&str>) -> Result<(i32, i32, u32, u32), String> {
+fn query_monitor_info(_app: &AppHandle, monitor_name: Option<&str>) -> Result<(i32, i32, u32, u32), String> {
     use objc2::{msg_send, runtime::AnyObject, ClassType};
     use objc2_app_kit::NSScreen;
     use objc2_foundation::NSRect;
@@ -109,31 +208,77 @@ fn get_monitor_info(_app: &AppHandle, _monitor_name: Option<&str>) -> Result<(i3
             return Err("No screens available".to_string());
         }
 
+        let count: usize = msg_send![screens, count];
+        if count == 0 {
+            return Err("No screens available".to_string());
+        }
+
+        // The primary screen anchors the global coordinate space
         let main_screen: *const AnyObject = msg_send![screens, firstObject];
         if main_screen.is_null() {
             return Err("No main screen".to_string());
         }
 
+        let mut target_screen = main_screen;
+        if let Some(name) = monitor_name.filter(|name| *name != "primary") {
+            for index in 0..count {
+                let screen: *const AnyObject = msg_send![screens, objectAtIndex: index];
+                if screen_matches(screen, name) {
+                    target_screen = screen;
+                    break;
+                }
+            }
+        }
+
         // visibleFrame excludes menu bar and dock
-        let visible: NSRect = msg_send![main_screen, visibleFrame];
-        // frame is the full screen
-        let frame: NSRect = msg_send![main_screen, frame];
+        let visible: NSRect = msg_send![target_screen, visibleFrame];
+        // frame of the primary screen
+        let main_frame: NSRect = msg_send![main_screen, frame];
 
-        // macOS uses bottom-left origin, convert to top-left
-        // menu_bar_height = frame.height - visible.height - visible.origin.y (dock height)
-        let menu_bar_height = frame.size.height - visible.size.height - visible.origin.y;
+        // macOS uses bottom-left origin relative to the primary screen, convert to top-left
+        // For the primary screen this equals the menu bar height
+        let top = main_frame.size.height - visible.size.height - visible.origin.y;
 
         Ok((
             visible.origin.x as i32,
-            menu_bar_height as i32,
+            top as i32,
             visible.size.width as u32,
             visible.size.height as u32,
         ))
     }
 }
 
+/// Check whether an NSScreen matches a display ID or localized name
+#[cfg(target_os = "macos")]
+unsafe fn screen_matches(screen: *const objc2::runtime::AnyObject, name: &str) -> bool {
+    use objc2::{msg_send, runtime::AnyObject};
+    use objc2_foundation::NSString;
+
+    if screen.is_null() {
+        return false;
+    }
+
+    // NSScreenNumber is the CGDirectDisplayID (same ID as MonitorInfo.id)
+    let description: *const AnyObject = msg_send![screen, deviceDescription];
+    if !description.is_null() {
+        let key = NSString::from_str("NSScreenNumber");
+        let number: *const AnyObject = msg_send![description, objectForKey: &*key];
+        if !number.is_null() {
+            let display_id: u32 = msg_send![number, unsignedIntValue];
+            if display_id.to_string() == name {
+                return true;
+            }
+        }
+    }
+
+    let localized_name: *const NSString = msg_send![screen, localizedName];
+    !localized_name.is_null() && (*localized_name).to_string() == name
+}
+
 #[cfg(not(target_os = "macos"))]
-fn get_monitor_info(app: &AppHandle, monitor_name: Option<&str>) -> Result<(i32, i32, u32, u32), String> {
+fn query_monitor_info(app: &AppHandle, monitor_name: Option<&str>) -> Result<(i32, i32, u32, u32), String> {
+    use crate::commands::geometry::logical_bounds;
+
     let monitors = app.available_monitors().map_err(|e| e.to_string())?;
 
     if monitors.is_empty() {
@@ -158,102 +303,381 @@ fn get_monitor_info(app: &AppHandle, monitor_name: Option<&str>) -> Result<(i32,
             .unwrap_or_else(|| monitors[0].clone())
     };
 
-    let size = monitor.size();
-    let pos = monitor.position();
-    let scale = monitor.scale_factor();
+    let (x, y, width, height) = logical_bounds(&monitor);
+    Ok((x as i32, y as i32, width as u32, height as u32))
+}
+
+/// Fail early when `position.monitor` names a monitor that isn't connected
+/// (otherwise the window would silently land on the primary screen)
+fn ensure_monitor_exists(app: &AppHandle, monitor_name: Option<&str>) -> Result<(), WindowCreateError> {
+    let Some(name) = monitor_name.filter(|name| *name != "primary") else {
+        return Ok(());
+    };
+
+    // If monitors can't be listed right now, let the fallback frame handle it
+    let Ok(monitors) = list_monitors(app) else {
+        return Ok(());
+    };
 
-    // Return logical pixels
-    Ok((
-        (pos.x as f64 / scale) as i32,
-        (pos.y as f64 / scale) as i32,
-        (size.width as f64 / scale) as u32,
-        (size.height as f64 / scale) as u32,
-    ))
+    if monitors.is_empty() || monitors.iter().any(|m| m.id == name || m.name == name) {
+        Ok(())
+    } else {
+        Err(WindowCreateError::MonitorNotFound {
+            name: name.to_string(),
+        })
+    }
 }
 
-/// Create an inline window (for <Window> component pattern)
-#[command]
-pub async fn create_inline_window(
-    app: AppHandle,
-    window_id: String,
-    url: String,
-    transparent: bool,
-    _always_on_top: bool,
-    decorations: bool,
-    resizable: bool,
-    _skip_taskbar: bool,
-    position: WindowPosition,
-) -> Result<(), String> {
-    let label = format!("inline-window-{}", window_id);
+/// Creation options shared by all instances of an inline window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct InlineWindowOptions {
+    pub(super) url: String,
+    pub(super) transparent: bool,
+    pub(super) always_on_top: bool,
+    pub(super) decorations: bool,
+    pub(super) resizable: bool,
+    pub(super) skip_taskbar: bool,
+    pub(super) size_constraints: SizeConstraints,
+    /// Join every Space and stay visible over fullscreen apps
+    #[serde(default)]
+    pub(super) all_spaces: bool,
+    /// Stay visible over fullscreen apps (`None` follows `all_spaces`)
+    #[serde(default)]
+    pub(super) over_fullscreen: Option<bool>,
+    /// Explicit window level (overrides `always_on_top`)
+    #[serde(default)]
+    pub(super) level: Option<WindowLevel>,
+}
 
-    // Check if window already exists
-    if app.get_webview_window(&label).is_some() {
-        return Err(format!("Inline window '{}' already exists", label));
+/// How an inline window was created, kept so it can be recreated
+/// A `perMonitor` definition is instantiated once per connected monitor
+#[derive(Debug, Clone)]
+pub(super) struct InlineWindowDefinition {
+    pub(super) title: String,
+    pub(super) options: InlineWindowOptions,
+    pub(super) position: WindowPosition,
+    pub(super) per_monitor: bool,
+}
+
+/// Inline window definitions keyed by base label (`inline-window-{id}`)
+pub(super) static INLINE_WINDOWS: Lazy<Mutex<HashMap<String, InlineWindowDefinition>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Label of a per-monitor instance (`inline-window-{id}:{monitorId}`)
+fn per_monitor_label(base_label: &str, monitor_id: &str) -> String {
+    format!("{}:{}", base_label, monitor_id)
+}
+
+/// Resolve a label to the windows it refers to
+/// A per-monitor base label expands to all of its instances
+pub(super) fn resolve_labels(app: &AppHandle, label: &str) -> Vec<String> {
+    if app.get_webview_window(label).is_some() {
+        return vec![label.to_string()];
     }
 
+    let instance_prefix = format!("{}:", label);
+    app.webview_windows()
+        .keys()
+        .filter(|k| k.starts_with(&instance_prefix))
+        .cloned()
+        .collect()
+}
+
+/// Append the monitor ID to a window URL so each instance knows its monitor
+fn with_monitor_param(url: &str, monitor_id: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.query_pairs_mut().append_pair("monitor", monitor_id);
+            parsed.to_string()
+        }
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Calculate geometry and build a hidden inline window
+fn build_inline_window(
+    app: &AppHandle,
+    label: &str,
+    title: &str,
+    options: &InlineWindowOptions,
+    position: &WindowPosition,
+) -> Result<(), WindowCreateError> {
     // Validate position constraints
-    validate_position(&position)?;
+    validate_position(position)?;
+    ensure_monitor_exists(app, position.monitor.as_deref())?;
 
     // Get monitor info
     let (monitor_x, monitor_y, monitor_width, monitor_height) =
-        get_monitor_info(&app, position.monitor.as_deref())?;
+        get_monitor_info(app, position.monitor.as_deref()).map_err(WindowCreateError::build)?;
 
-    // Calculate geometry
+    // Calculate geometry, keeping the window reachable on screen
     let geometry = calculate_geometry(
-        &position,
+        position,
         monitor_x,
         monitor_y,
         monitor_width,
         monitor_height,
     );
+    let geometry = clamp_to_visible_frame(
+        label,
+        position,
+        geometry,
+        (monitor_x, monitor_y, monitor_width, monitor_height),
+    );
 
     // Parse URL - Tauri handles custom protocols registered via register_uri_scheme_protocol
-    let parsed_url: url::Url = url.parse().map_err(|e| format!("Invalid URL: {}", e))?;
+    let parsed_url = url::Url::parse(&options.url).map_err(|e| WindowCreateError::InvalidUrl {
+        url: options.url.clone(),
+        reason: e.to_string(),
+    })?;
     let webview_url = WebviewUrl::External(parsed_url);
 
-    let _window = WebviewWindowBuilder::new(&app, &label, webview_url)
-        .title(&window_id)
-        .decorations(decorations)
-        .transparent(transparent)
-        .always_on_top(_always_on_top)
-        .skip_taskbar(_skip_taskbar)
-        .resizable(resizable)
+    let mut builder = WebviewWindowBuilder::new(app, label, webview_url);
+    if let Some(min) = options.size_constraints.min_size() {
+        builder = builder.min_inner_size(min.width, min.height);
+    }
+    if let Some(max) = options.size_constraints.max_size() {
+        builder = builder.max_inner_size(max.width, max.height);
+    }
+
+    let window = builder
+        .title(title)
+        .decorations(options.decorations)
+        .transparent(options.transparent)
+        .always_on_top(options.always_on_top)
+        .skip_taskbar(options.skip_taskbar)
+        .resizable(options.resizable)
         .visible(false)
         .focused(false)
         .position(geometry.x as f64, geometry.y as f64)
         .inner_size(geometry.width as f64, geometry.height as f64)
         .build()
-        .map_err(|e| e.to_string())?;
+        .map_err(WindowCreateError::build)?;
+
+    let over_fullscreen = options.over_fullscreen.unwrap_or(options.all_spaces);
+    if options.all_spaces || over_fullscreen {
+        apply_collection_behavior(&window, options.all_spaces, over_fullscreen)
+            .map_err(WindowCreateError::build)?;
+    }
+    if let Some(level) = options.level {
+        apply_window_level(&window, level).map_err(WindowCreateError::build)?;
+    }
 
     Ok(())
 }
 
-/// Update window position
+/// Create the instance of a per-monitor window for one monitor
+fn create_per_monitor_instance(
+    app: &AppHandle,
+    base_label: &str,
+    definition: &InlineWindowDefinition,
+    monitor: &MonitorInfo,
+    show: bool,
+) -> Result<(), WindowCreateError> {
+    let label = per_monitor_label(base_label, &monitor.id);
+    if app.get_webview_window(&label).is_some() {
+        return Ok(());
+    }
+
+    let options = InlineWindowOptions {
+        url: with_monitor_param(&definition.options.url, &monitor.id),
+        ..definition.options.clone()
+    };
+    let position = WindowPosition {
+        monitor: Some(monitor.id.clone()),
+        ..definition.position.clone()
+    };
+
+    build_inline_window(app, &label, &definition.title, &options, &position)?;
+
+    if show {
+        if let Some(window) = app.get_webview_window(&label) {
+            window.show().map_err(WindowCreateError::build)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Spawn per-monitor windows on connected monitors and close the
+/// instances of disconnected ones
+pub fn sync_per_monitor_windows(app: &AppHandle, change: &MonitorChange) {
+    let definitions = match INLINE_WINDOWS.lock() {
+        Ok(definitions) => definitions.clone(),
+        Err(_) => return,
+    };
+
+    for (base_label, definition) in definitions.iter().filter(|(_, d)| d.per_monitor) {
+        for monitor in &change.removed {
+            let label = per_monitor_label(base_label, &monitor.id);
+            if let Some(window) = app.get_webview_window(&label) {
+                if let Err(e) = window.close() {
+                    eprintln!("[window] Failed to close '{}': {}", label, e);
+                }
+            }
+        }
+
+        for monitor in &change.added {
+            if let Err(e) = create_per_monitor_instance(app, base_label, definition, monitor, true) {
+                eprintln!(
+                    "[window] Failed to create '{}' on monitor {}: {}",
+                    base_label, monitor.id, e
+                );
+            }
+        }
+    }
+}
+
+/// Create an inline window (for <Window> component pattern)
+/// With `per_monitor`, one instance is created per monitor and kept in sync
+/// as displays are connected and disconnected
+/// `size_constraints` limits user resizing (from `minSize` / `maxSize` in WindowConfig)
+/// `all_spaces` keeps the window on every Space, including over fullscreen apps
+/// `level` places the window in a z-order layer (overrides `always_on_top`)
 #[command]
-pub fn update_window_position(
+pub async fn create_inline_window(
     app: AppHandle,
-    label: String,
+    window_id: String,
+    url: String,
+    transparent: bool,
+    _always_on_top: bool,
+    decorations: bool,
+    resizable: bool,
+    _skip_taskbar: bool,
     position: WindowPosition,
+    per_monitor: Option<bool>,
+    size_constraints: Option<SizeConstraints>,
+    all_spaces: Option<bool>,
+    level: Option<WindowLevel>,
+) -> Result<(), WindowCreateError> {
+    let label = format!("inline-window-{}", window_id);
+    let size_constraints = size_constraints.unwrap_or_default();
+    size_constraints
+        .validate()
+        .map_err(|reason| WindowCreateError::InvalidSizeConstraints { reason })?;
+
+    let definition = InlineWindowDefinition {
+        title: window_id,
+        options: InlineWindowOptions {
+            url,
+            transparent,
+            always_on_top: _always_on_top,
+            decorations,
+            resizable,
+            skip_taskbar: _skip_taskbar,
+            size_constraints,
+            all_spaces: all_spaces.unwrap_or(false),
+            over_fullscreen: None,
+            level,
+        },
+        position,
+        per_monitor: per_monitor.unwrap_or(false),
+    };
+
+    wait_for_screens(&app).await;
+    create_from_definition(&app, &label, definition)
+}
+
+/// Create (hidden) the window(s) of a definition and remember it
+pub(super) fn create_from_definition(
+    app: &AppHandle,
+    label: &str,
+    definition: InlineWindowDefinition,
+) -> Result<(), WindowCreateError> {
+    let already_exists = || WindowCreateError::AlreadyExists {
+        label: label.to_string(),
+    };
+
+    // Check if window already exists
+    if app.get_webview_window(label).is_some() {
+        return Err(already_exists());
+    }
+    if INLINE_WINDOWS
+        .lock()
+        .map_err(WindowCreateError::build)?
+        .get(label)
+        .is_some_and(|existing| existing.per_monitor)
+    {
+        return Err(already_exists());
+    }
+
+    if definition.per_monitor {
+        validate_position(&definition.position)?;
+
+        INLINE_WINDOWS
+            .lock()
+            .map_err(WindowCreateError::build)?
+            .insert(label.to_string(), definition.clone());
+
+        // Instances stay hidden until show_window is called with the base label
+        let created = list_monitors(app)
+            .map_err(WindowCreateError::build)
+            .and_then(|monitors| {
+                monitors.iter().try_for_each(|monitor| {
+                    create_per_monitor_instance(app, label, &definition, monitor, false)
+                })
+            });
+
+        // Roll back so a failed create can be retried under the same label
+        if created.is_err() {
+            if let Ok(mut definitions) = INLINE_WINDOWS.lock() {
+                definitions.remove(label);
+            }
+            for instance in resolve_labels(app, label) {
+                if let Some(window) = app.get_webview_window(&instance) {
+                    let _ = window.destroy();
+                }
+            }
+        }
+
+        return created;
+    }
+
+    build_inline_window(
+        app,
+        label,
+        &definition.title,
+        &definition.options,
+        &definition.position,
+    )?;
+
+    INLINE_WINDOWS
+        .lock()
+        .map_err(WindowCreateError::build)?
+        .insert(label.to_string(), definition);
+
+    Ok(())
+}
+
+/// Apply position config to an existing window
+fn apply_window_position(
+    app: &AppHandle,
+    label: &str,
+    position: &WindowPosition,
 ) -> Result<(), String> {
     let window = app
-        .get_webview_window(&label)
+        .get_webview_window(label)
         .ok_or_else(|| format!("Window '{}' not found", label))?;
 
-    // Validate position constraints
-    validate_position(&position)?;
-
     // Get monitor info
     let (monitor_x, monitor_y, monitor_width, monitor_height) =
-        get_monitor_info(&app, position.monitor.as_deref())?;
+        get_monitor_info(app, position.monitor.as_deref())?;
 
-    // Calculate geometry
+    // Calculate geometry, keeping the window reachable on screen
     let geometry = calculate_geometry(
-        &position,
+        position,
         monitor_x,
         monitor_y,
         monitor_width,
         monitor_height,
     );
+    let geometry = clamp_to_visible_frame(
+        label,
+        position,
+        geometry,
+        (monitor_x, monitor_y, monitor_width, monitor_height),
+    );
 
     // Apply position and size
     window
@@ -273,39 +697,380 @@ pub fn update_window_position(
     Ok(())
 }
 
-/// Hide a window by label
+/// Update window position
+/// For per-monitor windows, updates the definition and repositions every instance
+#[command]
+pub fn update_window_position(
+    app: AppHandle,
+    label: String,
+    position: WindowPosition,
+) -> Result<(), String> {
+    // Validate position constraints
+    validate_position(&position)?;
+
+    let is_per_monitor = {
+        let mut definitions = INLINE_WINDOWS.lock().map_err(|e| e.to_string())?;
+        match definitions.get_mut(&label) {
+            Some(definition) => {
+                definition.position = position.clone();
+                definition.per_monitor
+            }
+            None => false,
+        }
+    };
+
+    apply_definition_position(&app, &label, &position, is_per_monitor)
+}
+
+/// Position a window, or every instance of a per-monitor window
+fn apply_definition_position(
+    app: &AppHandle,
+    label: &str,
+    position: &WindowPosition,
+    per_monitor: bool,
+) -> Result<(), String> {
+    if !per_monitor {
+        return apply_window_position(app, label, position);
+    }
+
+    let instance_prefix = format!("{}:", label);
+    for instance_label in resolve_labels(app, label) {
+        let monitor_id = instance_label
+            .strip_prefix(&instance_prefix)
+            .unwrap_or_default()
+            .to_string();
+        let instance_position = WindowPosition {
+            monitor: Some(monitor_id),
+            ..position.clone()
+        };
+        apply_window_position(app, &instance_label, &instance_position)?;
+    }
+
+    Ok(())
+}
+
+/// Recompute the geometry of every open inline window from its position
+/// config against the current monitors. Windows anchored to a monitor that
+/// is no longer connected move to the primary monitor.
+/// Returns the labels that were repositioned.
+pub fn reapply_window_geometry(app: &AppHandle) -> Vec<String> {
+    let definitions = match INLINE_WINDOWS.lock() {
+        Ok(definitions) => definitions.clone(),
+        Err(_) => return Vec::new(),
+    };
+
+    let mut labels: Vec<String> = definitions
+        .iter()
+        .filter(|(label, _)| !resolve_labels(app, label).is_empty())
+        .filter_map(|(label, definition)| {
+            match apply_definition_position(app, label, &definition.position, definition.per_monitor) {
+                Ok(()) => Some(label.clone()),
+                Err(e) => {
+                    eprintln!("[window] Failed to reposition '{}': {}", label, e);
+                    None
+                }
+            }
+        })
+        .collect();
+    labels.sort();
+    labels
+}
+
+/// Re-apply every inline window's position config, e.g. after a display
+/// was disconnected. Runs automatically on `monitor-changed`.
 #[command]
-pub fn hide_window(app: AppHandle, label: String) -> Result<(), String> {
-    if let Some(window) = app.get_webview_window(&label) {
-        // Ignore cursor events so clicks pass through
+pub fn reapply_all_window_geometry(app: AppHandle) -> Result<Vec<String>, String> {
+    Ok(reapply_window_geometry(&app))
+}
+
+/// Apply an explicit click-through setting (None leaves it unchanged)
+pub(super) fn apply_ignore_cursor_events(
+    window: &WebviewWindow,
+    ignore_cursor_events: Option<bool>,
+) -> Result<(), String> {
+    match ignore_cursor_events {
+        Some(ignore) => window
+            .set_ignore_cursor_events(ignore)
+            .map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
+
+/// NSWindowCollectionBehavior flags managed by `set_window_collection_behavior`
+#[cfg(target_os = "macos")]
+mod collection_behavior {
+    pub const CAN_JOIN_ALL_SPACES: usize = 1 << 0;
+    pub const STATIONARY: usize = 1 << 4;
+    pub const FULL_SCREEN_AUXILIARY: usize = 1 << 8;
+}
+
+/// Set the Spaces/fullscreen collection behavior, leaving other flags as they are
+fn apply_collection_behavior(
+    window: &WebviewWindow,
+    all_spaces: bool,
+    over_fullscreen: bool,
+) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        use collection_behavior::*;
+
         window
-            .set_ignore_cursor_events(true)
-            .map_err(|e| e.to_string())?;
-        window.hide().map_err(|e| e.to_string())?;
+            .with_webview(move |webview| unsafe {
+                use objc2::msg_send;
+                use objc2::runtime::AnyObject;
+
+                let ns_window = webview.ns_window() as *const AnyObject;
+                if ns_window.is_null() {
+                    return;
+                }
+
+                let current: usize = msg_send![ns_window, collectionBehavior];
+                let mut behavior =
+                    current & !(CAN_JOIN_ALL_SPACES | STATIONARY | FULL_SCREEN_AUXILIARY);
+                if all_spaces {
+                    behavior |= CAN_JOIN_ALL_SPACES | STATIONARY;
+                }
+                if over_fullscreen {
+                    behavior |= FULL_SCREEN_AUXILIARY;
+                }
+                let _: () = msg_send![ns_window, setCollectionBehavior: behavior];
+            })
+            .map_err(|e| format!("Failed to set collection behavior: {}", e))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (window, all_spaces, over_fullscreen);
         Ok(())
-    } else {
-        Err(format!("Window '{}' not found", label))
     }
 }
 
+/// Make a window join every Space (`all_spaces`) and/or stay visible over
+/// fullscreen apps (`over_fullscreen`)
+/// A per-monitor base label applies to every instance, and the definition
+/// keeps the behavior so recreated windows get it too
+#[command]
+pub fn set_window_collection_behavior(
+    app: AppHandle,
+    label: String,
+    all_spaces: bool,
+    over_fullscreen: bool,
+) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    if let Some(definition) = INLINE_WINDOWS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get_mut(&label)
+    {
+        definition.options.all_spaces = all_spaces;
+        definition.options.over_fullscreen = Some(over_fullscreen);
+    }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            apply_collection_behavior(&window, all_spaces, over_fullscreen)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Z-order layer of a window, from behind desktop icons to above the menu bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowLevel {
+    /// Wallpaper level, behind desktop icons
+    Desktop,
+    Normal,
+    /// What `always_on_top` uses
+    Floating,
+    /// Menu bar level
+    StatusBar,
+    PopUpMenu,
+    ScreenSaver,
+}
+
+impl WindowLevel {
+    /// `CGWindowLevelKey` whose level this maps to
+    #[cfg(target_os = "macos")]
+    fn cg_key(self) -> i32 {
+        match self {
+            WindowLevel::Desktop => 2,      // kCGDesktopWindowLevelKey
+            WindowLevel::Normal => 4,       // kCGNormalWindowLevelKey
+            WindowLevel::Floating => 5,     // kCGFloatingWindowLevelKey
+            WindowLevel::StatusBar => 9,    // kCGStatusWindowLevelKey
+            WindowLevel::PopUpMenu => 11,   // kCGPopUpMenuWindowLevelKey
+            WindowLevel::ScreenSaver => 13, // kCGScreenSaverWindowLevelKey
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGWindowLevelForKey(key: i32) -> i32;
+}
+
+/// Set `NSWindow.level`
+fn apply_window_level(window: &WebviewWindow, level: WindowLevel) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let ns_level = unsafe { CGWindowLevelForKey(level.cg_key()) } as isize;
+
+        window
+            .with_webview(move |webview| unsafe {
+                use objc2::msg_send;
+                use objc2::runtime::AnyObject;
+
+                let ns_window = webview.ns_window() as *const AnyObject;
+                if !ns_window.is_null() {
+                    let _: () = msg_send![ns_window, setLevel: ns_level];
+                }
+            })
+            .map_err(|e| format!("Failed to set window level: {}", e))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (window, level);
+        Ok(())
+    }
+}
+
+/// Move a window to a z-order layer
+/// A per-monitor base label applies to every instance, and the definition
+/// keeps the level so recreated windows get it too
+#[command]
+pub fn set_window_level(app: AppHandle, label: String, level: WindowLevel) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    if let Some(definition) = INLINE_WINDOWS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get_mut(&label)
+    {
+        definition.options.level = Some(level);
+    }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            apply_window_level(&window, level)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Hide a window by label
+/// `ignore_cursor_events` optionally makes the window click-through as well
+#[command]
+pub fn hide_window(
+    app: AppHandle,
+    label: String,
+    ignore_cursor_events: Option<bool>,
+) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            apply_ignore_cursor_events(&window, ignore_cursor_events)?;
+            window.hide().map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Close a window
+/// Closing a per-monitor base label closes every instance and forgets the definition
 #[command]
 pub fn close_window(app: AppHandle, label: String) -> Result<(), String> {
-    if let Some(window) = app.get_webview_window(&label) {
-        window.close().map_err(|e| e.to_string())?;
-        Ok(())
-    } else {
-        Err(format!("Window '{}' not found", label))
+    let was_per_monitor = INLINE_WINDOWS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&label)
+        .is_some_and(|definition| definition.per_monitor);
+
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() && !was_per_monitor {
+        return Err(format!("Window '{}' not found", label));
     }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            window.close().map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
 }
 
 /// Show a window (after positioning is applied)
+/// `ignore_cursor_events` optionally sets click-through at the same time
 #[command]
-pub fn show_window(app: AppHandle, label: String) -> Result<(), String> {
-    if let Some(window) = app.get_webview_window(&label) {
-        window.show().map_err(|e| e.to_string())?;
-        Ok(())
-    } else {
-        Err(format!("Window '{}' not found", label))
+pub fn show_window(
+    app: AppHandle,
+    label: String,
+    ignore_cursor_events: Option<bool>,
+) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            apply_ignore_cursor_events(&window, ignore_cursor_events)?;
+            window.show().map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_axis;
+
+    #[test]
+    fn leaves_a_visible_window_alone() {
+        assert_eq!(clamp_axis(100, 200, 0, 1440, 50), 100);
+    }
+
+    #[test]
+    fn keeps_the_visible_part_on_either_side() {
+        // At most 150 of the 200px may hang off each edge
+        assert_eq!(clamp_axis(1420, 200, 0, 1440, 50), 1390);
+        assert_eq!(clamp_axis(-500, 200, 0, 1440, 50), -150);
+    }
+
+    #[test]
+    fn handles_a_window_larger_than_the_monitor() {
+        assert_eq!(clamp_axis(0, 2000, 0, 1440, 100), 0);
+        assert_eq!(clamp_axis(-3000, 2000, 0, 1440, 100), -1900);
+        assert_eq!(clamp_axis(1500, 2000, 0, 1440, 100), 1340);
+        // Asking for more than the monitor shows means covering all of it
+        assert_eq!(clamp_axis(100, 2000, 0, 1440, 5000), 0);
+        assert_eq!(clamp_axis(-1000, 2000, 0, 1440, 5000), -560);
+    }
+
+    #[test]
+    fn handles_negative_monitor_origins() {
+        // A 1080px monitor stacked above the primary starts at y = -1080
+        assert_eq!(clamp_axis(-500, 100, -1080, 1080, 50), -500);
+        assert_eq!(clamp_axis(-2000, 100, -1080, 1080, 50), -1130);
+        assert_eq!(clamp_axis(10, 100, -1080, 1080, 50), -50);
+        // A monitor to the left of the primary
+        assert_eq!(clamp_axis(-3000, 300, -1920, 1920, 100), -2120);
     }
 }
@@ -1,4 +1,18 @@
+pub mod error;
+pub mod fade;
 pub mod hover_focus;
+pub mod key_window;
+pub mod layouts;
+pub mod length;
+pub mod magnetic;
 pub mod manager;
+pub mod monitors;
+pub mod reload;
 
+pub use error::WindowCreateError;
+pub use fade::*;
+pub use key_window::*;
+pub use layouts::*;
+pub use magnetic::*;
 pub use manager::*;
+pub use reload::*;
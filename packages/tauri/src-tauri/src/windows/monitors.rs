@@ -0,0 +1,126 @@
+//! Display Arrangement Tracking
+//!
+//! Keeps the last known monitor set and computes what changed when macOS
+//! posts `NSApplicationDidChangeScreenParametersNotification`.
+//! Emits `monitor-changed` with `{ added, removed, changed, monitors }`.
+//!
+//! Notifications are debounced: sleep/wake and display reconfiguration post
+//! several in a row, so we wait for the layout to settle and emit once.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use super::manager::{reapply_window_geometry, sync_per_monitor_windows};
+use crate::commands::events;
+use crate::commands::helpers::wait_for_screens;
+use crate::commands::window::{list_monitors, MonitorInfo};
+
+/// Last known monitor set, used as the baseline for the next diff
+static LAST_MONITORS: Lazy<Mutex<Vec<MonitorInfo>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Quiet period after the last notification before the layout counts as stable
+const SETTLE_DELAY: Duration = Duration::from_millis(300);
+
+/// Bumped on every notification; only the latest pending refresh runs
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Difference between two monitor sets, keyed by stable monitor id
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorChange {
+    pub added: Vec<MonitorInfo>,
+    pub removed: Vec<MonitorInfo>,
+    /// Monitors that were resized, moved (rearranged) or changed scale
+    pub changed: Vec<MonitorInfo>,
+    pub monitors: Vec<MonitorInfo>,
+}
+
+/// Record the initial monitor set
+pub fn init(app_handle: &AppHandle) {
+    if let Ok(monitors) = list_monitors(app_handle) {
+        if let Ok(mut last) = LAST_MONITORS.lock() {
+            *last = monitors;
+        }
+    }
+}
+
+/// Compute the change between two monitor sets
+pub fn diff_monitors(previous: &[MonitorInfo], current: &[MonitorInfo]) -> MonitorChange {
+    let added = current
+        .iter()
+        .filter(|m| !previous.iter().any(|p| p.id == m.id))
+        .cloned()
+        .collect();
+
+    let removed = previous
+        .iter()
+        .filter(|p| !current.iter().any(|m| m.id == p.id))
+        .cloned()
+        .collect();
+
+    let changed = current
+        .iter()
+        .filter(|m| previous.iter().any(|p| p.id == m.id && p != *m))
+        .cloned()
+        .collect();
+
+    MonitorChange {
+        added,
+        removed,
+        changed,
+        monitors: current.to_vec(),
+    }
+}
+
+/// Re-read the monitor set, update the baseline and return the change
+pub fn refresh(app_handle: &AppHandle) -> Result<MonitorChange, String> {
+    let current = list_monitors(app_handle)?;
+    let mut last = LAST_MONITORS.lock().map_err(|e| e.to_string())?;
+    let change = diff_monitors(&last, &current);
+    *last = current;
+    Ok(change)
+}
+
+impl MonitorChange {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Handle a screen parameter change notification (debounced)
+pub fn handle_screen_change(app_handle: &AppHandle) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app_handle = app_handle.clone();
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(SETTLE_DELAY).await;
+        if GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        wait_for_screens(&app_handle).await;
+
+        // Window creation and positioning must happen on the main thread
+        let handle = app_handle.clone();
+        let _ = app_handle.run_on_main_thread(move || apply_screen_change(&handle));
+    });
+}
+
+/// Diff the settled layout, resync per-monitor windows, move windows back
+/// onto the current monitors and emit
+fn apply_screen_change(app_handle: &AppHandle) {
+    match refresh(app_handle) {
+        Ok(change) if change.is_empty() => {}
+        Ok(change) => {
+            sync_per_monitor_windows(app_handle, &change);
+            reapply_window_geometry(app_handle);
+
+            if let Err(e) = app_handle.emit(events::MONITOR_CHANGED, &change) {
+                eprintln!("[monitors] Failed to emit monitor-changed event: {}", e);
+            }
+        }
+        Err(e) => eprintln!("[monitors] Failed to read monitors: {}", e),
+    }
+}
@@ -0,0 +1,156 @@
+//! Widget Reload Handshake
+//!
+//! Before a window reloads it receives `widget-will-reload`, giving the page
+//! a chance to stash in-page state (scroll position, form input) in the
+//! shared store and restore it once loaded again. The reload waits for the
+//! page's `ack_widget_reload`, or `RELOAD_ACK_TIMEOUT` if it never answers.
+//!
+//! `reload_all_widgets` reloads every inline window at once (e.g. after a
+//! change shared across widgets) and emits `widgets-reloaded` when done.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, EventTarget, Manager, WebviewWindow};
+use tokio::sync::Notify;
+
+use super::manager::resolve_labels;
+use crate::commands::events;
+
+/// How long a page gets to save its state before it is reloaded anyway
+const RELOAD_ACK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Windows waiting to acknowledge `widget-will-reload`, by label
+static PENDING_ACKS: Lazy<Mutex<HashMap<String, Arc<Notify>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WillReloadPayload {
+    label: String,
+    timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct WidgetsReloadedPayload {
+    labels: Vec<String>,
+}
+
+/// Notify a window it is about to reload, wait (bounded) for its ack, then reload
+/// Fails if a reload of the same window is already waiting for its ack
+pub async fn reload_with_handshake(window: &WebviewWindow) -> Result<(), String> {
+    let label = window.label().to_string();
+    let acked = Arc::new(Notify::new());
+    {
+        let mut pending = PENDING_ACKS.lock().map_err(|e| e.to_string())?;
+        if pending.contains_key(&label) {
+            return Err(format!("Reload of '{}' is already in progress", label));
+        }
+        pending.insert(label.clone(), acked.clone());
+    }
+
+    let payload = WillReloadPayload {
+        label: label.clone(),
+        timeout_ms: RELOAD_ACK_TIMEOUT.as_millis() as u64,
+    };
+    match window.emit_to(
+        EventTarget::webview_window(label.as_str()),
+        events::WIDGET_WILL_RELOAD,
+        payload,
+    ) {
+        Ok(()) => {
+            if tokio::time::timeout(RELOAD_ACK_TIMEOUT, acked.notified())
+                .await
+                .is_err()
+            {
+                eprintln!("[reload] '{}' did not acknowledge, reloading anyway", label);
+            }
+        }
+        Err(e) => eprintln!("[reload] Failed to emit widget-will-reload: {}", e),
+    }
+
+    if let Ok(mut pending) = PENDING_ACKS.lock() {
+        pending.remove(&label);
+    }
+
+    window
+        .reload()
+        .map_err(|e| format!("Failed to reload '{}': {}", label, e))
+}
+
+// ============================================
+// Reload Commands
+// ============================================
+
+/// Called by a page once it has stashed its state after `widget-will-reload`
+#[command]
+pub fn ack_widget_reload(window: WebviewWindow) -> Result<(), String> {
+    let acked = PENDING_ACKS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(window.label())
+        .cloned();
+    if let Some(acked) = acked {
+        acked.notify_one();
+    }
+    Ok(())
+}
+
+/// Reload a window after giving it a chance to save its state
+/// A per-monitor base label reloads every instance
+#[command]
+pub async fn reload_widget(app: AppHandle, label: String) -> Result<(), String> {
+    let labels = resolve_labels(&app, &label);
+    if labels.is_empty() {
+        return Err(format!("Window '{}' not found", label));
+    }
+
+    for target in labels {
+        if let Some(window) = app.get_webview_window(&target) {
+            reload_with_handshake(&window).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reload every inline window (each gets the save-state handshake)
+/// Returns the labels that reloaded and emits `widgets-reloaded` with them
+#[command]
+pub async fn reload_all_widgets(app: AppHandle) -> Result<Vec<String>, String> {
+    // Handshakes run concurrently so one slow page doesn't hold up the rest
+    let handles: Vec<_> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label.starts_with("inline-window-"))
+        .map(|(label, window)| {
+            tauri::async_runtime::spawn(async move {
+                match reload_with_handshake(&window).await {
+                    Ok(()) => Some(label),
+                    Err(e) => {
+                        eprintln!("[reload] {}", e);
+                        None
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut reloaded = Vec::new();
+    for handle in handles {
+        if let Ok(Some(label)) = handle.await {
+            reloaded.push(label);
+        }
+    }
+    reloaded.sort();
+
+    let _ = app.emit(
+        events::WIDGETS_RELOADED,
+        WidgetsReloadedPayload {
+            labels: reloaded.clone(),
+        },
+    );
+    Ok(reloaded)
+}
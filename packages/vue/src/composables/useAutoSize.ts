@@ -15,6 +15,11 @@ export interface UseAutoSizeOptions {
   maxWidth?: MaybeRef<number>
   /** Maximum height in pixels */
   maxHeight?: MaybeRef<number>
+  /**
+   * Coalesce resizes in the backend, applying only the last size within
+   * this many ms (avoids jitter while content animates)
+   */
+  debounceMs?: number
 }
 
 export interface UseAutoSizeReturn {
@@ -108,10 +113,18 @@ export function useAutoSize(
     height.value = newHeight
 
     try {
-      await invoke('set_window_size', {
-        width: newWidth,
-        height: newHeight,
-      })
+      if (options?.debounceMs !== undefined) {
+        await invoke('set_window_size_debounced', {
+          width: newWidth,
+          height: newHeight,
+          debounceMs: options.debounceMs,
+        })
+      } else {
+        await invoke('set_window_size', {
+          width: newWidth,
+          height: newHeight,
+        })
+      }
     } catch (e) {
       console.error('Failed to set window size:', e)
     }